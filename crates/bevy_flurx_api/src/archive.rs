@@ -0,0 +1,251 @@
+//! Provides mechanism to read and write archives such as zip and tar from webview.
+//!
+//! The archives are read and written in another thread,
+//! and the paths are restricted by [`AllowPaths`](crate::fs::AllowPaths).
+
+mod create;
+mod extract;
+mod list;
+
+pub use crate::archive::create::ArchiveCreatePlugin;
+pub use crate::archive::extract::ArchiveExtractPlugin;
+pub use crate::archive::list::ArchiveListPlugin;
+use crate::error::ApiResult;
+use crate::error::archive::{UnsafeArchiveEntry, UnsupportedArchive};
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::PluginGroup;
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+/// Allows you to use all archive plugins.
+///
+/// ## Plugins
+///
+/// - [ArchiveListPlugin]
+/// - [ArchiveExtractPlugin]
+/// - [ArchiveCreatePlugin]
+pub struct AllArchivePlugins;
+impl PluginGroup for AllArchivePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(ArchiveListPlugin)
+            .add(ArchiveExtractPlugin)
+            .add(ArchiveCreatePlugin)
+    }
+}
+
+/// The format of the archive.
+///
+/// If not specified, it's detected from the extension of the archive path.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    fn detect(path: &Path, format: Option<ArchiveFormat>) -> ApiResult<Self> {
+        if let Some(format) = format {
+            return Ok(format);
+        }
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_lowercase();
+        if name.ends_with(".zip") {
+            Ok(Self::Zip)
+        } else if name.ends_with(".tar") {
+            Ok(Self::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Ok(Self::TarGz)
+        } else {
+            Err(UnsupportedArchive(path.to_path_buf()).into())
+        }
+    }
+}
+
+/// The entry in the archive.
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+struct ArchiveEntry {
+    /// The path in the archive separated by `/`.
+    path: String,
+    size: u64,
+    #[serde(rename = "isDir")]
+    is_dir: bool,
+}
+
+/// The progress of extracting or creating an archive.
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+struct ArchiveProgress {
+    #[serde(rename = "processedEntries")]
+    processed_entries: u64,
+    #[serde(rename = "totalEntries")]
+    total_entries: u64,
+    #[serde(rename = "processedBytes")]
+    processed_bytes: u64,
+    #[serde(rename = "totalBytes")]
+    total_bytes: u64,
+}
+
+impl ArchiveProgress {
+    fn new<'a>(entries: impl IntoIterator<Item = &'a ArchiveEntry>) -> Self {
+        let mut progress = Self::default();
+        for entry in entries {
+            progress.total_entries += 1;
+            progress.total_bytes += entry.size;
+        }
+        progress
+    }
+
+    fn advance(&mut self, entry: &ArchiveEntry) {
+        self.processed_entries += 1;
+        self.processed_bytes += entry.size;
+    }
+}
+
+fn open_tar(path: &Path, format: ArchiveFormat) -> ApiResult<tar::Archive<Box<dyn Read>>> {
+    let file = File::open(path)?;
+    let reader: Box<dyn Read> = if format == ArchiveFormat::TarGz {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    Ok(tar::Archive::new(reader))
+}
+
+/// Joins the entry path to the destination.
+///
+/// Fails if the entry path is absolute or contains `..`, so that no file is written outside of the destination.
+fn safe_join(dest: &Path, entry_path: &str) -> ApiResult<PathBuf> {
+    let mut path = dest.to_path_buf();
+    for component in Path::new(entry_path).components() {
+        match component {
+            Component::Normal(name) => path.push(name),
+            Component::CurDir => {}
+            _ => return Err(UnsafeArchiveEntry(entry_path.to_string()).into()),
+        }
+    }
+    Ok(path)
+}
+
+fn to_entry_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::archive::create::{CreateRequest, create_archive};
+    use crate::archive::extract::{ExtractRequest, extract_archive};
+    use crate::archive::list::read_entries;
+    use crate::archive::{ArchiveEntry, ArchiveFormat, safe_join};
+    use std::fs::create_dir_all;
+    use std::path::Path;
+
+    #[test]
+    fn detect_format_from_extension() {
+        assert_eq!(
+            ArchiveFormat::detect(Path::new("mods.zip"), None).unwrap(),
+            ArchiveFormat::Zip
+        );
+        assert_eq!(
+            ArchiveFormat::detect(Path::new("mods.tar.gz"), None).unwrap(),
+            ArchiveFormat::TarGz
+        );
+        assert_eq!(
+            ArchiveFormat::detect(Path::new("mods.bin"), Some(ArchiveFormat::Tar)).unwrap(),
+            ArchiveFormat::Tar
+        );
+        ArchiveFormat::detect(Path::new("mods.bin"), None).unwrap_err();
+    }
+
+    #[test]
+    fn reject_entries_outside_of_destination() {
+        let dest = Path::new("dest");
+        assert_eq!(
+            safe_join(dest, "./mods/a.ron").unwrap(),
+            dest.join("mods").join("a.ron")
+        );
+        safe_join(dest, "../evil.sh").unwrap_err();
+        safe_join(dest, "mods/../../evil.sh").unwrap_err();
+        safe_join(dest, "/etc/passwd").unwrap_err();
+    }
+
+    #[test]
+    fn zip_round_trip() {
+        round_trip("save.zip", ArchiveFormat::Zip);
+    }
+
+    #[test]
+    fn tar_gz_round_trip() {
+        round_trip("save.tar.gz", ArchiveFormat::TarGz);
+    }
+
+    fn round_trip(name: &str, format: ArchiveFormat) {
+        let tmp_dir = std::env::temp_dir().join(format!("archive_round_trip_{name}"));
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        let saves = tmp_dir.join("saves");
+        create_dir_all(saves.join("slot1")).unwrap();
+        std::fs::write(saves.join("slot1").join("data.ron"), "data").unwrap();
+        std::fs::write(tmp_dir.join("config.ron"), "config").unwrap();
+        let archive = tmp_dir.join(name);
+
+        create_archive(
+            &CreateRequest {
+                path: archive.clone(),
+                format,
+                sources: vec![saves, tmp_dir.join("config.ron")],
+            },
+            |_| {},
+        )
+        .unwrap();
+
+        let mut entries = read_entries(&archive, format).unwrap();
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        let paths = entries
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            ["config.ron", "saves", "saves/slot1", "saves/slot1/data.ron"]
+        );
+        assert_eq!(
+            entries[0],
+            ArchiveEntry {
+                path: "config.ron".to_string(),
+                size: 6,
+                is_dir: false,
+            }
+        );
+
+        let to = tmp_dir.join("extracted");
+        extract_archive(
+            &ExtractRequest {
+                path: archive,
+                format,
+                to: to.clone(),
+                entries: None,
+                overwrite: false,
+            },
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(to.join("saves").join("slot1").join("data.ron")).unwrap(),
+            "data"
+        );
+        assert_eq!(
+            std::fs::read_to_string(to.join("config.ron")).unwrap(),
+            "config"
+        );
+    }
+}
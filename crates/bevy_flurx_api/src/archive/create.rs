@@ -0,0 +1,227 @@
+use crate::archive::{ArchiveEntry, ArchiveFormat, ArchiveProgress, to_entry_path};
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use crate::progress::spawn_with_progress;
+use bevy::prelude::{In, Res, Update};
+use bevy_flurx::action::once;
+use bevy_flurx::task::ReactorTask;
+use bevy_flurx_ipc::prelude::*;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+
+api_plugin!(
+    /// You'll be able to create an archive from files or directories from typescript(or js).
+    ///
+    /// Each source is stored under its file name, and directories are stored recursively.
+    /// The archive is written in another thread.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// await window.__FLURX__.archive.create("./save.zip", ["./saves/slot1", "./config.ron"], {
+    ///     dir: "Download",
+    ///     sourceDir: "AppData",
+    /// });
+    /// ```
+    ArchiveCreatePlugin,
+    command: create
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+    sources: Vec<PathBuf>,
+    #[serde(rename = "sourceDir")]
+    source_dir: Option<BaseDirectory>,
+    format: Option<ArchiveFormat>,
+    /// The event id which the progress is emitted to.
+    #[serde(rename = "progressEventId")]
+    progress_event_id: Option<String>,
+}
+
+pub(super) struct CreateRequest {
+    pub(super) path: PathBuf,
+    pub(super) format: ArchiveFormat,
+    pub(super) sources: Vec<PathBuf>,
+}
+
+#[command(id = "FLURX|archive::create")]
+async fn create(
+    In(args): In<Args>,
+    WebviewEntity(webview_entity): WebviewEntity,
+    task: ReactorTask,
+) -> ApiResult {
+    let progress_event_id = args.progress_event_id.clone();
+    let request = task
+        .will(Update, once::run(create_system).with(args))
+        .await?;
+    spawn_with_progress(&task, webview_entity, progress_event_id, move |tx| {
+        create_archive(&request, |progress| {
+            let _ = tx.send(progress);
+        })
+    })
+    .await
+}
+
+fn create_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<CreateRequest> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    let sources = args
+        .sources
+        .into_iter()
+        .map(|source| {
+            let source = join_path_if_need(&args.source_dir, source, &identifier)?;
+            error_if_not_accessible(&source, &scope)?;
+            Ok(source)
+        })
+        .collect::<ApiResult<Vec<_>>>()?;
+    Ok(CreateRequest {
+        format: ArchiveFormat::detect(&path, args.format)?,
+        path,
+        sources,
+    })
+}
+
+/// Writes the sources into the archive, and reports the progress after each entry is written.
+pub(super) fn create_archive(
+    request: &CreateRequest,
+    mut on_progress: impl FnMut(ArchiveProgress),
+) -> ApiResult {
+    let mut entries = Vec::new();
+    for source in &request.sources {
+        let name = source.file_name().map(PathBuf::from).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} has no file name", source.display()),
+            )
+        })?;
+        collect_entries(source, name, &mut entries)?;
+    }
+    let mut progress = ArchiveProgress::new(entries.iter().map(|(_, entry)| entry));
+    on_progress(progress);
+
+    let file = File::create(&request.path)?;
+    match request.format {
+        ArchiveFormat::Zip => {
+            let mut zip = zip::ZipWriter::new(file);
+            let options = SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated)
+                .large_file(true);
+            for (source, entry) in &entries {
+                if entry.is_dir {
+                    zip.add_directory(entry.path.as_str(), options)?;
+                } else {
+                    zip.start_file(entry.path.as_str(), options)?;
+                    io::copy(&mut File::open(source)?, &mut zip)?;
+                }
+                progress.advance(entry);
+                on_progress(progress);
+            }
+            zip.finish()?;
+        }
+        ArchiveFormat::Tar => {
+            write_tar(file, &entries, |entry| {
+                progress.advance(entry);
+                on_progress(progress);
+            })?;
+        }
+        ArchiveFormat::TarGz => {
+            let encoder = GzEncoder::new(file, Compression::default());
+            write_tar(encoder, &entries, |entry| {
+                progress.advance(entry);
+                on_progress(progress);
+            })?
+            .finish()?;
+        }
+    }
+    Ok(())
+}
+
+fn write_tar<W: Write>(
+    writer: W,
+    entries: &[(PathBuf, ArchiveEntry)],
+    mut on_written: impl FnMut(&ArchiveEntry),
+) -> ApiResult<W> {
+    let mut tar = tar::Builder::new(writer);
+    for (source, entry) in entries {
+        if entry.is_dir {
+            tar.append_dir(&entry.path, source)?;
+        } else {
+            tar.append_path_with_name(source, &entry.path)?;
+        }
+        on_written(entry);
+    }
+    Ok(tar.into_inner()?)
+}
+
+/// Collects the source and its descendants with the paths in the archive.
+fn collect_entries(
+    source: &Path,
+    name: PathBuf,
+    entries: &mut Vec<(PathBuf, ArchiveEntry)>,
+) -> io::Result<()> {
+    let metadata = std::fs::metadata(source)?;
+    entries.push((
+        source.to_path_buf(),
+        ArchiveEntry {
+            path: to_entry_path(&name),
+            size: if metadata.is_dir() { 0 } else { metadata.len() },
+            is_dir: metadata.is_dir(),
+        },
+    ));
+    if metadata.is_dir() {
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            collect_entries(&entry.path(), name.join(entry.file_name()), entries)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::archive::create::{Args, create_system};
+    use crate::fs::AllowPaths;
+    use crate::tests::test_app;
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+
+    #[test]
+    fn err_if_source_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert()
+                            .with(AllowPaths::new([tmp_dir.join("archives")]))
+                            .then(once::run(create_system).with(Args {
+                                path: tmp_dir.join("archives").join("save.zip"),
+                                sources: vec![tmp_dir.join("saves")],
+                                ..default()
+                            }))
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
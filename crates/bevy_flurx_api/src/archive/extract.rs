@@ -0,0 +1,309 @@
+use crate::archive::list::read_entries;
+use crate::archive::{
+    ArchiveEntry, ArchiveFormat, ArchiveProgress, open_tar, safe_join, to_entry_path,
+};
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use crate::progress::spawn_with_progress;
+use bevy::prelude::{In, Res, Update};
+use bevy_flurx::action::once;
+use bevy_flurx::task::ReactorTask;
+use bevy_flurx_ipc::prelude::*;
+use serde::Deserialize;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
+api_plugin!(
+    /// You'll be able to extract an archive into a directory from typescript(or js).
+    ///
+    /// The entries pointing outside of the destination are rejected,
+    /// and the archive is extracted in another thread.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// await window.__FLURX__.archive.extract("./mods.zip", "./mods", {
+    ///     dir: "Download",
+    ///     toDir: "AppData",
+    ///     onProgress: (progress) => {
+    ///         console.log(`${progress.processedBytes} / ${progress.totalBytes}`);
+    ///     },
+    /// });
+    /// ```
+    ArchiveExtractPlugin,
+    command: extract
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+    to: PathBuf,
+    #[serde(rename = "toDir")]
+    to_dir: Option<BaseDirectory>,
+    /// The entries to extract; a directory entry extracts everything under it.
+    ///
+    /// If not specified, all entries are extracted.
+    entries: Option<Vec<String>>,
+    /// Whether to overwrite the files that already exist in the destination.
+    #[serde(default)]
+    overwrite: bool,
+    format: Option<ArchiveFormat>,
+    /// The event id which the progress is emitted to.
+    #[serde(rename = "progressEventId")]
+    progress_event_id: Option<String>,
+}
+
+pub(super) struct ExtractRequest {
+    pub(super) path: PathBuf,
+    pub(super) format: ArchiveFormat,
+    pub(super) to: PathBuf,
+    pub(super) entries: Option<Vec<String>>,
+    pub(super) overwrite: bool,
+}
+
+#[command(id = "FLURX|archive::extract")]
+async fn extract(
+    In(args): In<Args>,
+    WebviewEntity(webview_entity): WebviewEntity,
+    task: ReactorTask,
+) -> ApiResult {
+    let progress_event_id = args.progress_event_id.clone();
+    let request = task
+        .will(Update, once::run(extract_system).with(args))
+        .await?;
+    spawn_with_progress(&task, webview_entity, progress_event_id, move |tx| {
+        extract_archive(&request, |progress| {
+            let _ = tx.send(progress);
+        })
+    })
+    .await
+}
+
+fn extract_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<ExtractRequest> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    let to = join_path_if_need(&args.to_dir, args.to, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    error_if_not_accessible(&to, &scope)?;
+    Ok(ExtractRequest {
+        format: ArchiveFormat::detect(&path, args.format)?,
+        path,
+        to,
+        entries: args.entries,
+        overwrite: args.overwrite,
+    })
+}
+
+/// Extracts the selected entries, and reports the progress after each entry is extracted.
+pub(super) fn extract_archive(
+    request: &ExtractRequest,
+    mut on_progress: impl FnMut(ArchiveProgress),
+) -> ApiResult {
+    let entries = read_entries(&request.path, request.format)?
+        .into_iter()
+        .filter(|entry| is_selected(&entry.path, &request.entries))
+        .collect::<Vec<_>>();
+    // Validates all entries before writing anything.
+    for entry in &entries {
+        safe_join(&request.to, &entry.path)?;
+    }
+    let mut progress = ArchiveProgress::new(&entries);
+    on_progress(progress);
+
+    std::fs::create_dir_all(&request.to)?;
+    let mut write_entry = |entry: ArchiveEntry, reader: Option<&mut dyn io::Read>| -> ApiResult {
+        let dest = safe_join(&request.to, &entry.path)?;
+        if entry.is_dir {
+            std::fs::create_dir_all(&dest)?;
+        } else if let Some(reader) = reader {
+            write_file(&dest, reader, request.overwrite)?;
+        }
+        progress.advance(&entry);
+        on_progress(progress);
+        Ok(())
+    };
+    match request.format {
+        ArchiveFormat::Zip => {
+            let mut archive = zip::ZipArchive::new(File::open(&request.path)?)?;
+            for i in 0..archive.len() {
+                let mut file = archive.by_index(i)?;
+                let entry = ArchiveEntry {
+                    path: file.name().trim_end_matches('/').to_string(),
+                    size: file.size(),
+                    is_dir: file.is_dir(),
+                };
+                if is_selected(&entry.path, &request.entries) {
+                    write_entry(entry, Some(&mut file))?;
+                }
+            }
+        }
+        ArchiveFormat::Tar | ArchiveFormat::TarGz => {
+            let mut archive = open_tar(&request.path, request.format)?;
+            for file in archive.entries()? {
+                let mut file = file?;
+                let entry_type = file.header().entry_type();
+                let entry = ArchiveEntry {
+                    path: to_entry_path(&file.path()?),
+                    size: file.size(),
+                    is_dir: entry_type.is_dir(),
+                };
+                if !is_selected(&entry.path, &request.entries) {
+                    continue;
+                }
+                // Links and special files are skipped since they may point outside of the destination.
+                if entry_type.is_file() || entry_type.is_dir() {
+                    write_entry(entry, Some(&mut file))?;
+                } else {
+                    write_entry(entry, None)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_file(dest: &Path, reader: &mut dyn io::Read, overwrite: bool) -> ApiResult {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if !overwrite && dest.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dest.display()),
+        )
+        .into());
+    }
+    io::copy(reader, &mut File::create(dest)?)?;
+    Ok(())
+}
+
+fn is_selected(entry_path: &str, selected: &Option<Vec<String>>) -> bool {
+    selected.as_ref().is_none_or(|selected| {
+        selected.iter().any(|selected| {
+            let selected = selected.trim_end_matches('/');
+            entry_path == selected
+                || entry_path
+                    .strip_prefix(selected)
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+    })
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::archive::ArchiveFormat;
+    use crate::archive::extract::{
+        Args, ExtractRequest, extract_archive, extract_system, is_selected,
+    };
+    use crate::fs::AllowPaths;
+    use crate::tests::test_app;
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use std::io::Write;
+    use std::path::Path;
+    use zip::write::SimpleFileOptions;
+
+    fn write_zip(path: &Path, files: &[(&str, &str)]) {
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        for (name, contents) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    fn request(path: &Path, to: &Path, entries: Option<Vec<String>>) -> ExtractRequest {
+        ExtractRequest {
+            path: path.to_path_buf(),
+            format: ArchiveFormat::Zip,
+            to: to.to_path_buf(),
+            entries,
+            overwrite: false,
+        }
+    }
+
+    #[test]
+    fn select_entries_by_name_or_directory() {
+        let selected = Some(vec!["mods/".to_string(), "readme.md".to_string()]);
+        assert!(is_selected("mods/a.ron", &selected));
+        assert!(is_selected("readme.md", &selected));
+        assert!(!is_selected("mods2/a.ron", &selected));
+        assert!(is_selected("mods2/a.ron", &None));
+    }
+
+    #[test]
+    fn extract_selected_entries() {
+        let tmp_dir = std::env::temp_dir().join("archive_extract_selected_entries");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        std::fs::create_dir_all(&tmp_dir).unwrap();
+        let archive = tmp_dir.join("mods.zip");
+        write_zip(
+            &archive,
+            &[("mods/a.ron", "a"), ("mods/b.ron", "bb"), ("readme.md", "")],
+        );
+        let to = tmp_dir.join("extracted");
+        let mut progresses = Vec::new();
+        extract_archive(
+            &request(&archive, &to, Some(vec!["mods".to_string()])),
+            |progress| progresses.push(progress),
+        )
+        .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(to.join("mods").join("b.ron")).unwrap(),
+            "bb"
+        );
+        assert!(!to.join("readme.md").exists());
+        let last = progresses.last().unwrap();
+        assert_eq!(last.processed_entries, 2);
+        assert_eq!(last.processed_bytes, 3);
+    }
+
+    #[test]
+    fn err_if_entry_points_outside_of_destination() {
+        let tmp_dir = std::env::temp_dir().join("archive_extract_zip_slip");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        std::fs::create_dir_all(&tmp_dir).unwrap();
+        let archive = tmp_dir.join("evil.zip");
+        write_zip(&archive, &[("ok.txt", ""), ("../evil.txt", "evil")]);
+        let to = tmp_dir.join("extracted");
+
+        extract_archive(&request(&archive, &to, None), |_| {}).unwrap_err();
+        assert!(!tmp_dir.join("evil.txt").exists());
+        assert!(!to.join("ok.txt").exists());
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(extract_system).with(Args {
+                                path: tmp_dir.join("mods.zip"),
+                                to: tmp_dir.join("mods"),
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
@@ -0,0 +1,122 @@
+use crate::archive::{ArchiveEntry, ArchiveFormat, open_tar, to_entry_path};
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use bevy::prelude::{In, Res};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx::prelude::{Pipe, side_effect};
+use bevy_flurx_ipc::prelude::*;
+use serde::Deserialize;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+api_plugin!(
+    /// You'll be able to list the entries in an archive from typescript(or js).
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const entries: window.__FLURX__.archive.ArchiveEntry[] = await window.__FLURX__.archive.list("./mods.zip", {
+    ///     dir: "Download",
+    /// });
+    /// ```
+    ArchiveListPlugin,
+    command: list
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+    format: Option<ArchiveFormat>,
+}
+
+#[command(id = "FLURX|archive::list")]
+fn list(In(args): In<Args>) -> Action<Args, ApiResult<Vec<ArchiveEntry>>> {
+    once::run(list_system)
+        .with(args)
+        .pipe(side_effect::thread::spawn(
+            |args: ApiResult<(PathBuf, ArchiveFormat)>| {
+                let (path, format) = args?;
+                read_entries(&path, format)
+            },
+        ))
+}
+
+fn list_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<(PathBuf, ArchiveFormat)> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    let format = ArchiveFormat::detect(&path, args.format)?;
+    Ok((path, format))
+}
+
+pub(super) fn read_entries(path: &Path, format: ArchiveFormat) -> ApiResult<Vec<ArchiveEntry>> {
+    match format {
+        ArchiveFormat::Zip => {
+            let mut archive = zip::ZipArchive::new(File::open(path)?)?;
+            (0..archive.len())
+                .map(|i| {
+                    let file = archive.by_index_raw(i)?;
+                    Ok(ArchiveEntry {
+                        path: file.name().trim_end_matches('/').to_string(),
+                        size: file.size(),
+                        is_dir: file.is_dir(),
+                    })
+                })
+                .collect()
+        }
+        ArchiveFormat::Tar | ArchiveFormat::TarGz => {
+            let mut archive = open_tar(path, format)?;
+            archive
+                .entries()?
+                .map(|entry| {
+                    let entry = entry?;
+                    Ok(ArchiveEntry {
+                        path: to_entry_path(&entry.path()?),
+                        size: entry.size(),
+                        is_dir: entry.header().entry_type().is_dir(),
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::archive::list::{Args, list_system};
+    use crate::fs::AllowPaths;
+    use crate::tests::test_app;
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(list_system).with(Args {
+                                path: std::env::temp_dir().join("mods.zip"),
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
@@ -65,4 +65,78 @@ pub mod fs {
     }
 
     impl Error for NotPermittedPath {}
+
+    #[derive(Debug)]
+    pub(crate) struct UnknownFileHandle(pub u64);
+
+    impl Display for UnknownFileHandle {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_fmt(format_args!("The file handle {} is not opened", self.0))
+        }
+    }
+
+    impl Error for UnknownFileHandle {}
+
+    #[derive(Debug)]
+    pub(crate) struct FileLocked(pub std::path::PathBuf);
+
+    impl Display for FileLocked {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_fmt(format_args!(
+                "{} is locked by another writer",
+                self.0.display()
+            ))
+        }
+    }
+
+    impl Error for FileLocked {}
+
+    #[derive(Debug)]
+    pub(crate) struct UnresolvedBaseDirectory(pub crate::fs::BaseDirectory);
+
+    impl Display for UnresolvedBaseDirectory {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_fmt(format_args!(
+                "Could not resolve the base directory {:?}; the app-specific directories require AppIdentifier",
+                self.0
+            ))
+        }
+    }
+
+    impl Error for UnresolvedBaseDirectory {}
+}
+
+#[cfg(feature = "archive")]
+pub mod archive {
+    use std::error::Error;
+    use std::fmt::{Display, Formatter};
+    use std::path::PathBuf;
+
+    #[derive(Debug)]
+    pub(crate) struct UnsupportedArchive(pub PathBuf);
+
+    impl Display for UnsupportedArchive {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_fmt(format_args!(
+                "Could not detect the archive format of {}",
+                self.0.display()
+            ))
+        }
+    }
+
+    impl Error for UnsupportedArchive {}
+
+    #[derive(Debug)]
+    pub(crate) struct UnsafeArchiveEntry(pub String);
+
+    impl Display for UnsafeArchiveEntry {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_fmt(format_args!(
+                "The archive entry {} points outside of the destination",
+                self.0
+            ))
+        }
+    }
+
+    impl Error for UnsafeArchiveEntry {}
 }
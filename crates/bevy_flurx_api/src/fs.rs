@@ -1,31 +1,47 @@
 //! Provides mechanism to access file systems from webview.
 
+mod copy_dir;
 mod copy_file;
 mod create_dir;
 mod exists;
+mod file_handle;
+mod glob;
+mod metadata;
+mod move_path;
 mod read_dir;
 mod read_file;
 mod remove_dir;
 mod remove_file;
 mod rename_file;
+mod trash;
+mod watch;
 mod write_file;
 
-use crate::error::fs::NotPermittedPath;
+use crate::error::fs::{NotPermittedPath, UnresolvedBaseDirectory};
 use crate::error::{ApiError, ApiResult};
 use bevy::app::{Plugin, PluginGroup, PluginGroupBuilder};
 use bevy::prelude::{
-    Reflect, ReflectDefault, ReflectDeserialize, ReflectResource, ReflectSerialize, Res, Resource,
+    Condition, IntoScheduleConfigs, Reflect, ReflectDefault, ReflectDeserialize, ReflectResource,
+    ReflectSerialize, Res, ResMut, Resource, Update, resource_changed, resource_exists,
+    resource_exists_and_changed,
 };
+pub use copy_dir::FsCopyDirPlugin;
 pub use copy_file::FsCopyFilePlugin;
 pub use create_dir::FsCreateDirPlugin;
 pub use exists::FsExistsPlugin;
+pub use file_handle::FsFileHandlePlugin;
+pub use glob::FsGlobPlugin;
+pub use metadata::{FsMetadataPlugin, FsSymlinkMetadataPlugin};
+pub use move_path::FsMovePlugin;
 pub use read_dir::FsReadDirPlugin;
 pub use read_file::{FsReadBinaryFilePlugin, FsReadTextFilePlugin};
 pub use remove_dir::FsRemoveDirPlugin;
 pub use remove_file::FsRemoveFilePlugin;
 pub use rename_file::FsRenameFilePlugin;
 use serde::{Deserialize, Serialize};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+pub use trash::{FsTrashListPlugin, FsTrashPlugin, FsTrashRestorePlugin};
+pub use watch::FsWatchPlugin;
 pub use write_file::{FsWriteBinaryFilePlugin, FsWriteTextFilePlugin};
 
 /// Allows you to use all fs apis.
@@ -34,6 +50,8 @@ pub use write_file::{FsWriteBinaryFilePlugin, FsWriteTextFilePlugin};
 /// - [FsCreateDirPlugin]
 /// - [FsCopyFilePlugin]
 /// - [FsExistsPlugin]
+/// - [FsMetadataPlugin]
+/// - [FsSymlinkMetadataPlugin]
 /// - [FsReadDirPlugin]
 /// - [FsReadTextFilePlugin]
 /// - [FsReadBinaryFilePlugin]
@@ -43,6 +61,14 @@ pub use write_file::{FsWriteBinaryFilePlugin, FsWriteTextFilePlugin};
 /// - [FsWriteBinaryFilePlugin]
 /// - [FsRemoveDirPlugin]
 /// - [FsReadDirPlugin]
+/// - [FsWatchPlugin]
+/// - [FsFileHandlePlugin]
+/// - [FsGlobPlugin]
+/// - [FsCopyDirPlugin]
+/// - [FsMovePlugin]
+/// - [FsTrashPlugin]
+/// - [FsTrashListPlugin]
+/// - [FsTrashRestorePlugin]
 pub struct AllFsPlugins;
 impl PluginGroup for AllFsPlugins {
     fn build(self) -> PluginGroupBuilder {
@@ -51,6 +77,8 @@ impl PluginGroup for AllFsPlugins {
             .add(FsCreateDirPlugin)
             .add(FsCopyFilePlugin)
             .add(FsExistsPlugin)
+            .add(FsMetadataPlugin)
+            .add(FsSymlinkMetadataPlugin)
             .add(FsReadDirPlugin)
             .add(FsReadTextFilePlugin)
             .add(FsReadBinaryFilePlugin)
@@ -60,20 +88,96 @@ impl PluginGroup for AllFsPlugins {
             .add(FsWriteTextFilePlugin)
             .add(FsRemoveDirPlugin)
             .add(FsReadDirPlugin)
+            .add(FsWatchPlugin)
+            .add(FsFileHandlePlugin)
+            .add(FsGlobPlugin)
+            .add(FsCopyDirPlugin)
+            .add(FsMovePlugin)
+            .add(FsTrashPlugin)
+            .add(FsTrashListPlugin)
+            .add(FsTrashRestorePlugin)
     }
 }
 
 /// Registers the type Fs resources in the AppTypeRegister.
+///
+/// This plugin also grants the access to the app-specific directories if [`AppIdentifier::grant_allow_paths`] is `true`.
 pub struct FsRegisterTypePlugin;
 impl Plugin for FsRegisterTypePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.register_type::<AllowPaths>();
+        app.register_type::<AllowPaths>()
+            .register_type::<AppIdentifier>()
+            .add_systems(
+                Update,
+                grant_app_directories.run_if(resource_exists::<AppIdentifier>.and(
+                    resource_changed::<AppIdentifier>.or(resource_exists_and_changed::<AllowPaths>),
+                )),
+            );
+    }
+}
+
+/// The identifier of the application such as `com.example.game`.
+///
+/// It is used to resolve the app-specific directories, `AppData`, `AppConfig`, `AppCache` and `AppLog`,
+/// so that each application doesn't write directly into the user's directories.
+/// If this resource is not inserted in the application, these directories can't be resolved.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx_api::fs::AppIdentifier;
+///
+/// App::new().insert_resource(AppIdentifier::new("com.example.game").grant_allow_paths());
+/// ```
+#[derive(Debug, Resource, Reflect, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[reflect(Resource, Serialize, Deserialize)]
+pub struct AppIdentifier {
+    identifier: String,
+    grant_allow_paths: bool,
+}
+
+impl AppIdentifier {
+    /// Creates a new [`AppIdentifier`].
+    pub fn new(identifier: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            grant_allow_paths: false,
+        }
+    }
+
+    /// Adds the app-specific directories to [`AllowPaths`] automatically.
+    ///
+    /// It has no effect if [`AllowPaths`] is not inserted, since all files are accessible in that case.
+    pub fn grant_allow_paths(mut self) -> Self {
+        self.grant_allow_paths = true;
+        self
+    }
+
+    /// Returns the identifier.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    /// Returns the app-specific directories.
+    pub fn app_dirs(&self) -> Vec<PathBuf> {
+        [
+            BaseDirectory::AppData,
+            BaseDirectory::AppConfig,
+            BaseDirectory::AppCache,
+            BaseDirectory::AppLog,
+        ]
+        .iter()
+        .filter_map(|base| base.as_path(Some(self)))
+        .collect()
     }
 }
 
 /// Represents the list of the paths accessible from [crate::fs] bevy_flurx_api.
 ///
 /// If this resource is not inserted in the application, bevy_flurx_api has access to all files.
+/// The paths containing `..` are always denied, since they could escape from the allowed directories.
 #[derive(Debug, Resource, Reflect, Default, Clone, Serialize, Deserialize)]
 #[reflect(Resource, Default, Serialize, Deserialize)]
 pub struct AllowPaths(Vec<PathBuf>);
@@ -107,12 +211,24 @@ impl AllowPaths {
 
     fn check_accessible(&self, path: impl AsRef<Path>) -> bool {
         let path = path.as_ref();
+        // `starts_with` compares the components lexically, so `allow/../secret` would pass.
+        // The path is not normalized since `..` after a symlink is resolved from the link target.
+        if path
+            .components()
+            .any(|component| component == Component::ParentDir)
+        {
+            return false;
+        }
         self.0.iter().any(|allow_path| path.starts_with(allow_path))
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum BaseDirectory {
+pub(crate) enum BaseDirectory {
+    AppData,
+    AppConfig,
+    AppCache,
+    AppLog,
     ConfigLocal,
     Data,
     LocalData,
@@ -134,8 +250,12 @@ enum BaseDirectory {
 }
 
 impl BaseDirectory {
-    fn as_path(&self) -> Option<PathBuf> {
+    pub(crate) fn as_path(&self, identifier: Option<&AppIdentifier>) -> Option<PathBuf> {
         match self {
+            BaseDirectory::AppData => Some(dirs::data_dir()?.join(identifier?.as_str())),
+            BaseDirectory::AppConfig => Some(dirs::config_dir()?.join(identifier?.as_str())),
+            BaseDirectory::AppCache => Some(dirs::cache_dir()?.join(identifier?.as_str())),
+            BaseDirectory::AppLog => app_log_dir(identifier?),
             BaseDirectory::Data => dirs::data_dir(),
             BaseDirectory::LocalData => dirs::data_local_dir(),
             BaseDirectory::Audio => dirs::audio_dir(),
@@ -158,12 +278,40 @@ impl BaseDirectory {
     }
 }
 
-fn join_path_if_need(base: &Option<BaseDirectory>, path: PathBuf) -> PathBuf {
-    if let Some(base) = base.and_then(|base| base.as_path()) {
-        base.join(path)
-    } else {
-        path
-    }
+#[cfg(target_os = "macos")]
+fn app_log_dir(identifier: &AppIdentifier) -> Option<PathBuf> {
+    Some(
+        dirs::home_dir()?
+            .join("Library/Logs")
+            .join(identifier.as_str()),
+    )
+}
+
+#[cfg(not(target_os = "macos"))]
+fn app_log_dir(identifier: &AppIdentifier) -> Option<PathBuf> {
+    Some(
+        dirs::data_local_dir()?
+            .join(identifier.as_str())
+            .join("logs"),
+    )
+}
+
+/// Joins the path to the base directory if it is specified.
+///
+/// Returns an error if the base directory can't be resolved, e.g. the app-specific directories without [`AppIdentifier`],
+/// instead of falling back to the path relative to the current directory.
+pub(crate) fn join_path_if_need(
+    base: &Option<BaseDirectory>,
+    path: PathBuf,
+    identifier: &Option<Res<AppIdentifier>>,
+) -> ApiResult<PathBuf> {
+    let Some(base) = base else {
+        return Ok(path);
+    };
+    let Some(base_path) = base.as_path(identifier.as_deref()) else {
+        return Err(ApiError::from(UnresolvedBaseDirectory(*base)));
+    };
+    Ok(base_path.join(path))
 }
 
 pub(crate) fn error_if_not_accessible(
@@ -177,3 +325,81 @@ pub(crate) fn error_if_not_accessible(
     }
     Ok(())
 }
+
+fn grant_app_directories(identifier: Res<AppIdentifier>, allow_paths: Option<ResMut<AllowPaths>>) {
+    let Some(mut allow_paths) = allow_paths else {
+        return;
+    };
+    if !identifier.grant_allow_paths {
+        return;
+    }
+    for dir in identifier.app_dirs() {
+        if !allow_paths.0.contains(&dir) {
+            allow_paths.add(dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fs::{AllowPaths, AppIdentifier, BaseDirectory, FsRegisterTypePlugin};
+    use crate::tests::test_app;
+
+    #[test]
+    fn app_data_dir() {
+        let identifier = AppIdentifier::new("com.example.game");
+        assert_eq!(
+            BaseDirectory::AppData.as_path(Some(&identifier)),
+            dirs::data_dir().map(|dir| dir.join("com.example.game"))
+        );
+        assert_eq!(BaseDirectory::AppData.as_path(None), None);
+    }
+
+    #[test]
+    fn grant_app_directories() {
+        let mut app = test_app();
+        app.add_plugins(FsRegisterTypePlugin)
+            .insert_resource(AllowPaths::default())
+            .insert_resource(AppIdentifier::new("com.example.game").grant_allow_paths());
+        app.update();
+
+        let identifier = AppIdentifier::new("com.example.game");
+        let allow_paths = app.world().resource::<AllowPaths>();
+        for dir in identifier.app_dirs() {
+            assert!(allow_paths.check_accessible(dir.join("save.json")));
+        }
+    }
+
+    #[test]
+    fn not_grant_app_directories_by_default() {
+        let mut app = test_app();
+        app.add_plugins(FsRegisterTypePlugin)
+            .insert_resource(AllowPaths::default())
+            .insert_resource(AppIdentifier::new("com.example.game"));
+        app.update();
+
+        assert!(app.world().resource::<AllowPaths>().0.is_empty());
+    }
+
+    #[test]
+    fn grant_app_directories_when_allow_paths_inserted_later() {
+        let mut app = test_app();
+        app.add_plugins(FsRegisterTypePlugin)
+            .insert_resource(AppIdentifier::new("com.example.game").grant_allow_paths());
+        app.update();
+        app.insert_resource(AllowPaths::default());
+        app.update();
+
+        let identifier = AppIdentifier::new("com.example.game");
+        let allow_paths = app.world().resource::<AllowPaths>();
+        assert_eq!(allow_paths.0, identifier.app_dirs());
+    }
+
+    #[test]
+    fn deny_parent_dir() {
+        let allow_paths = AllowPaths::new(["allow"]);
+        assert!(allow_paths.check_accessible("allow/save.json"));
+        assert!(!allow_paths.check_accessible("allow/../secret"));
+        assert!(!allow_paths.check_accessible("allow/dir/../../secret"));
+    }
+}
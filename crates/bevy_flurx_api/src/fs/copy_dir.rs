@@ -0,0 +1,378 @@
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use crate::progress::spawn_with_progress;
+use bevy::prelude::{In, Res, Update};
+use bevy_flurx::action::once;
+use bevy_flurx::task::ReactorTask;
+use bevy_flurx_ipc::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+
+api_plugin!(
+    /// You'll be able to copy a directory recursively from typescript(or js).
+    ///
+    /// The files are copied in another thread.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// await window.__FLURX__.fs.copyDir("./templates/default", "./mods/new", {
+    ///     onProgress: (progress) => {
+    ///         console.log(`${progress.copiedBytes} / ${progress.totalBytes}`);
+    ///     },
+    /// });
+    /// ```
+    FsCopyDirPlugin,
+    command: copy_dir
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    from: PathBuf,
+    to: PathBuf,
+    #[serde(rename = "fromBaseDir")]
+    from_base_dir: Option<BaseDirectory>,
+    #[serde(rename = "toBaseDir")]
+    to_base_dir: Option<BaseDirectory>,
+    /// Whether to overwrite the files that already exist in the destination.
+    #[serde(default)]
+    overwrite: bool,
+    /// The event id which the progress is emitted to.
+    #[serde(rename = "progressEventId")]
+    progress_event_id: Option<String>,
+}
+
+/// The progress of copying a directory.
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub(crate) struct CopyProgress {
+    #[serde(rename = "copiedFiles")]
+    pub(crate) copied_files: u64,
+    #[serde(rename = "totalFiles")]
+    pub(crate) total_files: u64,
+    #[serde(rename = "copiedBytes")]
+    pub(crate) copied_bytes: u64,
+    #[serde(rename = "totalBytes")]
+    pub(crate) total_bytes: u64,
+}
+
+#[command(id = "FLURX|fs::copy_dir")]
+async fn copy_dir(
+    In(args): In<Args>,
+    WebviewEntity(webview_entity): WebviewEntity,
+    task: ReactorTask,
+) -> ApiResult {
+    let overwrite = args.overwrite;
+    let progress_event_id = args.progress_event_id.clone();
+    let (from, to) = task
+        .will(Update, once::run(copy_dir_system).with(args))
+        .await?;
+    spawn_with_progress(&task, webview_entity, progress_event_id, move |tx| {
+        copy_dir_all(&from, &to, overwrite, |progress| {
+            let _ = tx.send(progress);
+        })
+    })
+    .await?;
+    Ok(())
+}
+
+fn copy_dir_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<(PathBuf, PathBuf)> {
+    let from = join_path_if_need(&args.from_base_dir, args.from, &identifier)?;
+    let to = join_path_if_need(&args.to_base_dir, args.to, &identifier)?;
+    error_if_not_accessible(&from, &scope)?;
+    error_if_not_accessible(&to, &scope)?;
+    Ok((from, to))
+}
+
+/// Copies the directory recursively, and reports the progress after each file is copied.
+///
+/// The symlinks are copied as links instead of the files they point to,
+/// since the targets may be outside of [`AllowPaths`].
+pub(crate) fn copy_dir_all(
+    from: &Path,
+    to: &Path,
+    overwrite: bool,
+    mut on_progress: impl FnMut(CopyProgress),
+) -> io::Result<()> {
+    // Compares the resolved paths, since `to` may reach `from` through a symlink or `..`.
+    if resolve(to)?.starts_with(from.canonicalize()?) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Cannot copy a directory into itself",
+        ));
+    }
+    let mut entries = Vec::new();
+    collect_entries(from, Path::new(""), &mut entries)?;
+    let mut progress = CopyProgress {
+        total_files: entries.len() as u64,
+        total_bytes: entries.iter().map(|entry| entry.size).sum(),
+        ..Default::default()
+    };
+    on_progress(progress);
+
+    std::fs::create_dir_all(to)?;
+    for entry in entries {
+        let dest = to.join(&entry.relative);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // `symlink_metadata` finds the existing symlinks even if they are dangling.
+        if let Ok(metadata) = dest.symlink_metadata() {
+            if !overwrite {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{} already exists", dest.display()),
+                ));
+            }
+            // Copying onto the existing symlink would write into the file it points to.
+            if entry.link.is_some() || metadata.file_type().is_symlink() {
+                std::fs::remove_file(&dest)?;
+            }
+        }
+        match entry.link {
+            Some(is_dir) => copy_symlink(&from.join(&entry.relative), &dest, is_dir)?,
+            None => {
+                std::fs::copy(from.join(&entry.relative), dest)?;
+            }
+        }
+        progress.copied_files += 1;
+        progress.copied_bytes += entry.size;
+        on_progress(progress);
+    }
+    copy_empty_dirs(from, to)
+}
+
+struct CopyEntry {
+    /// The path relative to the source directory.
+    relative: PathBuf,
+    size: u64,
+    /// Whether the entry is a symlink pointing to a directory, if it is a symlink.
+    link: Option<bool>,
+}
+
+fn collect_entries(root: &Path, relative: &Path, entries: &mut Vec<CopyEntry>) -> io::Result<()> {
+    for entry in std::fs::read_dir(root.join(relative))? {
+        let entry = entry?;
+        let path = relative.join(entry.file_name());
+        // `DirEntry::file_type` doesn't follow the symlinks.
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_entries(root, &path, entries)?;
+        } else if file_type.is_symlink() {
+            entries.push(CopyEntry {
+                relative: path,
+                size: 0,
+                link: Some(is_symlink_dir(&file_type)),
+            });
+        } else {
+            entries.push(CopyEntry {
+                relative: path,
+                size: entry.metadata()?.len(),
+                link: None,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Creates the symlink pointing to the same target as `link`.
+pub(crate) fn copy_symlink(link: &Path, dest: &Path, is_dir: bool) -> io::Result<()> {
+    let target = std::fs::read_link(link)?;
+    #[cfg(unix)]
+    {
+        let _ = is_dir;
+        std::os::unix::fs::symlink(target, dest)
+    }
+    #[cfg(windows)]
+    {
+        if is_dir {
+            std::os::windows::fs::symlink_dir(target, dest)
+        } else {
+            std::os::windows::fs::symlink_file(target, dest)
+        }
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (target, dest, is_dir);
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+}
+
+/// Returns whether the symlink points to a directory.
+///
+/// Only Windows distinguishes the symlinks to directories from the ones to files.
+pub(crate) fn is_symlink_dir(file_type: &std::fs::FileType) -> bool {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::FileTypeExt;
+        file_type.is_symlink_dir()
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = file_type;
+        false
+    }
+}
+
+/// Resolves the symlinks and `..` in the path that may not exist yet.
+///
+/// The nearest existing ancestor is canonicalized, and the rest of the path is joined to it.
+fn resolve(path: &Path) -> io::Result<PathBuf> {
+    let mut rest = Vec::new();
+    let mut existing = path;
+    loop {
+        match existing.canonicalize() {
+            Ok(resolved) => {
+                return Ok(rest
+                    .into_iter()
+                    .rev()
+                    .fold(resolved, |path, name| path.join(name)));
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+                    return Err(e);
+                };
+                rest.push(name);
+                existing = if parent.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    parent
+                };
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn copy_empty_dirs(from: &Path, to: &Path) -> io::Result<()> {
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            let dest = to.join(entry.file_name());
+            std::fs::create_dir_all(&dest)?;
+            copy_empty_dirs(&entry.path(), &dest)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::fs::AllowPaths;
+    use crate::fs::copy_dir::{Args, CopyProgress, copy_dir_all, copy_dir_system};
+    use crate::tests::test_app;
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use std::fs::create_dir_all;
+
+    fn setup_template(tmp_dir: &std::path::Path) -> std::path::PathBuf {
+        let _ = std::fs::remove_dir_all(tmp_dir);
+        let from = tmp_dir.join("template");
+        create_dir_all(from.join("sub")).unwrap();
+        create_dir_all(from.join("empty")).unwrap();
+        std::fs::write(from.join("a.ron"), "a").unwrap();
+        std::fs::write(from.join("sub").join("b.ron"), "bb").unwrap();
+        from
+    }
+
+    #[test]
+    fn copy_dir_recursively() {
+        let tmp_dir = std::env::temp_dir().join("copy_dir_recursively");
+        let from = setup_template(&tmp_dir);
+        let to = tmp_dir.join("copied");
+        let mut progresses = Vec::new();
+        copy_dir_all(&from, &to, false, |progress| progresses.push(progress)).unwrap();
+
+        assert_eq!(std::fs::read_to_string(to.join("a.ron")).unwrap(), "a");
+        assert_eq!(
+            std::fs::read_to_string(to.join("sub").join("b.ron")).unwrap(),
+            "bb"
+        );
+        assert!(to.join("empty").is_dir());
+        assert_eq!(
+            progresses.last(),
+            Some(&CopyProgress {
+                copied_files: 2,
+                total_files: 2,
+                copied_bytes: 3,
+                total_bytes: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn err_if_exists_without_overwrite() {
+        let tmp_dir = std::env::temp_dir().join("copy_dir_without_overwrite");
+        let from = setup_template(&tmp_dir);
+        let to = tmp_dir.join("copied");
+        copy_dir_all(&from, &to, false, |_| {}).unwrap();
+        copy_dir_all(&from, &to, false, |_| {}).unwrap_err();
+        copy_dir_all(&from, &to, true, |_| {}).unwrap();
+    }
+
+    #[test]
+    fn err_if_copy_into_itself() {
+        let tmp_dir = std::env::temp_dir().join("copy_dir_into_itself");
+        let from = setup_template(&tmp_dir);
+        copy_dir_all(&from, &from.join("sub"), false, |_| {}).unwrap_err();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_symlinks_as_links() {
+        let tmp_dir = std::env::temp_dir().join("copy_dir_symlinks_as_links");
+        let from = setup_template(&tmp_dir);
+        let secret = tmp_dir.join("secret.txt");
+        std::fs::write(&secret, "secret").unwrap();
+        std::os::unix::fs::symlink(&secret, from.join("link")).unwrap();
+        let to = tmp_dir.join("copied");
+        copy_dir_all(&from, &to, false, |_| {}).unwrap();
+
+        let copied = to.join("link");
+        assert!(copied.symlink_metadata().unwrap().file_type().is_symlink());
+        assert_eq!(std::fs::read_link(copied).unwrap(), secret);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn err_if_copy_into_itself_through_symlink() {
+        let tmp_dir = std::env::temp_dir().join("copy_dir_into_itself_through_symlink");
+        let from = setup_template(&tmp_dir);
+        let link = tmp_dir.join("link");
+        std::os::unix::fs::symlink(&from, &link).unwrap();
+        copy_dir_all(&from, &link.join("copied"), false, |_| {}).unwrap_err();
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(copy_dir_system).with(Args {
+                                from: tmp_dir.join("from"),
+                                to: tmp_dir.join("to"),
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
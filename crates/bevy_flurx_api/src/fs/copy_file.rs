@@ -1,5 +1,7 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -36,9 +38,13 @@ fn copy_file(In(args): In<Args>) -> Action<Args, ApiResult> {
     once::run(copy_file_system).with(args)
 }
 
-fn copy_file_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) -> ApiResult {
-    let from = join_path_if_need(&args.from_base_dir, args.from);
-    let to = join_path_if_need(&args.to_base_dir, args.to);
+fn copy_file_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult {
+    let from = join_path_if_need(&args.from_base_dir, args.from, &identifier)?;
+    let to = join_path_if_need(&args.to_base_dir, args.to, &identifier)?;
     error_if_not_accessible(&from, &scope)?;
     error_if_not_accessible(&to, &scope)?;
     std::fs::copy(from, to)?;
@@ -1,5 +1,7 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -31,8 +33,12 @@ fn create_dir(In(args): In<CreateDirArgs>) -> Action<CreateDirArgs, ApiResult> {
     once::run(create_dir_system).with(args)
 }
 
-fn create_dir_system(In(args): In<CreateDirArgs>, scope: Option<Res<AllowPaths>>) -> ApiResult {
-    let path = join_path_if_need(&args.dir, args.path);
+fn create_dir_system(
+    In(args): In<CreateDirArgs>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
     if std::fs::exists(&path).is_ok_and(|exists| exists) {
         return Ok(());
@@ -1,5 +1,7 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -30,8 +32,12 @@ fn exists(In(args): In<Args>) -> Action<Args, ApiResult<bool>> {
     once::run(exists_system).with(args)
 }
 
-fn exists_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) -> ApiResult<bool> {
-    let path = join_path_if_need(&args.dir, args.path);
+fn exists_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<bool> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
     Ok(std::fs::exists(path)?)
 }
@@ -0,0 +1,471 @@
+use crate::error::ApiResult;
+use crate::error::fs::UnknownFileHandle;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{
+    Added, App, Entity, In, Plugin, PostUpdate, Query, Res, ResMut, Resource, Update, With,
+};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx_ipc::prelude::*;
+use serde::Deserialize;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+/// You'll be able to read and write a large file in chunks from typescript(or js).
+///
+/// The opened file is identified by the handle returned from `fs.open`,
+/// and can be used only from the webview that opened it.
+/// The handles are closed on `fs.close` or when the webview is despawned.
+///
+/// ## Typescript Code Example
+///
+/// ```ts
+/// const handle: number = await window.__FLURX__.fs.open("./replay.bin", {
+///     dir: "Download",
+///     read: true,
+/// });
+/// const chunk: Uint8Array = await window.__FLURX__.fs.readChunk(handle, 1024 * 1024, {
+///     offset: 0,
+/// });
+/// await window.__FLURX__.fs.close(handle);
+/// ```
+pub struct FsFileHandlePlugin;
+
+impl Plugin for FsFileHandlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FileHandles>()
+            .add_systems(Update, close_orphan_handles)
+            .add_systems(
+                PostUpdate,
+                |mut views: Query<&mut IpcHandlers, Added<IpcHandlers>>| {
+                    for mut handlers in views.iter_mut() {
+                        handlers.register(open());
+                        handlers.register(read_chunk());
+                        handlers.register(write_chunk());
+                        handlers.register(seek());
+                        handlers.register(close());
+                    }
+                },
+            );
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct OpenArgs {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+    /// Default is `true`.
+    read: Option<bool>,
+    write: Option<bool>,
+    append: Option<bool>,
+    create: Option<bool>,
+    truncate: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+struct ReadChunkArgs {
+    handle: u64,
+    /// The maximum number of bytes to read.
+    length: u64,
+    /// If specified, reads from this position instead of the current position.
+    offset: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+struct WriteChunkArgs {
+    handle: u64,
+    contents: Vec<u8>,
+    /// If specified, writes from this position instead of the current position.
+    offset: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+enum SeekWhence {
+    #[default]
+    Start,
+    Current,
+    End,
+}
+
+#[derive(Deserialize, Default)]
+struct SeekArgs {
+    handle: u64,
+    offset: i64,
+    #[serde(default)]
+    whence: SeekWhence,
+}
+
+struct FileHandle {
+    webview_entity: Entity,
+    file: File,
+}
+
+/// The files opened from webviews associated with their handles.
+#[derive(Resource, Default)]
+struct FileHandles {
+    next_handle: u64,
+    files: HashMap<u64, FileHandle>,
+}
+
+impl FileHandles {
+    fn get_mut(&mut self, handle: u64, webview_entity: Entity) -> ApiResult<&mut File> {
+        match self.files.get_mut(&handle) {
+            Some(file) if file.webview_entity == webview_entity => Ok(&mut file.file),
+            _ => Err(UnknownFileHandle(handle).into()),
+        }
+    }
+}
+
+#[command(id = "FLURX|fs::open")]
+fn open(
+    In(args): In<OpenArgs>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(OpenArgs, Entity), ApiResult<u64>> {
+    once::run(open_system).with((args, webview_entity))
+}
+
+#[command(id = "FLURX|fs::read_chunk")]
+fn read_chunk(
+    In(args): In<ReadChunkArgs>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(ReadChunkArgs, Entity), ApiResult<Vec<u8>>> {
+    once::run(read_chunk_system).with((args, webview_entity))
+}
+
+#[command(id = "FLURX|fs::write_chunk")]
+fn write_chunk(
+    In(args): In<WriteChunkArgs>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(WriteChunkArgs, Entity), ApiResult> {
+    once::run(write_chunk_system).with((args, webview_entity))
+}
+
+#[command(id = "FLURX|fs::seek")]
+fn seek(
+    In(args): In<SeekArgs>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(SeekArgs, Entity), ApiResult<u64>> {
+    once::run(seek_system).with((args, webview_entity))
+}
+
+#[command(id = "FLURX|fs::close")]
+fn close(
+    In(handle): In<u64>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(u64, Entity), ApiResult> {
+    once::run(close_system).with((handle, webview_entity))
+}
+
+fn open_system(
+    In((args, webview_entity)): In<(OpenArgs, Entity)>,
+    mut handles: ResMut<FileHandles>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<u64> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    let file = OpenOptions::new()
+        .read(args.read.unwrap_or(true))
+        .write(args.write.unwrap_or(false))
+        .append(args.append.unwrap_or(false))
+        .create(args.create.unwrap_or(false))
+        .truncate(args.truncate.unwrap_or(false))
+        .open(path)?;
+    let handle = handles.next_handle;
+    handles.next_handle += 1;
+    handles.files.insert(
+        handle,
+        FileHandle {
+            webview_entity,
+            file,
+        },
+    );
+    Ok(handle)
+}
+
+fn read_chunk_system(
+    In((args, webview_entity)): In<(ReadChunkArgs, Entity)>,
+    mut handles: ResMut<FileHandles>,
+) -> ApiResult<Vec<u8>> {
+    let file = handles.get_mut(args.handle, webview_entity)?;
+    if let Some(offset) = args.offset {
+        file.seek(SeekFrom::Start(offset))?;
+    }
+    let mut buf = Vec::new();
+    file.take(args.length).read_to_end(&mut buf)?;
+    Ok(buf)
+}
+
+fn write_chunk_system(
+    In((args, webview_entity)): In<(WriteChunkArgs, Entity)>,
+    mut handles: ResMut<FileHandles>,
+) -> ApiResult {
+    let file = handles.get_mut(args.handle, webview_entity)?;
+    if let Some(offset) = args.offset {
+        file.seek(SeekFrom::Start(offset))?;
+    }
+    file.write_all(&args.contents)?;
+    Ok(())
+}
+
+fn seek_system(
+    In((args, webview_entity)): In<(SeekArgs, Entity)>,
+    mut handles: ResMut<FileHandles>,
+) -> ApiResult<u64> {
+    let file = handles.get_mut(args.handle, webview_entity)?;
+    let pos = match args.whence {
+        SeekWhence::Start => SeekFrom::Start(args.offset.max(0) as u64),
+        SeekWhence::Current => SeekFrom::Current(args.offset),
+        SeekWhence::End => SeekFrom::End(args.offset),
+    };
+    Ok(file.seek(pos)?)
+}
+
+fn close_system(
+    In((handle, webview_entity)): In<(u64, Entity)>,
+    mut handles: ResMut<FileHandles>,
+) -> ApiResult {
+    handles.get_mut(handle, webview_entity)?;
+    handles.files.remove(&handle);
+    Ok(())
+}
+
+fn close_orphan_handles(mut handles: ResMut<FileHandles>, webviews: Query<(), With<IpcHandlers>>) {
+    handles
+        .files
+        .retain(|_, file| webviews.contains(file.webview_entity));
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::fs::AllowPaths;
+    use crate::fs::file_handle::{
+        FileHandles, FsFileHandlePlugin, OpenArgs, ReadChunkArgs, SeekArgs, SeekWhence,
+        WriteChunkArgs, close_system, open_system, read_chunk_system, seek_system,
+        write_chunk_system,
+    };
+    use crate::tests::{assert_api_registered, test_app};
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use bevy_flurx_ipc::prelude::IpcHandlers;
+    use std::fs::create_dir_all;
+
+    #[test]
+    fn registered_file_handle_apis() {
+        for id in [
+            "FLURX|fs::open",
+            "FLURX|fs::read_chunk",
+            "FLURX|fs::write_chunk",
+            "FLURX|fs::seek",
+            "FLURX|fs::close",
+        ] {
+            assert_api_registered(FsFileHandlePlugin, id);
+        }
+    }
+
+    #[test]
+    fn write_and_read_chunks() {
+        let mut app = test_app();
+        app.add_plugins(FsFileHandlePlugin);
+        let webview_entity = app.world_mut().spawn(IpcHandlers::default()).id();
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let tmp_dir = std::env::temp_dir().join("file_handle_write_and_read_chunks");
+                let _ = create_dir_all(&tmp_dir);
+                let handle = task
+                    .will(
+                        Update,
+                        once::run(open_system).with((
+                            OpenArgs {
+                                path: tmp_dir.join("chunks.bin"),
+                                write: Some(true),
+                                create: Some(true),
+                                truncate: Some(true),
+                                ..default()
+                            },
+                            webview_entity,
+                        )),
+                    )
+                    .await
+                    .unwrap();
+                task.will(
+                    Update,
+                    once::run(write_chunk_system)
+                        .with((
+                            WriteChunkArgs {
+                                handle,
+                                contents: vec![0, 1, 2],
+                                offset: None,
+                            },
+                            webview_entity,
+                        ))
+                        .then(once::run(write_chunk_system).with((
+                            WriteChunkArgs {
+                                handle,
+                                contents: vec![3, 4],
+                                offset: None,
+                            },
+                            webview_entity,
+                        ))),
+                )
+                .await
+                .unwrap();
+
+                let chunk = task
+                    .will(
+                        Update,
+                        once::run(read_chunk_system).with((
+                            ReadChunkArgs {
+                                handle,
+                                length: 2,
+                                offset: Some(1),
+                            },
+                            webview_entity,
+                        )),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(chunk, vec![1, 2]);
+
+                let pos = task
+                    .will(
+                        Update,
+                        once::run(seek_system).with((
+                            SeekArgs {
+                                handle,
+                                offset: -1,
+                                whence: SeekWhence::End,
+                            },
+                            webview_entity,
+                        )),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(pos, 4);
+                let chunk = task
+                    .will(
+                        Update,
+                        once::run(read_chunk_system).with((
+                            ReadChunkArgs {
+                                handle,
+                                length: 10,
+                                offset: None,
+                            },
+                            webview_entity,
+                        )),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(chunk, vec![4]);
+
+                task.will(
+                    Update,
+                    once::run(close_system).with((handle, webview_entity)),
+                )
+                .await
+                .unwrap();
+            }));
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(app.world().resource::<FileHandles>().files.is_empty());
+    }
+
+    #[test]
+    fn err_if_handle_opened_by_other_webview() {
+        let mut app = test_app();
+        app.add_plugins(FsFileHandlePlugin);
+        let webview1 = app.world_mut().spawn(IpcHandlers::default()).id();
+        let webview2 = app.world_mut().spawn(IpcHandlers::default()).id();
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let tmp_dir = std::env::temp_dir().join("file_handle_other_webview");
+                let _ = create_dir_all(&tmp_dir);
+                let path = tmp_dir.join("hello.txt");
+                std::fs::write(&path, "hello").unwrap();
+                let handle = task
+                    .will(
+                        Update,
+                        once::run(open_system).with((OpenArgs { path, ..default() }, webview1)),
+                    )
+                    .await
+                    .unwrap();
+                let result = task
+                    .will(
+                        Update,
+                        once::run(read_chunk_system).with((
+                            ReadChunkArgs {
+                                handle,
+                                length: 5,
+                                offset: None,
+                            },
+                            webview2,
+                        )),
+                    )
+                    .await;
+                result.unwrap_err();
+            }));
+        for _ in 0..3 {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn close_handles_when_webview_despawned() {
+        let mut app = test_app();
+        app.add_plugins(FsFileHandlePlugin);
+        let webview_entity = app.world_mut().spawn(IpcHandlers::default()).id();
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let tmp_dir = std::env::temp_dir().join("file_handle_despawned");
+                let _ = create_dir_all(&tmp_dir);
+                let path = tmp_dir.join("hello.txt");
+                std::fs::write(&path, "hello").unwrap();
+                task.will(
+                    Update,
+                    once::run(open_system).with((OpenArgs { path, ..default() }, webview_entity)),
+                )
+                .await
+                .unwrap();
+            }));
+        app.update();
+        assert_eq!(app.world().resource::<FileHandles>().files.len(), 1);
+
+        app.world_mut().despawn(webview_entity);
+        app.update();
+        assert!(app.world().resource::<FileHandles>().files.is_empty());
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_plugins(FsFileHandlePlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(open_system).with((
+                                OpenArgs {
+                                    path: tmp_dir.join("hello.txt"),
+                                    ..default()
+                                },
+                                Entity::PLACEHOLDER,
+                            )),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
@@ -0,0 +1,218 @@
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use bevy::prelude::{In, Res};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx::prelude::{Pipe, side_effect};
+use bevy_flurx_ipc::prelude::*;
+use globset::{GlobBuilder, GlobMatcher};
+use serde::Deserialize;
+use std::path::{Component, Path, PathBuf};
+
+api_plugin!(
+    /// You'll be able to find the paths matching a glob pattern from typescript(or js).
+    ///
+    /// The leading components of the pattern that don't contain any glob characters are the directory to search in,
+    /// and the directory is searched in another thread.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const paths: string[] = await window.__FLURX__.fs.glob("mods/**/*.ron", {
+    ///     dir: "AppData",
+    /// });
+    /// ```
+    FsGlobPlugin,
+    command: glob
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    pattern: String,
+    dir: Option<BaseDirectory>,
+    /// Whether to include the directories matching the pattern.
+    #[serde(rename = "includeDirs", default)]
+    include_dirs: bool,
+}
+
+struct GlobQuery {
+    root: PathBuf,
+    matcher: GlobMatcher,
+    max_depth: Option<usize>,
+    include_dirs: bool,
+}
+
+impl GlobQuery {
+    fn new(root: PathBuf, pattern: &Path, include_dirs: bool) -> ApiResult<Self> {
+        let pattern = pattern.to_string_lossy().replace('\\', "/");
+        let matcher = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .build()?
+            .compile_matcher();
+        Ok(Self {
+            root,
+            matcher,
+            // Without `**`, the paths deeper than the pattern never match.
+            max_depth: (!pattern.contains("**")).then(|| pattern.split('/').count()),
+            include_dirs,
+        })
+    }
+}
+
+#[command(id = "FLURX|fs::glob")]
+fn glob(In(args): In<Args>) -> Action<Args, ApiResult<Vec<PathBuf>>> {
+    glob_action(args)
+}
+
+fn glob_action(args: Args) -> Action<Args, ApiResult<Vec<PathBuf>>> {
+    once::run(glob_system)
+        .with(args)
+        .pipe(side_effect::thread::spawn(|query: ApiResult<GlobQuery>| {
+            let query = query?;
+            let mut paths = Vec::new();
+            find_paths(&query, Path::new(""), 1, &mut paths)?;
+            paths.sort();
+            Ok(paths)
+        }))
+}
+
+fn glob_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<GlobQuery> {
+    let (root, pattern) = split_pattern(Path::new(&args.pattern));
+    let root = if root.as_os_str().is_empty() && args.dir.is_none() {
+        PathBuf::from(".")
+    } else {
+        join_path_if_need(&args.dir, root, &identifier)?
+    };
+    error_if_not_accessible(&root, &scope)?;
+    GlobQuery::new(root, &pattern, args.include_dirs)
+}
+
+/// Splits the pattern into the directory without glob characters and the rest.
+fn split_pattern(pattern: &Path) -> (PathBuf, PathBuf) {
+    let mut root = PathBuf::new();
+    let mut components = pattern.components().peekable();
+    while let Some(component) = components.peek() {
+        let is_glob = matches!(component, Component::Normal(name) if name
+            .to_string_lossy()
+            .contains(['*', '?', '[', '{']));
+        if is_glob {
+            break;
+        }
+        root.push(component);
+        components.next();
+    }
+    (root, components.collect())
+}
+
+fn find_paths(
+    query: &GlobQuery,
+    relative: &Path,
+    depth: usize,
+    paths: &mut Vec<PathBuf>,
+) -> ApiResult {
+    let dir = query.root.join(relative);
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = relative.join(entry.file_name());
+        let is_dir = entry.file_type()?.is_dir();
+        if (!is_dir || query.include_dirs) && query.matcher.is_match(&path) {
+            paths.push(query.root.join(&path));
+        }
+        if is_dir && query.max_depth.is_none_or(|max_depth| depth < max_depth) {
+            find_paths(query, &path, depth + 1, paths)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::fs::AllowPaths;
+    use crate::fs::glob::{Args, glob_action, glob_system, split_pattern};
+    use crate::tests::{run_action_in_app, test_app};
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use std::fs::create_dir_all;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn split_root_and_pattern() {
+        assert_eq!(
+            split_pattern(Path::new("mods/**/*.ron")),
+            (PathBuf::from("mods"), PathBuf::from("**/*.ron"))
+        );
+        assert_eq!(
+            split_pattern(Path::new("*.ron")),
+            (PathBuf::new(), PathBuf::from("*.ron"))
+        );
+    }
+
+    #[test]
+    fn find_ron_files_recursively() {
+        let tmp_dir = std::env::temp_dir().join("glob_find_ron_files");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        create_dir_all(tmp_dir.join("mods").join("a")).unwrap();
+        std::fs::write(tmp_dir.join("mods").join("root.ron"), "").unwrap();
+        std::fs::write(tmp_dir.join("mods").join("a").join("a.ron"), "").unwrap();
+        std::fs::write(tmp_dir.join("mods").join("a").join("a.txt"), "").unwrap();
+
+        let pattern = tmp_dir.join("mods").join("**").join("*.ron");
+        assert_eq!(
+            glob_in_app(pattern.to_string_lossy().to_string()),
+            vec![
+                tmp_dir.join("mods").join("a").join("a.ron"),
+                tmp_dir.join("mods").join("root.ron"),
+            ]
+        );
+
+        let pattern = tmp_dir.join("mods").join("*.ron");
+        assert_eq!(
+            glob_in_app(pattern.to_string_lossy().to_string()),
+            vec![tmp_dir.join("mods").join("root.ron")]
+        );
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let pattern = std::env::temp_dir().join("*.ron");
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(glob_system).with(Args {
+                                pattern: pattern.to_string_lossy().to_string(),
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+
+    fn glob_in_app(pattern: String) -> Vec<PathBuf> {
+        run_action_in_app(move || {
+            glob_action(Args {
+                pattern,
+                ..default()
+            })
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,307 @@
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use bevy::prelude::{In, Res};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx_ipc::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+api_plugin!(
+    /// You'll be able to get the metadata of a file or directory from typescript(or js).
+    ///
+    /// Symbolic links are followed, so the path the link points to must also be permitted by [`AllowPaths`].
+    /// Use [`FsSymlinkMetadataPlugin`] to get the metadata of the link itself.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const metadata: window.__FLURX__.fs.FileMetadata = await window.__FLURX__.fs.metadata("./file.txt", {
+    ///     dir: "Download"
+    /// });
+    /// ```
+    FsMetadataPlugin,
+    command: metadata
+);
+
+api_plugin!(
+    /// You'll be able to get the metadata of a file or directory from typescript(or js).
+    ///
+    /// Unlike [`FsMetadataPlugin`], symbolic links are not followed.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const metadata: window.__FLURX__.fs.FileMetadata = await window.__FLURX__.fs.symlinkMetadata("./link", {
+    ///     dir: "Download"
+    /// });
+    /// ```
+    FsSymlinkMetadataPlugin,
+    command: symlink_metadata
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+}
+
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FileType {
+    File,
+    Dir,
+    Symlink,
+    Other,
+}
+
+/// The metadata of a file or directory.
+///
+/// All times are milliseconds since the unix epoch, and none if the platform doesn't support it.
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+pub(crate) struct FileMetadata {
+    #[serde(rename = "fileType")]
+    pub(crate) file_type: FileType,
+    pub(crate) size: u64,
+    pub(crate) readonly: bool,
+    pub(crate) modified: Option<u64>,
+    pub(crate) accessed: Option<u64>,
+    pub(crate) created: Option<u64>,
+    /// none if not symlink.
+    #[serde(rename = "symlinkTarget")]
+    pub(crate) symlink_target: Option<PathBuf>,
+}
+
+impl FileMetadata {
+    pub(crate) fn new(path: &Path, metadata: &Metadata) -> Self {
+        let file_type = metadata.file_type();
+        let file_type = if file_type.is_symlink() {
+            FileType::Symlink
+        } else if file_type.is_dir() {
+            FileType::Dir
+        } else if file_type.is_file() {
+            FileType::File
+        } else {
+            FileType::Other
+        };
+        Self {
+            file_type,
+            size: metadata.len(),
+            readonly: metadata.permissions().readonly(),
+            modified: as_millis(metadata.modified()),
+            accessed: as_millis(metadata.accessed()),
+            created: as_millis(metadata.created()),
+            symlink_target: if file_type == FileType::Symlink {
+                std::fs::read_link(path).ok()
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[command(id = "FLURX|fs::metadata")]
+fn metadata(In(args): In<Args>) -> Action<Args, ApiResult<FileMetadata>> {
+    once::run(metadata_system).with(args)
+}
+
+#[command(id = "FLURX|fs::symlink_metadata")]
+fn symlink_metadata(In(args): In<Args>) -> Action<Args, ApiResult<FileMetadata>> {
+    once::run(symlink_metadata_system).with(args)
+}
+
+fn metadata_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<FileMetadata> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    if std::fs::symlink_metadata(&path)?.is_symlink() {
+        error_if_not_accessible(std::fs::canonicalize(&path)?, &scope)?;
+    }
+    let metadata = std::fs::metadata(&path)?;
+    Ok(FileMetadata::new(&path, &metadata))
+}
+
+fn symlink_metadata_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<FileMetadata> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    let metadata = std::fs::symlink_metadata(&path)?;
+    Ok(FileMetadata::new(&path, &metadata))
+}
+
+fn as_millis(time: std::io::Result<SystemTime>) -> Option<u64> {
+    let duration = time.ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(duration.as_millis() as u64)
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::fs::AllowPaths;
+    use crate::fs::metadata::{
+        Args, FileMetadata, FileType, metadata_system, symlink_metadata_system,
+    };
+    use crate::tests::test_app;
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use std::fs::create_dir_all;
+
+    #[test]
+    fn file_metadata() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir().join("metadata_file");
+                let _ = create_dir_all(&tmp_dir);
+                let path = tmp_dir.join("hello.txt");
+                std::fs::write(&path, "hello").unwrap();
+                let metadata: FileMetadata = task
+                    .will(
+                        Update,
+                        once::run(metadata_system).with(Args { path, ..default() }),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(metadata.file_type, FileType::File);
+                assert_eq!(metadata.size, 5);
+                assert!(!metadata.readonly);
+                assert!(metadata.modified.is_some());
+                assert_eq!(metadata.symlink_target, None);
+            }));
+        });
+        app.update();
+    }
+
+    #[test]
+    fn dir_metadata() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir().join("metadata_dir");
+                let _ = create_dir_all(&tmp_dir);
+                let metadata: FileMetadata = task
+                    .will(
+                        Update,
+                        once::run(metadata_system).with(Args {
+                            path: tmp_dir,
+                            ..default()
+                        }),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(metadata.file_type, FileType::Dir);
+            }));
+        });
+        app.update();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_metadata() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir().join("metadata_symlink");
+                let _ = create_dir_all(&tmp_dir);
+                let target = tmp_dir.join("target.txt");
+                let link = tmp_dir.join("link");
+                std::fs::write(&target, "hello").unwrap();
+                let _ = std::fs::remove_file(&link);
+                std::os::unix::fs::symlink(&target, &link).unwrap();
+                let metadata: FileMetadata = task
+                    .will(
+                        Update,
+                        once::run(symlink_metadata_system).with(Args {
+                            path: link,
+                            ..default()
+                        }),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(metadata.file_type, FileType::Symlink);
+                assert_eq!(metadata.symlink_target, Some(target));
+            }));
+        });
+        app.update();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn err_if_symlink_target_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir().join("metadata_symlink_out_of_scope");
+                let allow = tmp_dir.join("allow");
+                let deny = tmp_dir.join("deny");
+                let _ = create_dir_all(&allow);
+                let _ = create_dir_all(&deny);
+                let allow = allow.canonicalize().unwrap();
+                let target = deny.join("secret.txt");
+                let link = allow.join("link");
+                std::fs::write(&target, "secret").unwrap();
+                let _ = std::fs::remove_file(&link);
+                std::os::unix::fs::symlink(&target, &link).unwrap();
+                let scope = AllowPaths::new([allow]);
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert()
+                            .with(scope)
+                            .then(once::run(metadata_system).with(Args {
+                                path: link.clone(),
+                                ..default()
+                            }))
+                    })
+                    .await;
+                result.unwrap_err();
+
+                let metadata: FileMetadata = task
+                    .will(
+                        Update,
+                        once::run(symlink_metadata_system).with(Args {
+                            path: link,
+                            ..default()
+                        }),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(metadata.file_type, FileType::Symlink);
+            }));
+        });
+        app.update();
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(metadata_system).with(Args {
+                                path: tmp_dir,
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
@@ -0,0 +1,153 @@
+use crate::error::ApiResult;
+use crate::fs::copy_dir::{copy_dir_all, copy_symlink, is_symlink_dir};
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use bevy::prelude::{In, Res};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx::prelude::{Pipe, side_effect};
+use bevy_flurx_ipc::prelude::*;
+use serde::Deserialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+api_plugin!(
+    /// You'll be able to move a file or directory from typescript(or js).
+    ///
+    /// Unlike `fs.renameFile`, it can be moved across devices; in that case,
+    /// it's copied to the destination and then the source is removed.
+    /// It's moved in another thread.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// await window.__FLURX__.fs.move("./mods/old", "./backup/old", {
+    ///     fromBaseDir: "AppData",
+    ///     toBaseDir: "Download",
+    /// });
+    /// ```
+    FsMovePlugin,
+    command: move_path
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    from: PathBuf,
+    to: PathBuf,
+    #[serde(rename = "fromBaseDir")]
+    from_base_dir: Option<BaseDirectory>,
+    #[serde(rename = "toBaseDir")]
+    to_base_dir: Option<BaseDirectory>,
+}
+
+#[command(id = "FLURX|fs::move")]
+fn move_path(In(args): In<Args>) -> Action<Args, ApiResult> {
+    move_action(args)
+}
+
+fn move_action(args: Args) -> Action<Args, ApiResult> {
+    once::run(move_system)
+        .with(args)
+        .pipe(side_effect::thread::spawn(
+            |paths: ApiResult<(PathBuf, PathBuf)>| {
+                let (from, to) = paths?;
+                move_all(&from, &to)?;
+                Ok(())
+            },
+        ))
+}
+
+fn move_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<(PathBuf, PathBuf)> {
+    let from = join_path_if_need(&args.from_base_dir, args.from, &identifier)?;
+    let to = join_path_if_need(&args.to_base_dir, args.to, &identifier)?;
+    error_if_not_accessible(&from, &scope)?;
+    error_if_not_accessible(&to, &scope)?;
+    Ok((from, to))
+}
+
+fn move_all(from: &Path, to: &Path) -> io::Result<()> {
+    match std::fs::rename(from, to) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            let file_type = std::fs::symlink_metadata(from)?.file_type();
+            if file_type.is_dir() {
+                copy_dir_all(from, to, false, |_| {})?;
+                std::fs::remove_dir_all(from)
+            } else if file_type.is_symlink() {
+                copy_symlink(from, to, is_symlink_dir(&file_type))?;
+                std::fs::remove_file(from)
+            } else {
+                std::fs::copy(from, to)?;
+                std::fs::remove_file(from)
+            }
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::error::ApiResult;
+    use crate::fs::AllowPaths;
+    use crate::fs::move_path::{Args, move_action, move_system};
+    use crate::tests::{run_action_in_app, test_app};
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use std::fs::create_dir_all;
+
+    #[test]
+    fn move_dir() {
+        let tmp_dir = std::env::temp_dir().join("move_dir");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        let from = tmp_dir.join("from");
+        create_dir_all(from.join("sub")).unwrap();
+        std::fs::write(from.join("sub").join("hello.txt"), "hello").unwrap();
+        let to = tmp_dir.join("to");
+
+        move_in_app(Args {
+            from: from.clone(),
+            to: to.clone(),
+            ..default()
+        })
+        .unwrap();
+        assert!(!from.exists());
+        assert_eq!(
+            std::fs::read_to_string(to.join("sub").join("hello.txt")).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(move_system).with(Args {
+                                from: tmp_dir.join("from"),
+                                to: tmp_dir.join("to"),
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+
+    fn move_in_app(args: Args) -> ApiResult {
+        run_action_in_app(move || move_action(args))
+    }
+}
@@ -1,21 +1,34 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::metadata::FileMetadata;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
+use bevy_flurx::prelude::{Pipe, side_effect};
 use bevy_flurx_ipc::prelude::*;
+use globset::{Glob, GlobMatcher};
 use serde::{Deserialize, Serialize};
-use std::ffi::OsString;
-use std::path::PathBuf;
+use std::cmp::Ordering;
+use std::ffi::{OsStr, OsString};
+use std::fs::{DirEntry, Metadata};
+use std::path::{Path, PathBuf};
 
 api_plugin!(
-    /// You'll be able to check if the path exists from typescript(or js).
+    /// You'll be able to list the entries of a directory from typescript(or js).
+    ///
+    /// The directory is read in another thread, so a large directory doesn't freeze the frame.
     ///
     /// ## Typescript Code Example
     ///
     /// ```ts
     /// const entries: window.__FLURX__.fs.FileEntry[]= await window.__FLURX__.fs.readDir("./dir", {
-    ///     dir: "Download"
+    ///     dir: "Download",
+    ///     depth: 0,
+    ///     extensions: ["png", "jpg"],
+    ///     sort: "name",
+    ///     limit: 100,
     /// });
     /// ```
     FsReadDirPlugin,
@@ -26,140 +39,528 @@ api_plugin!(
 struct Args {
     path: PathBuf,
     dir: Option<BaseDirectory>,
+    #[serde(flatten)]
+    options: ReadDirOptions,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+struct ReadDirOptions {
+    /// Whether to include the metadata in each entry.
+    metadata: bool,
+    /// The depth of the subdirectories to read; `0` reads only the entries of the directory.
+    ///
+    /// none if unlimited.
+    depth: Option<usize>,
+    /// The glob pattern that the file names must match.
+    glob: Option<String>,
+    /// The extensions that the files must have, without the leading dot.
+    extensions: Option<Vec<String>>,
+    /// Whether to include the hidden files and directories.
+    #[serde(rename = "includeHidden")]
+    include_hidden: bool,
+    sort: Option<SortBy>,
+    descending: bool,
+    /// The number of the top-level entries to skip.
+    offset: usize,
+    /// The maximum number of the top-level entries.
+    limit: Option<usize>,
+    /// The name of the last top-level entry of the previous page; the entries up to it are skipped.
+    after: Option<String>,
+}
+
+impl Default for ReadDirOptions {
+    fn default() -> Self {
+        Self {
+            metadata: false,
+            depth: None,
+            glob: None,
+            extensions: None,
+            include_hidden: true,
+            sort: None,
+            descending: false,
+            offset: 0,
+            limit: None,
+            after: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum SortBy {
+    Name,
+    Modified,
+    Size,
 }
 
 #[derive(Serialize, PartialEq, Debug)]
 struct FileEntry {
     name: OsString,
     path: PathBuf,
-    // none if not dir.
+    #[serde(rename = "isDir")]
+    is_dir: bool,
+    // none if not dir or deeper than the depth.
     children: Option<Vec<FileEntry>>,
+    // none if not requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<FileMetadata>,
+}
+
+/// Filters the files by name.
+///
+/// Directories are not filtered except the hidden ones so that their children can be listed.
+struct EntryFilter {
+    glob: Option<GlobMatcher>,
+    extensions: Option<Vec<String>>,
+    include_hidden: bool,
+}
+
+impl EntryFilter {
+    fn new(options: &ReadDirOptions) -> ApiResult<Self> {
+        let glob = match options.glob.as_ref() {
+            Some(pattern) => Some(Glob::new(pattern)?.compile_matcher()),
+            None => None,
+        };
+        Ok(Self {
+            glob,
+            extensions: options.extensions.as_ref().map(|extensions| {
+                extensions
+                    .iter()
+                    .map(|extension| extension.trim_start_matches('.').to_lowercase())
+                    .collect()
+            }),
+            include_hidden: options.include_hidden,
+        })
+    }
+
+    fn is_match(&self, entry: &DirEntry, metadata: &Metadata) -> bool {
+        if !self.include_hidden && is_hidden(entry, metadata) {
+            return false;
+        }
+        if metadata.is_dir() {
+            return true;
+        }
+        let path = entry.path();
+        if let Some(glob) = self.glob.as_ref() {
+            if !glob.is_match(entry.file_name()) {
+                return false;
+            }
+        }
+        if let Some(extensions) = self.extensions.as_ref() {
+            let Some(extension) = path.extension() else {
+                return false;
+            };
+            let extension = extension.to_string_lossy().to_lowercase();
+            if !extensions.contains(&extension) {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 #[command(id = "FLURX|fs::read_dir")]
 fn read_dir(In(args): In<Args>) -> Action<Args, ApiResult<Vec<FileEntry>>> {
-    once::run(read_dir_system).with(args)
+    read_dir_action(args)
+}
+
+fn read_dir_action(args: Args) -> Action<Args, ApiResult<Vec<FileEntry>>> {
+    once::run(read_dir_system)
+        .with(args)
+        .pipe(side_effect::thread::spawn(
+            |args: ApiResult<(PathBuf, ReadDirOptions)>| {
+                let (path, options) = args?;
+                read_entries(&path, &options)
+            },
+        ))
 }
 
 fn read_dir_system(
     In(args): In<Args>,
     scope: Option<Res<AllowPaths>>,
-) -> ApiResult<Vec<FileEntry>> {
-    let path = join_path_if_need(&args.dir, args.path);
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<(PathBuf, ReadDirOptions)> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
-    Ok(read_dirs(&path)?)
+    Ok((path, args.options))
+}
+
+/// Reads the top-level entries, and paginates them before reading their children.
+fn read_entries(path: &Path, options: &ReadDirOptions) -> ApiResult<Vec<FileEntry>> {
+    let filter = EntryFilter::new(options)?;
+    let entries = list_entries(path, options, &filter)?;
+    let start = options
+        .after
+        .as_ref()
+        .map_or(0, |after| position_after(&entries, after, options));
+    let entries = entries
+        .into_iter()
+        .skip(start)
+        .skip(options.offset)
+        .take(options.limit.unwrap_or(usize::MAX))
+        .map(|(entry, metadata)| to_file_entry(entry, metadata, options, &filter, 0))
+        .collect::<std::io::Result<_>>()?;
+    Ok(entries)
+}
+
+fn read_dirs(
+    path: &Path,
+    options: &ReadDirOptions,
+    filter: &EntryFilter,
+    depth: usize,
+) -> std::io::Result<Vec<FileEntry>> {
+    list_entries(path, options, filter)?
+        .into_iter()
+        .map(|(entry, metadata)| to_file_entry(entry, metadata, options, filter, depth))
+        .collect()
 }
 
-fn read_dirs(path: &PathBuf) -> std::io::Result<Vec<FileEntry>> {
-    let mut file_entries = Vec::new();
+/// Returns the filtered and sorted entries of the directory without reading the subdirectories.
+fn list_entries(
+    path: &Path,
+    options: &ReadDirOptions,
+    filter: &EntryFilter,
+) -> std::io::Result<Vec<(DirEntry, Metadata)>> {
+    let mut entries = Vec::new();
     for entry in std::fs::read_dir(path)?.filter_map(|entry| entry.ok()) {
-        let children = if entry.metadata()?.is_dir() {
-            Some(read_dirs(&entry.path())?)
-        } else {
-            None
-        };
-        file_entries.push(FileEntry {
-            name: entry.file_name(),
-            path: entry.path(),
-            children,
+        let metadata = entry.metadata()?;
+        if filter.is_match(&entry, &metadata) {
+            entries.push((entry, metadata));
+        }
+    }
+    if let Some(sort) = options.sort {
+        entries.sort_by(|(e1, m1), (e2, m2)| {
+            let ordering = compare(sort, e1, m1, e2, m2);
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
         });
     }
-    Ok(file_entries)
+    Ok(entries)
+}
+
+/// Returns the index of the entry next to the entry named `after`.
+///
+/// If the entry has been removed, the entries are compared by name when sorted by name,
+/// otherwise all entries are skipped since the position is unknown.
+fn position_after(
+    entries: &[(DirEntry, Metadata)],
+    after: &str,
+    options: &ReadDirOptions,
+) -> usize {
+    if let Some(index) = entries
+        .iter()
+        .position(|(entry, _)| entry.file_name() == after)
+    {
+        return index + 1;
+    }
+    if options.sort != Some(SortBy::Name) {
+        return entries.len();
+    }
+    entries
+        .iter()
+        .position(|(entry, _)| {
+            let ordering = entry.file_name().as_os_str().cmp(OsStr::new(after));
+            if options.descending {
+                ordering.is_lt()
+            } else {
+                ordering.is_gt()
+            }
+        })
+        .unwrap_or(entries.len())
+}
+
+fn to_file_entry(
+    entry: DirEntry,
+    metadata: Metadata,
+    options: &ReadDirOptions,
+    filter: &EntryFilter,
+    depth: usize,
+) -> std::io::Result<FileEntry> {
+    let path = entry.path();
+    let is_dir = metadata.is_dir();
+    let children = if is_dir && options.depth.is_none_or(|max| depth < max) {
+        Some(read_dirs(&path, options, filter, depth + 1)?)
+    } else {
+        None
+    };
+    Ok(FileEntry {
+        name: entry.file_name(),
+        metadata: options
+            .metadata
+            .then(|| FileMetadata::new(&path, &metadata)),
+        path,
+        is_dir,
+        children,
+    })
+}
+
+fn compare(sort: SortBy, e1: &DirEntry, m1: &Metadata, e2: &DirEntry, m2: &Metadata) -> Ordering {
+    match sort {
+        SortBy::Name => e1.file_name().cmp(&e2.file_name()),
+        SortBy::Modified => m1.modified().ok().cmp(&m2.modified().ok()),
+        SortBy::Size => m1.len().cmp(&m2.len()),
+    }
+}
+
+#[cfg(unix)]
+fn is_hidden(entry: &DirEntry, _: &Metadata) -> bool {
+    entry.file_name().to_string_lossy().starts_with('.')
+}
+
+#[cfg(windows)]
+fn is_hidden(entry: &DirEntry, metadata: &Metadata) -> bool {
+    use std::os::windows::fs::MetadataExt;
+
+    const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+    entry.file_name().to_string_lossy().starts_with('.')
+        || metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0
 }
 
 #[cfg(test)]
 //noinspection DuplicatedCode
 mod tests {
-    use crate::fs::read_dir::{Args, FileEntry, read_dir_system};
-    use crate::tests::test_app;
+    use crate::error::ApiResult;
+    use crate::fs::AllowPaths;
+    use crate::fs::metadata::FileType;
+    use crate::fs::read_dir::{
+        Args, FileEntry, ReadDirOptions, SortBy, read_dir_action, read_dir_system,
+    };
+    use crate::tests::{run_action_in_app, test_app};
     use bevy::prelude::*;
     use bevy::utils::default;
     use bevy_flurx::action::once;
-    use bevy_flurx::prelude::Reactor;
+    use bevy_flurx::prelude::{Reactor, Then};
     use std::ffi::OsString;
     use std::fs::{create_dir, create_dir_all};
+    use std::path::PathBuf;
 
     #[test]
     fn empty_dir() {
-        let mut app = test_app();
-        app.add_systems(Startup, |mut commands: Commands| {
-            commands.spawn(Reactor::schedule(|task| async move {
-                let tmp_dir = std::env::temp_dir().join("read_dir_empty_dir");
-                let _ = create_dir(&tmp_dir);
-                let entries: Vec<FileEntry> = task
-                    .will(
-                        Update,
-                        once::run(read_dir_system).with(Args {
-                            path: tmp_dir,
-                            ..default()
-                        }),
-                    )
-                    .await
-                    .unwrap();
-                assert!(entries.is_empty());
-            }));
-        });
-        app.update();
+        let tmp_dir = std::env::temp_dir().join("read_dir_empty_dir");
+        let _ = create_dir(&tmp_dir);
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir,
+            ..default()
+        })
+        .unwrap();
+        assert!(entries.is_empty());
     }
 
     #[test]
     fn read_text_file() {
-        let mut app = test_app();
-        app.add_systems(Startup, |mut commands: Commands| {
-            commands.spawn(Reactor::schedule(|task| async move {
-                let tmp_dir = std::env::temp_dir().join("read_dir_text_dir");
-                let _ = create_dir(&tmp_dir);
-                let _ = std::fs::write(tmp_dir.join("hello.txt"), "hello");
-                let entries: Vec<FileEntry> = task
-                    .will(
-                        Update,
-                        once::run(read_dir_system).with(Args {
-                            path: tmp_dir.clone(),
-                            ..default()
-                        }),
-                    )
-                    .await
-                    .unwrap();
-                assert_eq!(
-                    entries,
-                    vec![FileEntry {
-                        path: tmp_dir.join("hello.txt"),
-                        name: OsString::from("hello.txt"),
-                        children: None,
-                    }]
-                );
-            }));
-        });
-        app.update();
+        let tmp_dir = std::env::temp_dir().join("read_dir_text_dir");
+        let _ = create_dir(&tmp_dir);
+        let _ = std::fs::write(tmp_dir.join("hello.txt"), "hello");
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir.clone(),
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![FileEntry {
+                path: tmp_dir.join("hello.txt"),
+                name: OsString::from("hello.txt"),
+                is_dir: false,
+                children: None,
+                metadata: None,
+            }]
+        );
     }
 
     #[test]
     fn contains_dir() {
+        let tmp_dir = std::env::temp_dir().join("read_dir_contains_dir");
+        let _ = create_dir_all(tmp_dir.join("child"));
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir.clone(),
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![FileEntry {
+                path: tmp_dir.join("child"),
+                name: OsString::from("child"),
+                is_dir: true,
+                children: Some(vec![]),
+                metadata: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn with_metadata() {
+        let tmp_dir = std::env::temp_dir().join("read_dir_with_metadata");
+        let _ = create_dir(&tmp_dir);
+        let _ = std::fs::write(tmp_dir.join("hello.txt"), "hello");
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir,
+            options: ReadDirOptions {
+                metadata: true,
+                ..default()
+            },
+            ..default()
+        })
+        .unwrap();
+        let metadata = entries[0].metadata.as_ref().unwrap();
+        assert_eq!(metadata.file_type, FileType::File);
+        assert_eq!(metadata.size, 5);
+    }
+
+    #[test]
+    fn limit_depth() {
+        let tmp_dir = std::env::temp_dir().join("read_dir_limit_depth");
+        let _ = create_dir_all(tmp_dir.join("child").join("grandchild"));
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir.clone(),
+            options: ReadDirOptions {
+                depth: Some(0),
+                ..default()
+            },
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![FileEntry {
+                path: tmp_dir.join("child"),
+                name: OsString::from("child"),
+                is_dir: true,
+                children: None,
+                metadata: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn filter_by_extensions_and_glob() {
+        let tmp_dir = new_dir_with_files("read_dir_filter");
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir.clone(),
+            options: ReadDirOptions {
+                extensions: Some(vec![".RON".to_string()]),
+                sort: Some(SortBy::Name),
+                ..default()
+            },
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(names(&entries), vec!["a.ron", "c.ron"]);
+
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir,
+            options: ReadDirOptions {
+                glob: Some("a.*".to_string()),
+                sort: Some(SortBy::Name),
+                ..default()
+            },
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(names(&entries), vec!["a.ron", "a.txt"]);
+    }
+
+    #[test]
+    fn exclude_hidden_files() {
+        let tmp_dir = new_dir_with_files("read_dir_hidden");
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir,
+            options: ReadDirOptions {
+                include_hidden: false,
+                sort: Some(SortBy::Name),
+                ..default()
+            },
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(names(&entries), vec!["a.ron", "a.txt", "c.ron"]);
+    }
+
+    #[test]
+    fn sort_and_paginate() {
+        let tmp_dir = new_dir_with_files("read_dir_paginate");
+        let entries = read_dir_in_app(Args {
+            path: tmp_dir,
+            options: ReadDirOptions {
+                sort: Some(SortBy::Name),
+                descending: true,
+                offset: 1,
+                limit: Some(2),
+                ..default()
+            },
+            ..default()
+        })
+        .unwrap();
+        assert_eq!(names(&entries), vec!["a.txt", "a.ron"]);
+    }
+
+    #[test]
+    fn paginate_with_cursor() {
+        let tmp_dir = new_dir_with_files("read_dir_cursor");
+        let read_after = |after: &str| {
+            read_dir_in_app(Args {
+                path: tmp_dir.clone(),
+                options: ReadDirOptions {
+                    include_hidden: false,
+                    sort: Some(SortBy::Name),
+                    limit: Some(1),
+                    after: Some(after.to_string()),
+                    ..default()
+                },
+                ..default()
+            })
+            .unwrap()
+        };
+        assert_eq!(names(&read_after("a.ron")), vec!["a.txt"]);
+        assert_eq!(names(&read_after("b.ron")), vec!["c.ron"]);
+        assert!(read_after("c.ron").is_empty());
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
         let mut app = test_app();
         app.add_systems(Startup, |mut commands: Commands| {
             commands.spawn(Reactor::schedule(|task| async move {
-                let tmp_dir = std::env::temp_dir().join("read_dir_contains_dir");
-                let _ = create_dir_all(tmp_dir.join("child"));
-                let entries: Vec<FileEntry> = task
-                    .will(
-                        Update,
-                        once::run(read_dir_system).with(Args {
-                            path: tmp_dir.clone(),
-                            ..default()
-                        }),
-                    )
-                    .await
-                    .unwrap();
-                assert_eq!(
-                    entries,
-                    vec![FileEntry {
-                        path: tmp_dir.join("child"),
-                        name: OsString::from("child"),
-                        children: Some(vec![]),
-                    }]
-                );
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(read_dir_system).with(Args {
+                                path: tmp_dir,
+                                ..default()
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
             }));
         });
         app.update();
     }
+
+    fn new_dir_with_files(name: &str) -> PathBuf {
+        let tmp_dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        create_dir_all(&tmp_dir).unwrap();
+        for file in ["a.ron", "a.txt", "c.ron", ".hidden"] {
+            std::fs::write(tmp_dir.join(file), file).unwrap();
+        }
+        tmp_dir
+    }
+
+    fn names(entries: &[FileEntry]) -> Vec<&str> {
+        entries
+            .iter()
+            .map(|entry| entry.name.to_str().unwrap())
+            .collect()
+    }
+
+    fn read_dir_in_app(args: Args) -> ApiResult<Vec<FileEntry>> {
+        run_action_in_app(move || read_dir_action(args))
+    }
 }
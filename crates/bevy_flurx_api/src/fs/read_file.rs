@@ -1,5 +1,7 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -54,14 +56,19 @@ fn read_binary_file(In(args): In<Args>) -> Action<Args, ApiResult<Vec<u8>>> {
 fn read_binary_file_system(
     In(args): In<Args>,
     scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
 ) -> ApiResult<Vec<u8>> {
-    let path = join_path_if_need(&args.dir, args.path);
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
     Ok(std::fs::read(path)?)
 }
 
-fn read_text_file_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) -> ApiResult<String> {
-    let path = join_path_if_need(&args.dir, args.path);
+fn read_text_file_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<String> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
     Ok(std::fs::read_to_string(path)?)
 }
@@ -69,8 +76,8 @@ fn read_text_file_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) ->
 #[cfg(test)]
 //noinspection DuplicatedCode
 mod tests {
-    use crate::fs::AllowPaths;
     use crate::fs::read_file::{Args, read_text_file_system};
+    use crate::fs::{AllowPaths, BaseDirectory};
     use crate::tests::test_app;
     use bevy::prelude::*;
     use bevy::utils::default;
@@ -123,4 +130,24 @@ mod tests {
         });
         app.update();
     }
+
+    #[test]
+    fn err_if_app_dir_without_identifier() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result: Result<String, _> = task
+                    .will(
+                        Update,
+                        once::run(read_text_file_system).with(Args {
+                            dir: Some(BaseDirectory::AppData),
+                            path: "save.json".into(),
+                        }),
+                    )
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
 }
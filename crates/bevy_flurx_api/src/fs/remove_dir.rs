@@ -1,5 +1,8 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::trash::move_to_trash;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -18,6 +21,16 @@ api_plugin!(
     ///     recursive: true
     /// })
     /// ```
+    ///
+    /// With `toTrash`, the directory is moved to the trash instead.
+    /// As with removing it, a non-empty directory is moved only if `recursive` is true.
+    ///
+    /// ```ts
+    /// await window.__FLURX__.fs.removeDir("./dir", {
+    ///     recursive: true,
+    ///     toTrash: true
+    /// })
+    /// ```
     FsRemoveDirPlugin,
     command: remove_dir
 );
@@ -27,6 +40,9 @@ struct Args {
     path: PathBuf,
     dir: Option<BaseDirectory>,
     recursive: Option<bool>,
+    /// Moves the directory to the trash instead of removing it permanently.
+    #[serde(rename = "toTrash")]
+    to_trash: Option<bool>,
 }
 
 #[command(id = "FLURX|fs::remove_dir")]
@@ -34,10 +50,21 @@ fn remove_dir(In(args): In<Args>) -> Action<Args, ApiResult> {
     once::run(remove_dir_system).with(args)
 }
 
-fn remove_dir_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) -> ApiResult {
-    let path = join_path_if_need(&args.dir, args.path);
+fn remove_dir_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
-    if args.recursive.is_some_and(|recursive| recursive) {
+    let recursive = args.recursive.is_some_and(|recursive| recursive);
+    if args.to_trash.is_some_and(|to_trash| to_trash) {
+        if !recursive && std::fs::read_dir(&path)?.next().is_some() {
+            return Err(std::io::Error::from(std::io::ErrorKind::DirectoryNotEmpty).into());
+        }
+        return move_to_trash(path);
+    }
+    if recursive {
         std::fs::remove_dir_all(path)?;
     } else {
         std::fs::remove_dir(path)?;
@@ -128,6 +155,31 @@ mod tests {
         app.update();
     }
 
+    #[test]
+    fn err_if_not_empty_dir_to_trash_without_recursive() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let dir = std::env::temp_dir().join("not_empty_dir_to_trash");
+                create_dir_if_need(&dir);
+                create_dir_if_need(&dir.join("dir"));
+                let result: Result<_, _> = task
+                    .will(
+                        Update,
+                        once::run(remove_dir_system).with(Args {
+                            path: dir.clone(),
+                            to_trash: Some(true),
+                            ..default()
+                        }),
+                    )
+                    .await;
+                result.unwrap_err();
+                assert!(std::fs::exists(dir).unwrap());
+            }));
+        });
+        app.update();
+    }
+
     fn create_dir_if_need(path: &PathBuf) {
         if !path.exists() {
             std::fs::create_dir_all(path).unwrap();
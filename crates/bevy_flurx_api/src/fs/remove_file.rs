@@ -1,5 +1,8 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::trash::move_to_trash;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -14,7 +17,8 @@ api_plugin!(
     ///
     /// ```ts
     /// await window.__FLURX__.fs.removeFile("./hoge.txt", {
-    ///     dir: "Download"
+    ///     dir: "Download",
+    ///     toTrash: true,
     /// });
     /// ```
     FsRemoveFilePlugin,
@@ -25,6 +29,9 @@ api_plugin!(
 struct Args {
     path: PathBuf,
     dir: Option<BaseDirectory>,
+    /// Moves the file to the trash instead of removing it permanently.
+    #[serde(rename = "toTrash")]
+    to_trash: Option<bool>,
 }
 
 #[command(id = "FLURX|fs::remove_file")]
@@ -32,9 +39,16 @@ fn remove_file(In(args): In<Args>) -> Action<Args, ApiResult> {
     once::run(remove_file_system).with(args)
 }
 
-fn remove_file_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) -> ApiResult {
-    let path = join_path_if_need(&args.dir, args.path);
+fn remove_file_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
+    if args.to_trash.is_some_and(|to_trash| to_trash) {
+        return move_to_trash(path);
+    }
     std::fs::remove_file(path)?;
     Ok(())
 }
@@ -1,5 +1,7 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
@@ -39,9 +41,13 @@ fn rename_file(In(args): In<Args>) -> Action<Args, ApiResult> {
     once::run(rename_file_system).with(args)
 }
 
-fn rename_file_system(In(args): In<Args>, scope: Option<Res<AllowPaths>>) -> ApiResult {
-    let old_path = join_path_if_need(&args.old_dir, args.old_path);
-    let new_path = join_path_if_need(&args.new_dir, args.new_path);
+fn rename_file_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult {
+    let old_path = join_path_if_need(&args.old_dir, args.old_path, &identifier)?;
+    let new_path = join_path_if_need(&args.new_dir, args.new_path, &identifier)?;
     error_if_not_accessible(&old_path, &scope)?;
     error_if_not_accessible(&new_path, &scope)?;
     std::fs::rename(old_path, new_path)?;
@@ -0,0 +1,294 @@
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use bevy::prelude::{In, Res};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx::prelude::{ActionSeed, Pipe, side_effect};
+use bevy_flurx_ipc::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+api_plugin!(
+    /// You'll be able to move a file or directory to the trash from typescript(or js).
+    ///
+    /// On Linux, it follows the freedesktop trash specification.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// await window.__FLURX__.fs.trash("./hoge.txt", {
+    ///     dir: "Download"
+    /// });
+    /// ```
+    FsTrashPlugin,
+    command: trash
+);
+
+api_plugin!(
+    /// You'll be able to list the items in the trash from typescript(or js).
+    ///
+    /// Only the items whose original paths are permitted by [`AllowPaths`] are listed.
+    /// If [`AllowPaths`] is not inserted, only the items originally in the directories of [`AppIdentifier`] are listed.
+    /// It's not supported on macOS.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const items: window.__FLURX__.fs.TrashItem[] = await window.__FLURX__.fs.trashList();
+    /// ```
+    FsTrashListPlugin,
+    command: trash_list
+);
+
+api_plugin!(
+    /// You'll be able to restore the items in the trash to their original paths from typescript(or js).
+    ///
+    /// The items are specified by the ids obtained from `fs.trashList`,
+    /// and only the items whose original paths are permitted by [`AllowPaths`] can be restored.
+    /// If [`AllowPaths`] is not inserted, only the items originally in the directories of [`AppIdentifier`] can be restored.
+    /// If any of the ids is unknown or not permitted, nothing is restored and an error is returned.
+    /// It's not supported on macOS.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const items = await window.__FLURX__.fs.trashList();
+    /// await window.__FLURX__.fs.trashRestore(items.map((item) => item.id));
+    /// ```
+    FsTrashRestorePlugin,
+    command: trash_restore
+);
+
+#[derive(Deserialize, Default)]
+struct Args {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+}
+
+/// An item in the trash.
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+struct TrashItem {
+    /// The platform specific id to restore the item.
+    id: String,
+    name: String,
+    #[serde(rename = "originalPath")]
+    original_path: PathBuf,
+    /// Seconds since the unix epoch.
+    #[serde(rename = "deletedAt")]
+    deleted_at: i64,
+}
+
+#[command(id = "FLURX|fs::trash")]
+fn trash(In(args): In<Args>) -> Action<Args, ApiResult> {
+    trash_action(args)
+}
+
+#[command(id = "FLURX|fs::trash_list")]
+fn trash_list() -> ActionSeed<(), ApiResult<Vec<TrashItem>>> {
+    once::run(with_scope::<()>).pipe(side_effect::thread::spawn(
+        |((), scope): ((), AllowPaths)| {
+            Ok(os_trash::list()?
+                .into_iter()
+                .filter(|item| is_accessible(&item.original_path, &scope))
+                .collect())
+        },
+    ))
+}
+
+#[command(id = "FLURX|fs::trash_restore")]
+fn trash_restore(In(ids): In<Vec<String>>) -> Action<Vec<String>, ApiResult> {
+    once::run(with_scope::<Vec<String>>)
+        .with(ids)
+        .pipe(side_effect::thread::spawn(
+            |(ids, scope): (Vec<String>, AllowPaths)| {
+                os_trash::restore(&ids, |path| is_accessible(path, &scope))
+            },
+        ))
+}
+
+fn trash_action(args: Args) -> Action<Args, ApiResult> {
+    once::run(trash_system)
+        .with(args)
+        .pipe(side_effect::thread::spawn(|path: ApiResult<PathBuf>| {
+            move_to_trash(path?)
+        }))
+}
+
+fn trash_system(
+    In(args): In<Args>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<PathBuf> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    Ok(path)
+}
+
+/// Moves the file or directory to the platform trash.
+pub(crate) fn move_to_trash(path: PathBuf) -> ApiResult {
+    ::trash::delete(path)?;
+    Ok(())
+}
+
+/// Passes the input to the thread with the scope of the trash items.
+fn with_scope<T: Send + 'static>(
+    In(input): In<T>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> (T, AllowPaths) {
+    (input, trash_scope(scope.as_deref(), identifier.as_deref()))
+}
+
+/// Since the trash is shared by all applications,
+/// the items are limited to the app directories if [`AllowPaths`] is not inserted.
+fn trash_scope(scope: Option<&AllowPaths>, identifier: Option<&AppIdentifier>) -> AllowPaths {
+    match scope {
+        Some(scope) => scope.clone(),
+        None => AllowPaths::new(identifier.map(AppIdentifier::app_dirs).unwrap_or_default()),
+    }
+}
+
+fn is_accessible(path: &Path, scope: &AllowPaths) -> bool {
+    scope.check_accessible(path)
+}
+
+#[cfg(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+mod os_trash {
+    use crate::error::ApiResult;
+    use crate::error::fs::NotPermittedPath;
+    use crate::fs::trash::TrashItem;
+    use ::trash::os_limited;
+    use std::io;
+    use std::path::Path;
+
+    pub(super) fn list() -> ApiResult<Vec<TrashItem>> {
+        Ok(os_limited::list()?
+            .into_iter()
+            .map(|item| TrashItem {
+                id: item.id.to_string_lossy().to_string(),
+                name: item.name.to_string_lossy().to_string(),
+                original_path: item.original_path(),
+                deleted_at: item.time_deleted,
+            })
+            .collect())
+    }
+
+    /// Restores nothing if any of the ids is unknown or denied.
+    pub(super) fn restore(ids: &[String], is_accessible: impl Fn(&Path) -> bool) -> ApiResult {
+        let items = os_limited::list()?
+            .into_iter()
+            .filter(|item| ids.contains(&item.id.to_string_lossy().to_string()))
+            .collect::<Vec<_>>();
+        if let Some(id) = ids.iter().find(|id| {
+            !items
+                .iter()
+                .any(|item| item.id.to_string_lossy() == id.as_str())
+        }) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("The trash item {id} is not found"),
+            )
+            .into());
+        }
+        if items
+            .iter()
+            .any(|item| !is_accessible(&item.original_path()))
+        {
+            return Err(NotPermittedPath.into());
+        }
+        os_limited::restore_all(items)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+)))]
+mod os_trash {
+    use crate::error::ApiResult;
+    use crate::fs::trash::TrashItem;
+    use std::io;
+    use std::path::Path;
+
+    pub(super) fn list() -> ApiResult<Vec<TrashItem>> {
+        Err(io::Error::from(io::ErrorKind::Unsupported).into())
+    }
+
+    pub(super) fn restore(_: &[String], _: impl Fn(&Path) -> bool) -> ApiResult {
+        Err(io::Error::from(io::ErrorKind::Unsupported).into())
+    }
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::fs::trash::{Args, is_accessible, trash_action, trash_scope};
+    use crate::fs::{AllowPaths, AppIdentifier};
+    use crate::tests::test_app;
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+
+    #[test]
+    fn accessible_only_in_scope() {
+        let scope = trash_scope(Some(&AllowPaths::new(["/allowed"])), None);
+        assert!(is_accessible("/allowed/hoge.txt".as_ref(), &scope));
+        assert!(!is_accessible("/denied/hoge.txt".as_ref(), &scope));
+    }
+
+    #[test]
+    fn accessible_only_in_app_dirs_without_allow_paths() {
+        let identifier = AppIdentifier::new("com.example.game");
+        let scope = trash_scope(None, Some(&identifier));
+        let app_data = dirs::data_dir().unwrap().join("com.example.game");
+        assert!(is_accessible(&app_data.join("hoge.txt"), &scope));
+        assert!(!is_accessible("/denied/hoge.txt".as_ref(), &scope));
+    }
+
+    #[test]
+    fn not_accessible_without_allow_paths_and_identifier() {
+        let scope = trash_scope(None, None);
+        assert!(!is_accessible("/denied/hoge.txt".as_ref(), &scope));
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let path = std::env::temp_dir().join("trash_out_of_scope.txt");
+                std::fs::write(&path, "hoge").unwrap();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert()
+                            .with(AllowPaths::default())
+                            .then(trash_action(Args {
+                                path: path.clone(),
+                                ..default()
+                            }))
+                    })
+                    .await;
+                result.unwrap_err();
+                assert!(path.exists());
+            }));
+        });
+        app.update();
+    }
+}
@@ -0,0 +1,361 @@
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{
+    Added, App, Commands, Entity, In, NonSendMut, Plugin, PostUpdate, Query, Res, Update, With,
+};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx_ipc::prelude::*;
+use bevy_webview_core::backend::{EmitIpcEvent, EventPayload};
+use notify::event::ModifyKind;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, channel};
+use std::time::{Duration, Instant};
+
+/// You'll be able to watch the changes of files and directories from typescript(or js).
+///
+/// The changes are delivered to the callback through [`EmitIpcEvent`],
+/// and the watcher is removed on unwatch or when the webview is despawned.
+///
+/// ## Typescript Code Example
+///
+/// ```ts
+/// const id: number = await window.__FLURX__.fs.watch("./assets", (event) => {
+///     console.log(event.kind, event.paths);
+/// }, {
+///     recursive: true,
+///     debounceMs: 100,
+/// });
+/// await window.__FLURX__.fs.unwatch(id);
+/// ```
+pub struct FsWatchPlugin;
+
+impl Plugin for FsWatchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_non_send_resource::<FsWatchers>()
+            .add_systems(Update, (emit_watch_events, remove_orphan_watchers))
+            .add_systems(
+                PostUpdate,
+                |mut views: Query<&mut IpcHandlers, Added<IpcHandlers>>| {
+                    for mut handlers in views.iter_mut() {
+                        handlers.register(watch());
+                        handlers.register(unwatch());
+                    }
+                },
+            );
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct Args {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+    #[serde(default)]
+    recursive: bool,
+    /// The events are delivered after no events have occurred for this duration.
+    #[serde(rename = "debounceMs", default)]
+    debounce_ms: u64,
+}
+
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum WatchEventKind {
+    Create,
+    Modify,
+    Remove,
+    Rename,
+}
+
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+struct WatchEvent {
+    kind: WatchEventKind,
+    paths: Vec<PathBuf>,
+}
+
+struct Watch {
+    webview_entity: Entity,
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<notify::Event>>,
+    debounce: Duration,
+    pending: Vec<WatchEvent>,
+    last_received: Option<Instant>,
+}
+
+/// The active watchers associated with the subscription ids.
+#[derive(Default)]
+struct FsWatchers {
+    next_id: u64,
+    watches: HashMap<u64, Watch>,
+}
+
+#[command(id = "FLURX|fs::watch")]
+fn watch(
+    In(args): In<Args>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(Args, Entity), ApiResult<u64>> {
+    once::run(watch_system).with((args, webview_entity))
+}
+
+#[command(id = "FLURX|fs::unwatch")]
+fn unwatch(
+    In(id): In<u64>,
+    WebviewEntity(webview_entity): WebviewEntity,
+) -> Action<(u64, Entity), ApiResult> {
+    once::run(unwatch_system).with((id, webview_entity))
+}
+
+fn watch_system(
+    In((args, webview_entity)): In<(Args, Entity)>,
+    mut watchers: NonSendMut<FsWatchers>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<u64> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = tx.send(event);
+    })?;
+    let mode = if args.recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    watcher.watch(&path, mode)?;
+
+    let id = watchers.next_id;
+    watchers.next_id += 1;
+    watchers.watches.insert(
+        id,
+        Watch {
+            webview_entity,
+            _watcher: watcher,
+            rx,
+            debounce: Duration::from_millis(args.debounce_ms),
+            pending: Vec::new(),
+            last_received: None,
+        },
+    );
+    Ok(id)
+}
+
+fn unwatch_system(
+    In((id, webview_entity)): In<(u64, Entity)>,
+    mut watchers: NonSendMut<FsWatchers>,
+) -> ApiResult {
+    // Only the webview that started watching can stop it.
+    if watchers
+        .watches
+        .get(&id)
+        .is_some_and(|watch| watch.webview_entity == webview_entity)
+    {
+        watchers.watches.remove(&id);
+    }
+    Ok(())
+}
+
+fn emit_watch_events(
+    mut commands: Commands,
+    mut watchers: NonSendMut<FsWatchers>,
+    scope: Option<Res<AllowPaths>>,
+) {
+    let now = Instant::now();
+    for (id, watch) in watchers.watches.iter_mut() {
+        for event in watch.rx.try_iter().filter_map(|event| event.ok()) {
+            let Some(event) = as_watch_event(event, &scope) else {
+                continue;
+            };
+            if !watch.pending.contains(&event) {
+                watch.pending.push(event);
+            }
+            watch.last_received = Some(now);
+        }
+        let Some(last_received) = watch.last_received else {
+            continue;
+        };
+        if now.duration_since(last_received) < watch.debounce {
+            continue;
+        }
+        watch.last_received = None;
+        for event in watch.pending.drain(..) {
+            commands.entity(watch.webview_entity).trigger(EmitIpcEvent {
+                id: format!("FLURX|fs::watch::{id}"),
+                payload: EventPayload::new(event),
+            });
+        }
+    }
+}
+
+fn remove_orphan_watchers(
+    mut watchers: NonSendMut<FsWatchers>,
+    webviews: Query<(), With<IpcHandlers>>,
+) {
+    watchers
+        .watches
+        .retain(|_, watch| webviews.contains(watch.webview_entity));
+}
+
+fn as_watch_event(event: notify::Event, scope: &Option<Res<AllowPaths>>) -> Option<WatchEvent> {
+    let kind = match event.kind {
+        EventKind::Create(_) => WatchEventKind::Create,
+        EventKind::Modify(ModifyKind::Name(_)) => WatchEventKind::Rename,
+        EventKind::Modify(_) => WatchEventKind::Modify,
+        EventKind::Remove(_) => WatchEventKind::Remove,
+        _ => return None,
+    };
+    let paths = event
+        .paths
+        .into_iter()
+        .filter(|path| error_if_not_accessible(path, scope).is_ok())
+        .collect::<Vec<_>>();
+    if paths.is_empty() {
+        return None;
+    }
+    Some(WatchEvent { kind, paths })
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::fs::AllowPaths;
+    use crate::fs::watch::{
+        Args, FsWatchPlugin, FsWatchers, WatchEvent, WatchEventKind, watch_system,
+    };
+    use crate::tests::{assert_api_registered, test_app};
+    use bevy::prelude::*;
+    use bevy::utils::default;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+    use bevy_flurx_ipc::prelude::IpcHandlers;
+    use bevy_webview_core::backend::{EmitIpcEvent, EventPayload};
+    use std::fs::create_dir_all;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[test]
+    fn registered_watch_and_unwatch() {
+        assert_api_registered(FsWatchPlugin, "FLURX|fs::watch");
+        assert_api_registered(FsWatchPlugin, "FLURX|fs::unwatch");
+    }
+
+    #[test]
+    fn emit_created_file() {
+        let tmp_dir = std::env::temp_dir().join("watch_emit_created_file");
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        create_dir_all(&tmp_dir).unwrap();
+
+        let mut app = test_app();
+        app.add_plugins(FsWatchPlugin);
+        let emitted = Arc::new(Mutex::new(Vec::new()));
+        let sender = emitted.clone();
+        app.add_observer(move |trigger: Trigger<EmitIpcEvent>| {
+            sender
+                .lock()
+                .unwrap()
+                .push((trigger.id.clone(), trigger.payload.as_str().to_string()));
+        });
+        let webview_entity = app.world_mut().spawn(IpcHandlers::default()).id();
+        let args = Args {
+            path: tmp_dir.clone(),
+            ..default()
+        };
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let id = task
+                    .will(Update, once::run(watch_system).with((args, webview_entity)))
+                    .await
+                    .unwrap();
+                assert_eq!(id, 0);
+            }));
+        app.update();
+
+        std::fs::write(tmp_dir.join("hello.txt"), "hello").unwrap();
+        let expected = EventPayload::new(WatchEvent {
+            kind: WatchEventKind::Create,
+            paths: vec![tmp_dir.join("hello.txt")],
+        })
+        .as_str()
+        .to_string();
+        for _ in 0..500 {
+            app.update();
+            if emitted
+                .lock()
+                .unwrap()
+                .contains(&("FLURX|fs::watch::0".to_string(), expected.clone()))
+            {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("The created file was not emitted");
+    }
+
+    #[test]
+    fn remove_watcher_when_webview_despawned() {
+        let tmp_dir = std::env::temp_dir().join("watch_remove_watcher");
+        let _ = create_dir_all(&tmp_dir);
+
+        let mut app = test_app();
+        app.add_plugins(FsWatchPlugin);
+        let webview_entity = app.world_mut().spawn(IpcHandlers::default()).id();
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                task.will(
+                    Update,
+                    once::run(watch_system).with((
+                        Args {
+                            path: tmp_dir,
+                            ..default()
+                        },
+                        webview_entity,
+                    )),
+                )
+                .await
+                .unwrap();
+            }));
+        app.update();
+        assert_eq!(
+            app.world().non_send_resource::<FsWatchers>().watches.len(),
+            1
+        );
+
+        app.world_mut().despawn(webview_entity);
+        app.update();
+        assert!(
+            app.world()
+                .non_send_resource::<FsWatchers>()
+                .watches
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_plugins(FsWatchPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(watch_system).with((
+                                Args {
+                                    path: tmp_dir,
+                                    ..default()
+                                },
+                                Entity::PLACEHOLDER,
+                            )),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+}
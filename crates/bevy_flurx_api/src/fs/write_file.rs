@@ -1,13 +1,17 @@
 use crate::error::ApiResult;
-use crate::fs::{AllowPaths, BaseDirectory, error_if_not_accessible, join_path_if_need};
+use crate::error::fs::FileLocked;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
 use crate::macros::api_plugin;
 use bevy::prelude::{In, Res};
 use bevy_flurx::action::{Action, once};
 use bevy_flurx::prelude::Pipe;
 use bevy_flurx_ipc::prelude::*;
 use serde::Deserialize;
+use std::fs::{File, TryLockError};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 api_plugin!(
     /// You'll be able to write a file from typescript(or js).
@@ -21,6 +25,13 @@ api_plugin!(
     ///     recursive: true
     /// })
     /// ```
+    ///
+    /// ## Write Options
+    ///
+    /// - `atomic`: Writes to a temporary file in the same directory, syncs it, and then renames it to the target,
+    ///   so the file is never left half-written.
+    /// - `lock`: Takes an advisory lock on `<file>.lock` while writing, and fails if another writer holds it.
+    /// - `backup`: Copies the previous version to `<file>.bak` before writing.
     FsWriteBinaryFilePlugin,
     command: write_binary_file
 );
@@ -31,12 +42,15 @@ api_plugin!(
     /// ## Typescript Code Example
     ///
     /// ```ts
-    /// await window.__FLURX__.fs.writeTextFile("./hoge.txt", "file contents", {
-    ///     dir: "Download",
-    ///     append: true,
-    ///     recursive: true
+    /// await window.__FLURX__.fs.writeTextFile("./save.json", "file contents", {
+    ///     dir: "AppData",
+    ///     atomic: true,
+    ///     lock: true,
+    ///     backup: true,
     /// })
     /// ```
+    ///
+    /// See [`FsWriteBinaryFilePlugin`] for the write options.
     FsWriteTextFilePlugin,
     command: write_text_file
 );
@@ -48,6 +62,9 @@ struct BinaryFileArgs {
     dir: Option<BaseDirectory>,
     append: Option<bool>,
     recursive: Option<bool>,
+    atomic: Option<bool>,
+    lock: Option<bool>,
+    backup: Option<bool>,
 }
 
 #[derive(Deserialize, Default)]
@@ -57,6 +74,9 @@ struct TextFileArgs {
     dir: Option<BaseDirectory>,
     append: Option<bool>,
     recursive: Option<bool>,
+    atomic: Option<bool>,
+    lock: Option<bool>,
+    backup: Option<bool>,
 }
 
 #[command(id = "FLURX|fs::write_binary_file")]
@@ -72,13 +92,20 @@ fn write_text_file(In(args): In<TextFileArgs>) -> Action<TextFileArgs, ApiResult
         dir: args.dir,
         append: args.append,
         recursive: args.recursive,
+        atomic: args.atomic,
+        lock: args.lock,
+        backup: args.backup,
     })
     .pipe(once::run(write_file_system))
     .with(args)
 }
 
-fn write_file_system(In(args): In<BinaryFileArgs>, scope: Option<Res<AllowPaths>>) -> ApiResult {
-    let path = join_path_if_need(&args.dir, args.path);
+fn write_file_system(
+    In(args): In<BinaryFileArgs>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
     error_if_not_accessible(&path, &scope)?;
     let append = args.append.is_some_and(|append| append);
     if args.recursive.is_some_and(|recursive| recursive) {
@@ -89,20 +116,82 @@ fn write_file_system(In(args): In<BinaryFileArgs>, scope: Option<Res<AllowPaths>
         }
     }
 
-    let mut file = std::fs::OpenOptions::new()
+    let _lock = if args.lock.is_some_and(|lock| lock) {
+        Some(lock_file(&path)?)
+    } else {
+        None
+    };
+    if args.backup.is_some_and(|backup| backup) && path.exists() {
+        std::fs::copy(&path, sibling_path(&path, ".bak"))?;
+    }
+    if args.atomic.is_some_and(|atomic| atomic) {
+        write_atomic(&path, &args.contents, append)
+    } else {
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)?;
+        file.write_all(&args.contents)?;
+        Ok(())
+    }
+}
+
+/// Takes the advisory lock on `<file>.lock` instead of the file itself,
+/// because the file is replaced by the atomic write and windows can't rename a locked file.
+///
+/// The lock is released when the returned file is dropped.
+fn lock_file(path: &Path) -> ApiResult<File> {
+    let lock = std::fs::OpenOptions::new()
         .write(true)
         .create(true)
-        .append(append)
-        .truncate(!append)
-        .open(path)?;
-    file.write_all(&args.contents)?;
+        .truncate(false)
+        .open(sibling_path(path, ".lock"))?;
+    match lock.try_lock() {
+        Ok(()) => Ok(lock),
+        Err(TryLockError::WouldBlock) => Err(FileLocked(path.to_path_buf()).into()),
+        Err(TryLockError::Error(e)) => Err(e.into()),
+    }
+}
+
+fn write_atomic(path: &Path, contents: &[u8], append: bool) -> ApiResult {
+    let tmp_path = sibling_path(path, &format!(".{}.tmp", std::process::id()));
+    let result = (|| {
+        let mut tmp = File::create(&tmp_path)?;
+        if append && path.exists() {
+            std::io::copy(&mut File::open(path)?, &mut tmp)?;
+        }
+        tmp.write_all(contents)?;
+        tmp.sync_all()?;
+        drop(tmp);
+        std::fs::rename(&tmp_path, path)
+    })();
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(e.into());
+    }
+    #[cfg(unix)]
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        // Syncs the directory so that the rename itself survives a crash.
+        File::open(parent)?.sync_all()?;
+    }
     Ok(())
 }
 
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
 #[cfg(test)]
 //noinspection DuplicatedCode
 mod tests {
-    use crate::fs::write_file::{BinaryFileArgs, write_file_system};
+    use crate::fs::write_file::{BinaryFileArgs, lock_file, write_file_system};
     use crate::tests::test_app;
     use bevy::prelude::*;
     use bevy::utils::default;
@@ -219,4 +308,131 @@ mod tests {
         });
         app.update();
     }
+
+    #[test]
+    fn write_atomically() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir().join("write_file_atomic");
+                let _ = std::fs::remove_dir_all(&tmp_dir);
+                std::fs::create_dir_all(&tmp_dir).unwrap();
+                let path = tmp_dir.join("save.json");
+                std::fs::write(&path, "old").unwrap();
+                let result: Result<_, _> = task
+                    .will(
+                        Update,
+                        once::run(write_file_system).with(BinaryFileArgs {
+                            path: path.clone(),
+                            contents: b"new".to_vec(),
+                            atomic: Some(true),
+                            ..default()
+                        }),
+                    )
+                    .await;
+                result.unwrap();
+                assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+                // The temporary file must not be left.
+                assert_eq!(std::fs::read_dir(&tmp_dir).unwrap().count(), 1);
+            }));
+        });
+        app.update();
+    }
+
+    #[test]
+    fn append_atomically() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let path = std::env::temp_dir().join("write_file_append_atomic.txt");
+                std::fs::write(&path, "hoge").unwrap();
+                let result: Result<_, _> = task
+                    .will(
+                        Update,
+                        once::run(write_file_system).with(BinaryFileArgs {
+                            path: path.clone(),
+                            contents: b"hoge".to_vec(),
+                            append: Some(true),
+                            atomic: Some(true),
+                            ..default()
+                        }),
+                    )
+                    .await;
+                result.unwrap();
+                assert_eq!(std::fs::read_to_string(&path).unwrap(), "hogehoge");
+            }));
+        });
+        app.update();
+    }
+
+    #[test]
+    fn create_backup() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let tmp_dir = std::env::temp_dir();
+                let path = tmp_dir.join("write_file_backup.txt");
+                std::fs::write(&path, "old").unwrap();
+                let result: Result<_, _> = task
+                    .will(
+                        Update,
+                        once::run(write_file_system).with(BinaryFileArgs {
+                            path: path.clone(),
+                            contents: b"new".to_vec(),
+                            backup: Some(true),
+                            ..default()
+                        }),
+                    )
+                    .await;
+                result.unwrap();
+                assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+                assert_eq!(
+                    std::fs::read_to_string(tmp_dir.join("write_file_backup.txt.bak")).unwrap(),
+                    "old"
+                );
+            }));
+        });
+        app.update();
+    }
+
+    #[test]
+    fn err_if_locked_by_another_writer() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let path = std::env::temp_dir().join("write_file_locked.txt");
+                std::fs::write(&path, "old").unwrap();
+                let lock = lock_file(&path).unwrap();
+                let result: Result<_, _> = task
+                    .will(
+                        Update,
+                        once::run(write_file_system).with(BinaryFileArgs {
+                            path: path.clone(),
+                            contents: b"new".to_vec(),
+                            lock: Some(true),
+                            ..default()
+                        }),
+                    )
+                    .await;
+                result.unwrap_err();
+                assert_eq!(std::fs::read_to_string(&path).unwrap(), "old");
+
+                drop(lock);
+                let result: Result<_, _> = task
+                    .will(
+                        Update,
+                        once::run(write_file_system).with(BinaryFileArgs {
+                            path: path.clone(),
+                            contents: b"new".to_vec(),
+                            lock: Some(true),
+                            ..default()
+                        }),
+                    )
+                    .await;
+                result.unwrap();
+                assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+            }));
+        });
+        app.update();
+    }
 }
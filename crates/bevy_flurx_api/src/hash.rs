@@ -0,0 +1,301 @@
+//! Provides mechanism to compute the hashes and checksums of files and buffers.
+//!
+//! The files are read in another thread and restricted by [`AllowPaths`](crate::fs::AllowPaths).
+
+use crate::error::ApiResult;
+use crate::fs::{
+    AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible, join_path_if_need,
+};
+use crate::macros::api_plugin;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::{In, PluginGroup, Res};
+use bevy_flurx::action::{Action, once};
+use bevy_flurx::prelude::{Pipe, side_effect};
+use bevy_flurx_ipc::prelude::*;
+use serde::Deserialize;
+use sha2::Digest;
+use std::fmt::Write;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Allows you to use all hash plugins.
+///
+/// ## Plugins
+///
+/// - [HashFilePlugin]
+/// - [HashBytesPlugin]
+pub struct AllHashPlugins;
+impl PluginGroup for AllHashPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(HashFilePlugin)
+            .add(HashBytesPlugin)
+    }
+}
+
+api_plugin!(
+    /// You'll be able to compute the hash of a file from typescript(or js).
+    ///
+    /// The file is read in chunks in another thread, so a large file doesn't freeze the frame.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const hash: string = await window.__FLURX__.hash.file("./mods.zip", "sha256", {
+    ///     dir: "Download",
+    /// });
+    /// ```
+    HashFilePlugin,
+    command: file
+);
+
+api_plugin!(
+    /// You'll be able to compute the hash of a text or bytes from typescript(or js).
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const hash: string = await window.__FLURX__.hash.bytes(new Uint8Array([0, 1, 2]), "blake3", {
+    ///     encoding: "base64",
+    /// });
+    /// ```
+    HashBytesPlugin,
+    command: bytes
+);
+
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum HashAlgorithm {
+    Sha256,
+    Sha1,
+    Blake3,
+    Crc32,
+}
+
+/// The encoding of the output; hex is used if not specified.
+#[derive(Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+enum HashEncoding {
+    #[default]
+    Hex,
+    Base64,
+}
+
+#[derive(Deserialize)]
+struct FileArgs {
+    path: PathBuf,
+    dir: Option<BaseDirectory>,
+    algorithm: HashAlgorithm,
+    #[serde(default)]
+    encoding: HashEncoding,
+}
+
+#[derive(Deserialize)]
+struct BytesArgs {
+    contents: Contents,
+    algorithm: HashAlgorithm,
+    #[serde(default)]
+    encoding: HashEncoding,
+}
+
+/// A text is hashed as UTF-8 bytes.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Contents {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+enum Hasher {
+    Sha256(sha2::Sha256),
+    Sha1(sha1::Sha1),
+    Blake3(Box<blake3::Hasher>),
+    Crc32(crc32fast::Hasher),
+}
+
+impl Hasher {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+            HashAlgorithm::Sha1 => Self::Sha1(sha1::Sha1::new()),
+            HashAlgorithm::Blake3 => Self::Blake3(Box::default()),
+            HashAlgorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(bytes),
+            Self::Sha1(hasher) => hasher.update(bytes),
+            Self::Blake3(hasher) => {
+                hasher.update(bytes);
+            }
+            Self::Crc32(hasher) => hasher.update(bytes),
+        }
+    }
+
+    /// Returns the digest; CRC32 is returned in big-endian.
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+            Self::Sha1(hasher) => hasher.finalize().to_vec(),
+            Self::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+            Self::Crc32(hasher) => hasher.finalize().to_be_bytes().to_vec(),
+        }
+    }
+}
+
+#[command(id = "FLURX|hash::file")]
+fn file(In(args): In<FileArgs>) -> Action<FileArgs, ApiResult<String>> {
+    file_action(args)
+}
+
+#[command(id = "FLURX|hash::bytes")]
+fn bytes(In(args): In<BytesArgs>) -> Action<BytesArgs, String> {
+    once::run(bytes_system).with(args)
+}
+
+fn file_action(args: FileArgs) -> Action<FileArgs, ApiResult<String>> {
+    once::run(file_system)
+        .with(args)
+        .pipe(side_effect::thread::spawn(
+            |args: ApiResult<(PathBuf, HashAlgorithm, HashEncoding)>| {
+                let (path, algorithm, encoding) = args?;
+                Ok(encode(hash_file(&path, algorithm)?, encoding))
+            },
+        ))
+}
+
+fn file_system(
+    In(args): In<FileArgs>,
+    scope: Option<Res<AllowPaths>>,
+    identifier: Option<Res<AppIdentifier>>,
+) -> ApiResult<(PathBuf, HashAlgorithm, HashEncoding)> {
+    let path = join_path_if_need(&args.dir, args.path, &identifier)?;
+    error_if_not_accessible(&path, &scope)?;
+    Ok((path, args.algorithm, args.encoding))
+}
+
+fn bytes_system(In(args): In<BytesArgs>) -> String {
+    let mut hasher = Hasher::new(args.algorithm);
+    match &args.contents {
+        Contents::Text(text) => hasher.update(text.as_bytes()),
+        Contents::Bytes(bytes) => hasher.update(bytes),
+    }
+    encode(hasher.finalize(), args.encoding)
+}
+
+fn hash_file(path: &Path, algorithm: HashAlgorithm) -> ApiResult<Vec<u8>> {
+    let mut file = File::open(path)?;
+    let mut hasher = Hasher::new(algorithm);
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let len = file.read(&mut buf)?;
+        if len == 0 {
+            break;
+        }
+        hasher.update(&buf[..len]);
+    }
+    Ok(hasher.finalize())
+}
+
+fn encode(digest: Vec<u8>, encoding: HashEncoding) -> String {
+    match encoding {
+        HashEncoding::Hex => digest.iter().fold(String::new(), |mut hex, b| {
+            let _ = write!(hex, "{b:02x}");
+            hex
+        }),
+        HashEncoding::Base64 => STANDARD.encode(digest),
+    }
+}
+
+#[cfg(test)]
+//noinspection DuplicatedCode
+mod tests {
+    use crate::error::ApiResult;
+    use crate::fs::AllowPaths;
+    use crate::hash::{
+        FileArgs, HashAlgorithm, HashEncoding, Hasher, encode, file_action, file_system,
+    };
+    use crate::tests::{run_action_in_app, test_app};
+    use bevy::prelude::*;
+    use bevy_flurx::action::once;
+    use bevy_flurx::prelude::{Reactor, Then};
+
+    fn hash(bytes: &[u8], algorithm: HashAlgorithm, encoding: HashEncoding) -> String {
+        let mut hasher = Hasher::new(algorithm);
+        hasher.update(bytes);
+        encode(hasher.finalize(), encoding)
+    }
+
+    #[test]
+    fn hash_bytes() {
+        assert_eq!(
+            hash(b"abc", HashAlgorithm::Sha256, HashEncoding::Hex),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hash(b"abc", HashAlgorithm::Sha1, HashEncoding::Hex),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hash(b"abc", HashAlgorithm::Blake3, HashEncoding::Hex),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        assert_eq!(
+            hash(b"abc", HashAlgorithm::Crc32, HashEncoding::Hex),
+            "352441c2"
+        );
+        assert_eq!(
+            hash(b"abc", HashAlgorithm::Sha256, HashEncoding::Base64),
+            "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0="
+        );
+    }
+
+    #[test]
+    fn hash_file() {
+        let path = std::env::temp_dir().join("hash_file.txt");
+        std::fs::write(&path, "abc").unwrap();
+        let hash = hash_file_in_app(FileArgs {
+            path,
+            dir: None,
+            algorithm: HashAlgorithm::Sha256,
+            encoding: HashEncoding::Hex,
+        })
+        .unwrap();
+        assert_eq!(
+            hash,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn err_if_out_of_scope() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result: Result<_, _> = task
+                    .will(Update, {
+                        once::res::insert().with(AllowPaths::default()).then(
+                            once::run(file_system).with(FileArgs {
+                                path: std::env::temp_dir().join("hash_out_of_scope.txt"),
+                                dir: None,
+                                algorithm: HashAlgorithm::Sha256,
+                                encoding: HashEncoding::Hex,
+                            }),
+                        )
+                    })
+                    .await;
+                result.unwrap_err();
+            }));
+        });
+        app.update();
+    }
+
+    fn hash_file_in_app(args: FileArgs) -> ApiResult<String> {
+        run_action_in_app(move || file_action(args))
+    }
+}
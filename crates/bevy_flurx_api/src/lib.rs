@@ -3,6 +3,9 @@
 #![doc = include_str!("../README.md")]
 
 pub mod app;
+#[cfg(feature = "archive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "archive")))]
+pub mod archive;
 #[cfg(feature = "clipboard")]
 #[cfg_attr(docsrs, doc(cfg(feature = "clipboard")))]
 pub mod clipboard;
@@ -13,6 +16,9 @@ mod error;
 #[cfg(feature = "fs")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
 pub mod fs;
+#[cfg(feature = "hash")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hash")))]
+pub mod hash;
 #[cfg(feature = "http")]
 #[cfg_attr(docsrs, doc(cfg(feature = "http")))]
 pub mod http;
@@ -29,16 +35,22 @@ pub mod os;
 #[cfg(feature = "fs")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
 pub mod path;
+#[cfg(feature = "fs")]
+mod progress;
 #[cfg(feature = "web_window")]
 #[cfg_attr(docsrs, doc(cfg(feature = "web_window")))]
 pub mod web_window;
 
 #[allow(missing_docs)]
 pub mod prelude {
+    #[cfg(feature = "archive")]
+    pub use crate::archive::*;
     #[cfg(feature = "clipboard")]
     pub use crate::clipboard::*;
     #[cfg(feature = "dialog")]
     pub use crate::dialog::*;
+    #[cfg(feature = "hash")]
+    pub use crate::hash::*;
     #[cfg(feature = "http")]
     pub use crate::http::*;
     #[cfg(feature = "monitor")]
@@ -82,9 +94,12 @@ mod macros {
 mod tests {
     use bevy::ecs::system::RunSystemOnce;
     use bevy::prelude::*;
+    use bevy_flurx::prelude::{Action, Reactor};
     use bevy_flurx_ipc::FlurxIpcPlugin;
     use bevy_flurx_ipc::component::IpcHandlers;
     use bevy_webview_wry::prelude::WebviewInitialized;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     pub fn test_app() -> App {
         let mut app = App::new();
@@ -92,6 +107,34 @@ mod tests {
         app
     }
 
+    /// Runs the action in the app, and waits for the output.
+    ///
+    /// The app is updated until the action completes, so that the action running in another thread can be awaited.
+    pub fn run_action_in_app<I, O>(
+        action: impl FnOnce() -> Action<I, O> + Send + Sync + 'static,
+    ) -> O
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        let mut app = test_app();
+        let output = Arc::new(Mutex::new(None));
+        let sender = output.clone();
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let result = task.will(Update, action()).await;
+                *sender.lock().unwrap() = Some(result);
+            }));
+        for _ in 0..1000 {
+            app.update();
+            if let Some(output) = output.lock().unwrap().take() {
+                return output;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("Timed out running the action");
+    }
+
     pub fn assert_api_registered<P: Plugin>(plugin: P, ipc_id: &'static str) {
         let mut app = test_app();
         app.add_plugins(plugin);
@@ -1,6 +1,6 @@
 //! Provides apis to obtain special system paths.
 
-use crate::fs::{AllowPaths, error_if_not_accessible};
+use crate::fs::{AllowPaths, AppIdentifier, BaseDirectory, error_if_not_accessible};
 use crate::macros::api_plugin;
 use bevy::app::PluginGroupBuilder;
 use bevy::prelude::{PluginGroup, Res};
@@ -31,6 +31,10 @@ use std::path::PathBuf;
 /// - [PathHomePlugin]
 /// - [PathPicturePlugin]
 /// - [PathFontPlugin]
+/// - [PathAppDataPlugin]
+/// - [PathAppConfigPlugin]
+/// - [PathAppCachePlugin]
+/// - [PathAppLogPlugin]
 pub struct AllPathPlugins;
 impl PluginGroup for AllPathPlugins {
     fn build(self) -> PluginGroupBuilder {
@@ -53,6 +57,10 @@ impl PluginGroup for AllPathPlugins {
             .add(PathHomePlugin)
             .add(PathPicturePlugin)
             .add(PathFontPlugin)
+            .add(PathAppDataPlugin)
+            .add(PathAppConfigPlugin)
+            .add(PathAppCachePlugin)
+            .add(PathAppLogPlugin)
     }
 }
 
@@ -304,6 +312,66 @@ api_plugin!(
     command: font
 );
 
+api_plugin!(
+    /// You'll be able to obtain the app-specific data path from typescript(or js).
+    ///
+    /// The path is resolved from [AppIdentifier].
+    /// If [AppIdentifier] is not inserted or the path is not permitted by [AllowPaths], will be null.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const path: string | null = await window.__FLURX__.path.appData();
+    /// ```
+    PathAppDataPlugin,
+    command: app_data
+);
+
+api_plugin!(
+    /// You'll be able to obtain the app-specific config path from typescript(or js).
+    ///
+    /// The path is resolved from [AppIdentifier].
+    /// If [AppIdentifier] is not inserted or the path is not permitted by [AllowPaths], will be null.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const path: string | null = await window.__FLURX__.path.appConfig();
+    /// ```
+    PathAppConfigPlugin,
+    command: app_config
+);
+
+api_plugin!(
+    /// You'll be able to obtain the app-specific cache path from typescript(or js).
+    ///
+    /// The path is resolved from [AppIdentifier].
+    /// If [AppIdentifier] is not inserted or the path is not permitted by [AllowPaths], will be null.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const path: string | null = await window.__FLURX__.path.appCache();
+    /// ```
+    PathAppCachePlugin,
+    command: app_cache
+);
+
+api_plugin!(
+    /// You'll be able to obtain the app-specific log path from typescript(or js).
+    ///
+    /// The path is resolved from [AppIdentifier].
+    /// If [AppIdentifier] is not inserted or the path is not permitted by [AllowPaths], will be null.
+    ///
+    /// ## Typescript Code Example
+    ///
+    /// ```ts
+    /// const path: string | null = await window.__FLURX__.path.appLog();
+    /// ```
+    PathAppLogPlugin,
+    command: app_log
+);
+
 #[command(id = "FLURX|path::config")]
 fn config() -> ActionSeed<(), Option<PathBuf>> {
     once::run(obtain_path(dirs::config_dir))
@@ -397,6 +465,26 @@ fn font() -> ActionSeed<(), Option<PathBuf>> {
     once::run(obtain_path(dirs::font_dir))
 }
 
+#[command(id = "FLURX|path::app_data")]
+fn app_data() -> ActionSeed<(), Option<PathBuf>> {
+    once::run(obtain_app_path(BaseDirectory::AppData))
+}
+
+#[command(id = "FLURX|path::app_config")]
+fn app_config() -> ActionSeed<(), Option<PathBuf>> {
+    once::run(obtain_app_path(BaseDirectory::AppConfig))
+}
+
+#[command(id = "FLURX|path::app_cache")]
+fn app_cache() -> ActionSeed<(), Option<PathBuf>> {
+    once::run(obtain_app_path(BaseDirectory::AppCache))
+}
+
+#[command(id = "FLURX|path::app_log")]
+fn app_log() -> ActionSeed<(), Option<PathBuf>> {
+    once::run(obtain_app_path(BaseDirectory::AppLog))
+}
+
 fn obtain_path(f: fn() -> Option<PathBuf>) -> impl Fn(Option<Res<AllowPaths>>) -> Option<PathBuf> {
     move |scope: Option<Res<AllowPaths>>| {
         let path = f()?;
@@ -407,3 +495,16 @@ fn obtain_path(f: fn() -> Option<PathBuf>) -> impl Fn(Option<Res<AllowPaths>>) -
         }
     }
 }
+
+fn obtain_app_path(
+    base: BaseDirectory,
+) -> impl Fn(Option<Res<AppIdentifier>>, Option<Res<AllowPaths>>) -> Option<PathBuf> {
+    move |identifier: Option<Res<AppIdentifier>>, scope: Option<Res<AllowPaths>>| {
+        let path = base.as_path(identifier.as_deref())?;
+        if error_if_not_accessible(&path, &scope).is_ok() {
+            Some(path)
+        } else {
+            None
+        }
+    }
+}
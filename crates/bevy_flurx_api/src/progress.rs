@@ -0,0 +1,55 @@
+use bevy::prelude::{Commands, Entity, In, Update};
+use bevy_flurx::action::once;
+use bevy_flurx::task::ReactorTask;
+use bevy_webview_core::backend::{EmitIpcEvent, EventPayload};
+use serde::Serialize;
+use std::sync::mpsc::{Sender, channel};
+
+/// Runs `f` in another thread, and emits the latest progress reported through the sender
+/// to the webview as the event `event_id` at most once per frame.
+///
+/// No event is emitted if `event_id` is none.
+pub(crate) async fn spawn_with_progress<P, O, F>(
+    task: &ReactorTask,
+    webview_entity: Entity,
+    event_id: Option<String>,
+    f: F,
+) -> O
+where
+    P: Serialize + Send + 'static,
+    O: Send + 'static,
+    F: FnOnce(Sender<P>) -> O + Send + 'static,
+{
+    let (tx, rx) = channel();
+    let handle = std::thread::spawn(move || f(tx));
+    loop {
+        // Checks before draining so that no progress is missed after the thread finished.
+        let finished = handle.is_finished();
+        let event = event_id
+            .clone()
+            .zip(rx.try_iter().last())
+            .map(|(id, progress)| EmitIpcEvent {
+                id,
+                payload: EventPayload::new(progress),
+            });
+        task.will(
+            Update,
+            once::run(emit_progress).with((webview_entity, event)),
+        )
+        .await;
+        if finished {
+            return handle
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e));
+        }
+    }
+}
+
+fn emit_progress(
+    In((webview_entity, event)): In<(Entity, Option<EmitIpcEvent>)>,
+    mut commands: Commands,
+) {
+    if let Some(event) = event {
+        commands.entity(webview_entity).trigger(event);
+    }
+}
@@ -0,0 +1,121 @@
+;(() => {
+    const REGIONS_EVENT_ID = "FLURX|webview::interactive_regions";
+    const HIT_TEST_EVENT_ID = "FLURX|webview::hit_test";
+    const INTERACTIVE_SELECTOR = "[data-flurx-interactive]";
+    // The elements painting their contents regardless of the styles.
+    const REPLACED_ELEMENTS = new Set(["IMG", "VIDEO", "CANVAS", "SVG", "IFRAME", "EMBED", "OBJECT", "INPUT", "TEXTAREA", "SELECT", "BUTTON"]);
+
+    let mode = "disabled";
+    let scheduled = false;
+    let lastRegions = null;
+    let rects = [];
+    let lastHit = null;
+
+    const isTransparent = (color) => color === "transparent" || /^rgba\(.*,\s*0\)$/.test(color);
+
+    const paintsBox = (element, style) => REPLACED_ELEMENTS.has(element.tagName.toUpperCase())
+        || !isTransparent(style.backgroundColor)
+        || style.backgroundImage !== "none"
+        || style.boxShadow !== "none"
+        || ["Top", "Right", "Bottom", "Left"].some((side) =>
+            parseFloat(style[`border${side}Width`]) > 0 && !isTransparent(style[`border${side}Color`]));
+
+    // Approximates the areas where the page paints something, since the pixels themselves can't be read.
+    const paintedRects = () => {
+        const result = [];
+        if (!document.documentElement) {
+            return result;
+        }
+        const walker = document.createTreeWalker(document.documentElement, NodeFilter.SHOW_ELEMENT | NodeFilter.SHOW_TEXT);
+        for (let node = walker.currentNode; node; node = walker.nextNode()) {
+            if (node.nodeType === Node.TEXT_NODE) {
+                const parent = node.parentElement;
+                if (node.textContent.trim() && parent && getComputedStyle(parent).visibility === "visible") {
+                    const range = document.createRange();
+                    range.selectNodeContents(node);
+                    result.push(...range.getClientRects());
+                }
+                continue;
+            }
+            const style = getComputedStyle(node);
+            if (style.visibility === "visible" && style.opacity !== "0" && paintsBox(node, style)) {
+                result.push(node.getBoundingClientRect());
+            }
+        }
+        return result;
+    };
+
+    const report = () => {
+        scheduled = false;
+        if (mode === "disabled") {
+            return;
+        }
+        const elements = Array.from(document.querySelectorAll(INTERACTIVE_SELECTOR))
+            .map((element) => element.getBoundingClientRect());
+        if (mode === "painted") {
+            elements.push(...paintedRects());
+        }
+        rects = elements
+            .filter((rect) => 0 < rect.width && 0 < rect.height)
+            .map((rect) => [rect.left, rect.top, rect.width, rect.height]);
+        const regions = JSON.stringify(rects);
+        if (regions === lastRegions) {
+            return;
+        }
+        lastRegions = regions;
+        window.__FLURX__.emit(REGIONS_EVENT_ID, {rects});
+    };
+
+    // Coalesces the layout changes within a frame into a single report.
+    const schedule = () => {
+        if (mode !== "disabled" && !scheduled) {
+            scheduled = true;
+            requestAnimationFrame(report);
+        }
+    };
+
+    // The window underneath can't know that the cursor left the hit areas while the page receives the mouse events,
+    // so the page reports it.
+    const reportHitTest = (hit) => {
+        if (mode === "disabled" || hit === lastHit) {
+            return;
+        }
+        lastHit = hit;
+        window.__FLURX__.emit(HIT_TEST_EVENT_ID, {hit});
+    };
+
+    const contains = (x, y) => rects.some(([left, top, width, height]) =>
+        left <= x && x <= left + width && top <= y && y <= top + height);
+
+    Object.defineProperty(window.__FLURX__, "clickThrough", {
+        get: () => mode,
+        set: (value) => {
+            mode = value;
+            lastRegions = null;
+            lastHit = null;
+            schedule();
+        },
+    });
+
+    const observe = () => {
+        new MutationObserver(schedule).observe(document.documentElement, {
+            subtree: true,
+            childList: true,
+            attributes: true,
+            characterData: true,
+        });
+        schedule();
+    };
+    if (document.documentElement) {
+        observe();
+    } else {
+        document.addEventListener("DOMContentLoaded", observe);
+    }
+    window.addEventListener("resize", schedule);
+    window.addEventListener("scroll", schedule, true);
+    window.addEventListener("transitionend", schedule, true);
+    window.addEventListener("animationend", schedule, true);
+    window.addEventListener("pointermove", (e) => reportHitTest(contains(e.clientX, e.clientY)), {capture: true, passive: true});
+    // `relatedTarget` is null when the cursor leaves the page.
+    document.addEventListener("pointerout", (e) => e.relatedTarget || reportHitTest(false), true);
+})();
@@ -0,0 +1,54 @@
+;(() => {
+    let options = {keyboard: false, wheel: false};
+
+    Object.defineProperty(window.__FLURX__, "forwardInput", {
+        get: () => options,
+        set: (value) => {
+            options = value;
+        },
+    });
+
+    // Waits until the event has been dispatched to all listeners of the page,
+    // so that whether `preventDefault` has been called can be known.
+    const afterDispatch = (f) => setTimeout(f, 0);
+
+    // Typing into the fields doesn't call `preventDefault`, but the page uses the keys.
+    const isEditable = (element) => element instanceof HTMLInputElement
+        || element instanceof HTMLTextAreaElement
+        || element instanceof HTMLSelectElement
+        || (element instanceof HTMLElement && element.isContentEditable);
+
+    const onKey = (pressed) => (e) => {
+        if (!options.keyboard || e.isComposing) {
+            return;
+        }
+        afterDispatch(() => {
+            window.__FLURX__.emit("FLURX|webview::key", {
+                code: e.code,
+                key: e.key,
+                pressed,
+                repeat: e.repeat,
+                handled: e.defaultPrevented,
+                editable: isEditable(e.target),
+            });
+        });
+    };
+    window.addEventListener("keydown", onKey(true), true);
+    window.addEventListener("keyup", onKey(false), true);
+
+    window.addEventListener("wheel", (e) => {
+        if (!options.wheel) {
+            return;
+        }
+        afterDispatch(() => {
+            if (e.defaultPrevented) {
+                return;
+            }
+            window.__FLURX__.emit("FLURX|webview::wheel", {
+                deltaX: e.deltaX,
+                deltaY: e.deltaY,
+                deltaMode: e.deltaMode,
+            });
+        });
+    }, {capture: true, passive: true});
+})();
@@ -0,0 +1,60 @@
+;(() => {
+    const EVENT_ID = "FLURX|webview::screenshot";
+
+    // Copies the rules of the stylesheets instead of inlining the computed style of every element.
+    // Cross-origin stylesheets can't be read, so they are skipped.
+    const collectStyles = () => {
+        const rules = [];
+        for (const sheet of document.styleSheets) {
+            try {
+                for (const rule of sheet.cssRules) {
+                    rules.push(rule.cssText);
+                }
+            } catch (_) {
+            }
+        }
+        const style = document.createElement("style");
+        style.textContent = rules.join("\n");
+        return style;
+    };
+
+    const renderToCanvas = async () => {
+        const width = document.documentElement.clientWidth;
+        const height = document.documentElement.clientHeight;
+        const clone = document.documentElement.cloneNode(true);
+        clone.querySelectorAll("script, style, link[rel='stylesheet']").forEach((node) => node.remove());
+        (clone.querySelector("head") ?? clone).appendChild(collectStyles());
+        clone.style.transform = `translate(${-window.scrollX}px, ${-window.scrollY}px)`;
+
+        const xhtml = new XMLSerializer().serializeToString(clone);
+        const svg = `<svg xmlns="http://www.w3.org/2000/svg" width="${width}" height="${height}"><foreignObject width="100%" height="100%">${xhtml}</foreignObject></svg>`;
+        const image = new Image();
+        image.src = `data:image/svg+xml;charset=utf-8,${encodeURIComponent(svg)}`;
+        await image.decode();
+
+        const scale = window.devicePixelRatio || 1;
+        const canvas = document.createElement("canvas");
+        canvas.width = Math.round(width * scale);
+        canvas.height = Math.round(height * scale);
+        const context = canvas.getContext("2d");
+        context.scale(scale, scale);
+        context.drawImage(image, 0, 0);
+        return canvas.toDataURL("image/png");
+    };
+
+    Object.defineProperty(window.__FLURX__, "__takeScreenshot", {
+        value: (requestId) => {
+            renderToCanvas()
+                .then((dataUrl) => window.__FLURX__.emit(EVENT_ID, {
+                    request_id: requestId,
+                    data_url: dataUrl,
+                }))
+                .catch((e) => window.__FLURX__.emit(EVENT_ID, {
+                    request_id: requestId,
+                    error: String(e),
+                }));
+        },
+        writable: false,
+        configurable: false,
+    });
+})();
@@ -0,0 +1,310 @@
+//! Declares the interface that the crates which actually implement the webview must provide.
+
+pub use click_through::CLICK_THROUGH_SCRIPT;
+pub use emit_event::{EmitIpcEvent, EventPayload};
+pub use error::{BackendError, BackendResult};
+pub use forward_input::FORWARD_INPUT_SCRIPT;
+#[cfg(feature = "offscreen")]
+pub use offscreen::OffscreenFrame;
+pub use print::{
+    ExportPdf, PageSize, PrintCompleted, PrintCompletion, PrintMargins, PrintOutput, PrintWebview,
+};
+pub use screenshot::{
+    SCREENSHOT_SCRIPT, Screenshot, ScreenshotCaptured, ScreenshotCompletion, TakeScreenshot,
+    take_screenshot,
+};
+
+use crate::backend::bounds::apply_bounds;
+use crate::backend::click_through::{
+    OnInteractiveRegions, OnPageHitTest, apply_click_through, apply_cursor_hit_test,
+    receive_interactive_regions, receive_page_hit_test,
+};
+use crate::backend::devtools::{DevtoolsReady, apply_open_devtools};
+use crate::backend::emit_event::apply_emit_event;
+use crate::backend::focus::{OnPagePointerDown, apply_page_focus};
+use crate::backend::forward_input::{
+    OnPageKey, OnPageWheel, apply_forward_input, forward_page_key, forward_page_wheel,
+};
+use crate::backend::ipc_resolve::resolve_ipc_event;
+use crate::backend::load::{apply_reload, load_changed_webview};
+use crate::backend::print::{
+    PrintCompletedQueue, apply_export_pdf, apply_print, send_print_completed,
+};
+use crate::backend::screenshot::{
+    DomScreenshot, ScreenshotCapturedQueue, apply_take_screenshot, receive_dom_screenshot,
+    send_screenshot_captured,
+};
+use crate::backend::visible::apply_visible;
+use crate::backend::zoom::apply_zoom;
+use crate::prelude::{Bounds, Webview};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::{App, Entity, Plugin, PostUpdate, PreUpdate, Update};
+use bevy_flurx_ipc::prelude::IpcTriggerExt;
+use std::marker::PhantomData;
+
+mod bounds;
+mod click_through;
+mod devtools;
+mod emit_event;
+mod error;
+mod focus;
+mod forward_input;
+mod ipc_resolve;
+mod load;
+#[cfg(feature = "offscreen")]
+mod offscreen;
+mod print;
+mod screenshot;
+mod visible;
+mod zoom;
+
+#[allow(missing_docs)]
+pub mod prelude {
+    #[cfg(feature = "offscreen")]
+    pub use crate::backend::OffscreenFrame;
+    pub use crate::backend::{
+        BackendError, BackendResult, EmitIpcEvent, EventPayload, ExportPdf, PageSize,
+        PrintCompleted, PrintCompletion, PrintMargins, PrintOutput, PrintWebview, Screenshot,
+        ScreenshotCaptured, ScreenshotCompletion, TakeScreenshot, WebviewBackend,
+        WebviewBackendPlugin, take_screenshot,
+    };
+}
+
+/// The webview engine that drives the webviews spawned in the [`World`](bevy::prelude::World).
+///
+/// [`World`](bevy::prelude::World) holds the backend as [`NonSend`](bevy::prelude::NonSend),
+/// so it can hold the native webview handles that are not thread safe.
+///
+/// [`WebviewBackendPlugin`] detects the changes of the webview components and dispatches them to the backend,
+/// so the backend only has to create the webview and apply the requested operations.
+///
+/// If the webview associated with the entity does not exist, the methods do nothing.
+pub trait WebviewBackend: 'static {
+    /// Loads [`Webview`] in the webview associated with the entity.
+    ///
+    /// This is called when [`Webview`] is changed after the webview has been initialized.
+    fn load(&mut self, webview_entity: Entity, webview: &Webview) -> BackendResult;
+
+    /// Reloads the current page.
+    fn reload(&mut self, webview_entity: Entity) -> BackendResult;
+
+    /// Sets the display area of the embedded webview.
+    fn set_bounds(&mut self, webview_entity: Entity, bounds: &Bounds) -> BackendResult;
+
+    /// Shows or hides the webview.
+    fn set_visible(&mut self, webview_entity: Entity, visible: bool) -> BackendResult;
+
+    /// Sets the zoom level of the page.
+    fn set_zoom(&mut self, webview_entity: Entity, scale_factor: f64) -> BackendResult;
+
+    /// Sets whether the webview receives the mouse events.
+    ///
+    /// If `false`, the mouse events must pass through the webview to the window underneath.
+    /// This is called when [`CursorHitTest`](crate::prelude::CursorHitTest) is changed.
+    ///
+    /// The default implementation does not support it.
+    fn set_cursor_hit_test(&mut self, _webview_entity: Entity, _hit_test: bool) -> BackendResult {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    /// Opens the devtools.
+    fn open_devtools(&mut self, webview_entity: Entity);
+
+    /// Closes the devtools.
+    fn close_devtools(&mut self, webview_entity: Entity);
+
+    /// Returns whether the devtools is opened.
+    ///
+    /// Returns `None` if the webview associated with the entity does not exist,
+    /// or the devtools are not available, such as in release builds.
+    /// In that case, [`IsOpenDevtools`](crate::prelude::IsOpenDevtools) is left as is.
+    fn is_devtools_open(&self, webview_entity: Entity) -> Option<bool>;
+
+    /// Takes the PNG screenshot of the current page.
+    ///
+    /// The backend passes the result to `completion` once the engine has captured the page.
+    /// If this returns an error, [`ScreenshotCaptured`] is sent as failed without calling `completion`.
+    ///
+    /// The default implementation renders the page into a canvas with [`SCREENSHOT_SCRIPT`] as a last resort
+    /// by evaluating [`ScreenshotCompletion::dom_script`].
+    fn take_screenshot(
+        &mut self,
+        webview_entity: Entity,
+        completion: ScreenshotCompletion,
+    ) -> BackendResult {
+        self.evaluate_script(webview_entity, &completion.dom_script())
+    }
+
+    /// Prints the page with the print dialog.
+    ///
+    /// The backend should pass the result to `completion` once printing finishes or is cancelled.
+    /// If the engine doesn't tell it, the completion may be reported as succeeded once the dialog is shown.
+    ///
+    /// The default implementation calls `window.print()` and reports the completion once the dialog is shown.
+    fn print(
+        &mut self,
+        webview_entity: Entity,
+        _options: &PrintWebview,
+        completion: PrintCompletion,
+    ) -> BackendResult {
+        self.evaluate_script(webview_entity, "window.print()")?;
+        completion.complete(true);
+        Ok(())
+    }
+
+    /// Exports the page as a PDF file.
+    ///
+    /// If this returns an error, [`PrintCompleted`] is sent as failed without calling `completion`.
+    ///
+    /// The default implementation does not support PDF export.
+    fn export_pdf(
+        &mut self,
+        _webview_entity: Entity,
+        _options: &ExportPdf,
+        _completion: PrintCompletion,
+    ) -> BackendResult {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    /// Returns whether the backend renders [`Offscreen`](crate::prelude::Offscreen) webviews into images.
+    ///
+    /// If this returns `false`, an error is logged when an [`Offscreen`](crate::prelude::Offscreen) webview is spawned,
+    /// and its frames are never captured.
+    ///
+    /// The default implementation does not support offscreen rendering.
+    #[cfg(feature = "offscreen")]
+    fn supports_offscreen(&self) -> bool {
+        false
+    }
+
+    /// Returns the latest frame of the [`Offscreen`](crate::prelude::Offscreen) webview.
+    ///
+    /// Returns `None` if no new frame has been rendered since the last capture.
+    ///
+    /// The default implementation does not support offscreen rendering.
+    #[cfg(feature = "offscreen")]
+    fn capture_frame(&mut self, _webview_entity: Entity) -> BackendResult<Option<OffscreenFrame>> {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    /// Forwards the input into the page of the [`Offscreen`](crate::prelude::Offscreen) webview.
+    ///
+    /// The default implementation does not support offscreen rendering.
+    #[cfg(feature = "offscreen")]
+    fn send_offscreen_input(
+        &mut self,
+        _webview_entity: Entity,
+        _input: &crate::prelude::OffscreenInput,
+    ) -> BackendResult {
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+    }
+
+    /// Evaluates the script in the webview associated with the entity.
+    fn evaluate_script(&mut self, webview_entity: Entity, script: &str) -> BackendResult;
+
+    /// Resolves the ipc command invoked from javascript.
+    ///
+    /// `output` is the serialized output value of the command.
+    fn resolve_ipc(
+        &mut self,
+        webview_entity: Entity,
+        resolve_id: usize,
+        output: &str,
+    ) -> BackendResult {
+        self.evaluate_script(
+            webview_entity,
+            &format!("window.__FLURX__.__resolveIpc({resolve_id}, {output})"),
+        )
+    }
+
+    /// Calls the event listener registered in javascript.
+    ///
+    /// `payload` is the serialized event body.
+    fn emit_event(
+        &mut self,
+        webview_entity: Entity,
+        webview_name: &str,
+        event_id: &str,
+        payload: &str,
+    ) -> BackendResult {
+        self.evaluate_script(
+            webview_entity,
+            &format!("window.__FLURX__.__emitEvent('{webview_name}', '{event_id}', {payload});"),
+        )
+    }
+}
+
+/// Connects the webview components and the ipc mechanism of [`bevy_flurx_ipc`] with the [`WebviewBackend`].
+///
+/// The backend must be inserted as a non-send resource by the crate that implements it.
+pub struct WebviewBackendPlugin<B>(PhantomData<B>);
+
+impl<B> Default for WebviewBackendPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: WebviewBackend> Plugin for WebviewBackendPlugin<B> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DevtoolsReady>()
+            .add_event::<ScreenshotCaptured>()
+            .add_event::<KeyboardInput>()
+            .add_event::<MouseWheel>()
+            .register_type::<PrintCompleted>()
+            .add_event::<PrintCompleted>()
+            .init_resource::<PrintCompletedQueue>()
+            .init_resource::<ScreenshotCapturedQueue>()
+            .add_ipc_trigger::<DomScreenshot>("FLURX|webview::screenshot")
+            .add_ipc_trigger::<OnPagePointerDown>("FLURX|webview::pointer_down")
+            .add_ipc_trigger::<OnInteractiveRegions>("FLURX|webview::interactive_regions")
+            .add_ipc_trigger::<OnPageHitTest>("FLURX|webview::hit_test")
+            .add_ipc_trigger::<OnPageKey>("FLURX|webview::key")
+            .add_ipc_trigger::<OnPageWheel>("FLURX|webview::wheel")
+            .add_systems(
+                Update,
+                (
+                    load_changed_webview::<B>,
+                    apply_visible::<B>,
+                    apply_zoom::<B>,
+                    apply_bounds::<B>,
+                    apply_click_through::<B>,
+                    apply_cursor_hit_test::<B>,
+                    apply_forward_input::<B>,
+                    resolve_ipc_event::<B>,
+                ),
+            )
+            .add_systems(PreUpdate, (send_print_completed, send_screenshot_captured))
+            .add_systems(PostUpdate, apply_open_devtools::<B>)
+            .add_observer(apply_emit_event::<B>)
+            .add_observer(apply_reload::<B>)
+            .add_observer(apply_take_screenshot::<B>)
+            .add_observer(receive_dom_screenshot)
+            .add_observer(apply_page_focus)
+            .add_observer(receive_interactive_regions)
+            .add_observer(receive_page_hit_test)
+            .add_observer(forward_page_key)
+            .add_observer(forward_page_wheel)
+            .add_observer(apply_print::<B>)
+            .add_observer(apply_export_pdf::<B>);
+
+        #[cfg(feature = "offscreen")]
+        app.add_systems(
+            PostUpdate,
+            (
+                offscreen::error_if_offscreen_unsupported::<B>,
+                offscreen::create_offscreen_images,
+                offscreen::capture_offscreen_frames::<B>,
+            )
+                .chain(),
+        )
+        .add_systems(Update, offscreen::forward_keyboard_input)
+        .add_observer(offscreen::apply_capture_offscreen_frame::<B>)
+        .add_observer(offscreen::forward_offscreen_input::<B>)
+        .add_observer(offscreen::forward_pointer_move)
+        .add_observer(offscreen::forward_pointer_pressed)
+        .add_observer(offscreen::forward_pointer_released)
+        .add_observer(offscreen::forward_pointer_scroll);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use crate::prelude::Bounds;
+use bevy::log::error;
+use bevy::prelude::{Added, Changed, Entity, NonSendMut, Or, Query};
+
+pub(crate) fn apply_bounds<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    views: Query<(Entity, &Bounds), Or<(Changed<Bounds>, Added<WebviewInitialized>)>>,
+) {
+    for (entity, bounds) in views.iter() {
+        if let Err(e) = backend.set_bounds(entity, bounds) {
+            error!("Failed to set resize bounds: {e}");
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use crate::prelude::{ClickThrough, CursorHitTest, InteractiveRegions, WebviewZoom};
+use bevy::log::{error, warn_once};
+use bevy::math::Rect;
+use bevy::prelude::{Added, Changed, Entity, Event, NonSendMut, Or, Query, Ref, Trigger};
+use serde::Deserialize;
+
+/// The initialization script that reports the hit areas of the page and whether the cursor is over them.
+///
+/// The backend must inject it to use [`ClickThrough`].
+pub const CLICK_THROUGH_SCRIPT: &str = include_str!("../../scripts/clickThrough.js");
+
+/// Emitted from the page when the hit areas have been changed.
+#[derive(Event, Deserialize)]
+pub(crate) struct OnInteractiveRegions {
+    /// `[left, top, width, height]` in CSS pixels.
+    rects: Vec<[f32; 4]>,
+}
+
+/// Emitted from the page when the cursor has entered or left the hit areas.
+#[derive(Event, Deserialize)]
+pub(crate) struct OnPageHitTest {
+    hit: bool,
+}
+
+pub(crate) fn receive_interactive_regions(
+    trigger: Trigger<OnInteractiveRegions>,
+    mut webviews: Query<(&mut InteractiveRegions, Option<&WebviewZoom>)>,
+) {
+    let Ok((mut regions, zoom)) = webviews.get_mut(trigger.target()) else {
+        return;
+    };
+    let scale = zoom.map(|zoom| zoom.0 as f32).unwrap_or(1.);
+    regions.0 = trigger
+        .rects
+        .iter()
+        .map(|&[left, top, width, height]| Rect::new(left, top, left + width, top + height))
+        .map(|rect| Rect::from_corners(rect.min * scale, rect.max * scale))
+        .collect();
+}
+
+/// Turns off the hit test when the cursor leaves the hit areas,
+/// since the window underneath doesn't receive the mouse events while the webview does.
+pub(crate) fn receive_page_hit_test(
+    trigger: Trigger<OnPageHitTest>,
+    mut webviews: Query<(&ClickThrough, &mut CursorHitTest)>,
+) {
+    let Ok((click_through, mut hit_test)) = webviews.get_mut(trigger.target()) else {
+        return;
+    };
+    if *click_through != ClickThrough::Disabled {
+        hit_test.set_if_neq(CursorHitTest(trigger.hit));
+    }
+}
+
+pub(crate) fn apply_click_through<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    views: Query<(Entity, &ClickThrough), Or<(Changed<ClickThrough>, Added<WebviewInitialized>)>>,
+) {
+    for (entity, click_through) in views.iter() {
+        if let Err(e) = backend.evaluate_script(
+            entity,
+            &format!(
+                "window.__FLURX__.clickThrough=\"{}\";",
+                click_through.as_js()
+            ),
+        ) {
+            error!("Failed to apply click through to webview {entity}: {e}");
+        }
+    }
+}
+
+pub(crate) fn apply_cursor_hit_test<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    views: Query<(Entity, Ref<CursorHitTest>)>,
+) {
+    for (entity, hit_test) in views.iter() {
+        if !hit_test.is_changed() || (hit_test.is_added() && hit_test.0) {
+            continue;
+        }
+        if let Err(e) = backend.set_cursor_hit_test(entity, hit_test.0) {
+            warn_once!("Failed to set the cursor hit test of webview {entity}: {e}");
+        }
+    }
+}
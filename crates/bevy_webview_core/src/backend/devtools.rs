@@ -0,0 +1,40 @@
+use crate::backend::WebviewBackend;
+use crate::prelude::{IsOpenDevtools, UseDevtools};
+use bevy::prelude::{
+    Commands, Component, DetectChanges, Entity, NonSendMut, Query, Reflect, ReflectComponent,
+};
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub(crate) struct DevtoolsReady;
+
+pub(crate) fn apply_open_devtools<B: WebviewBackend>(
+    mut commands: Commands,
+    mut views: Query<(
+        Entity,
+        &mut IsOpenDevtools,
+        &UseDevtools,
+        Option<&DevtoolsReady>,
+    )>,
+    mut backend: NonSendMut<B>,
+) {
+    for (entity, mut is_open, use_devtools, ready) in views.iter_mut() {
+        if !use_devtools.0 {
+            is_open.0 = false;
+            continue;
+        }
+        let Some(is_devtools_open) = backend.is_devtools_open(entity) else {
+            continue;
+        };
+        if ready.is_none() || is_open.is_changed() {
+            if is_open.0 {
+                backend.open_devtools(entity);
+            } else {
+                backend.close_devtools(entity);
+            }
+            commands.entity(entity).insert(DevtoolsReady);
+        } else if is_open.0 != is_devtools_open {
+            is_open.0 = is_devtools_open;
+        }
+    }
+}
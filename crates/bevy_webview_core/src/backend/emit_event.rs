@@ -1,5 +1,7 @@
-use crate::webview::WryWebViews;
-use bevy::prelude::*;
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use bevy::log::error;
+use bevy::prelude::{Entity, Event, Name, NonSendMut, Query, Trigger, With};
 use serde::Serialize;
 
 /// This structure is used to send events to the webview.
@@ -24,51 +26,45 @@ impl EventPayload {
         let payload = serde_json::to_string(&payload).expect("Failed to serialize payload");
         Self(payload)
     }
-}
-
-pub(crate) struct EventEmitterPlugin;
 
-impl Plugin for EventEmitterPlugin {
-    fn build(&self, app: &mut App) {
-        app.add_observer(apply_emit_event);
+    /// Returns the serialized payload.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
     }
 }
 
-fn apply_emit_event(
+pub(crate) fn apply_emit_event<B: WebviewBackend>(
     trigger: Trigger<EmitIpcEvent>,
-    webviews: Query<(Entity, &Name)>,
-    wry_webviews: NonSend<WryWebViews>,
+    webviews: Query<(Entity, &Name), With<WebviewInitialized>>,
+    mut backend: NonSendMut<B>,
 ) {
     let target = trigger.target();
     // Global event
     if target == Entity::PLACEHOLDER {
         for (entity, _) in webviews.iter() {
-            call_javascript_callback(entity, trigger.event(), &webviews, &wry_webviews);
+            call_javascript_callback(entity, trigger.event(), &webviews, &mut *backend);
         }
     } else {
-        call_javascript_callback(target, trigger.event(), &webviews, &wry_webviews);
+        call_javascript_callback(target, trigger.event(), &webviews, &mut *backend);
     }
 }
 
-fn call_javascript_callback(
+fn call_javascript_callback<B: WebviewBackend>(
     webview_entity: Entity,
     event: &EmitIpcEvent,
-    webviews: &Query<(Entity, &Name)>,
-    wry_webviews: &WryWebViews,
+    webviews: &Query<(Entity, &Name), With<WebviewInitialized>>,
+    backend: &mut B,
 ) {
-    let Some(webview) = wry_webviews.0.get(&webview_entity) else {
-        return;
-    };
     let Ok((_, name)) = webviews.get(webview_entity) else {
         return;
     };
-
-    let name = name.as_str();
-    let event_id = event.id.as_str();
-    let payload = event.payload.0.as_str();
-    if let Err(e) = webview.evaluate_script(&format!(
-        "window.__FLURX__.__emitEvent('{name}', '{event_id}', {payload});"
-    )) {
+    if let Err(e) = backend.emit_event(
+        webview_entity,
+        name.as_str(),
+        event.id.as_str(),
+        event.payload.as_str(),
+    ) {
         error!("{e}");
     }
 }
@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// The result type returned from [`WebviewBackend`](crate::backend::WebviewBackend).
+pub type BackendResult<V = ()> = Result<V, BackendError>;
+
+/// The error that occurred in the webview engine.
+pub struct BackendError(Box<dyn Error + Send + Sync>);
+
+impl Debug for BackendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for BackendError {
+    fn from(value: E) -> Self {
+        Self(Box::new(value))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::prelude::{BringToFront, RaiseOnClick, WebviewFocused};
+use bevy::prelude::{Commands, Event, EventWriter, Query, Trigger};
+use serde::Deserialize;
+
+/// Emitted from the page when it is clicked.
+#[allow(non_snake_case)]
+#[derive(Event, Deserialize)]
+pub(crate) struct OnPagePointerDown {
+    __FLURX__pointer_down: u8,
+}
+
+pub(crate) fn apply_page_focus(
+    trigger: Trigger<OnPagePointerDown>,
+    mut commands: Commands,
+    mut ew: EventWriter<WebviewFocused>,
+    webviews: Query<&RaiseOnClick>,
+) {
+    let webview_entity = trigger.target();
+    ew.write(WebviewFocused { webview_entity });
+    if webviews.get(webview_entity).is_ok_and(|raise| raise.0) {
+        commands.entity(webview_entity).trigger(BringToFront);
+    }
+}
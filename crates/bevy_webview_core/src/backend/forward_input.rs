@@ -0,0 +1,352 @@
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use crate::prelude::{EmbedWithin, ForwardInput};
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyCode, KeyboardInput, NativeKey, NativeKeyCode};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::log::error;
+use bevy::prelude::{Added, Changed, Entity, Event, EventWriter, NonSendMut, Or, Query, Trigger};
+use serde::Deserialize;
+
+/// The initialization script that reports the key and wheel events to forward them to bevy.
+///
+/// The backend must inject it to use [`ForwardInput`].
+pub const FORWARD_INPUT_SCRIPT: &str = include_str!("../../scripts/forwardInput.js");
+
+/// Emitted from the page when the key has been pressed or released.
+#[derive(Event, Deserialize)]
+pub(crate) struct OnPageKey {
+    /// `KeyboardEvent.code`
+    code: String,
+    /// `KeyboardEvent.key`
+    key: String,
+    pressed: bool,
+    repeat: bool,
+    /// Whether `preventDefault` has been called on the event.
+    handled: bool,
+    /// Whether the event target is editable, such as `<input>`, `<textarea>` or `contenteditable`.
+    #[serde(default)]
+    editable: bool,
+}
+
+/// Emitted from the page when the wheel has been scrolled and the page didn't handle it.
+#[derive(Event, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OnPageWheel {
+    delta_x: f32,
+    delta_y: f32,
+    /// `WheelEvent.deltaMode`; `0` is pixels and the others are lines.
+    delta_mode: u32,
+}
+
+pub(crate) fn apply_forward_input<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    views: Query<(Entity, &ForwardInput), Or<(Changed<ForwardInput>, Added<WebviewInitialized>)>>,
+) {
+    for (entity, forward) in views.iter() {
+        let keyboard = forward.keyboard || !forward.always_forward.is_empty();
+        if let Err(e) = backend.evaluate_script(
+            entity,
+            &format!(
+                "window.__FLURX__.forwardInput={{keyboard:{keyboard},wheel:{}}};",
+                forward.wheel
+            ),
+        ) {
+            error!("Failed to apply forward input to webview {entity}: {e}");
+        }
+    }
+}
+
+pub(crate) fn forward_page_key(
+    trigger: Trigger<OnPageKey>,
+    mut ew: EventWriter<KeyboardInput>,
+    webviews: Query<(&ForwardInput, Option<&EmbedWithin>)>,
+) {
+    let webview_entity = trigger.target();
+    let Ok((forward, embed_within)) = webviews.get(webview_entity) else {
+        return;
+    };
+    let key_code = key_code(&trigger.code);
+    // The keys typed into the fields are used by the page even if it doesn't call `preventDefault`.
+    if !forward.should_forward_key(key_code, trigger.handled || trigger.editable) {
+        return;
+    }
+    let logical_key = logical_key(&trigger.key);
+    let text = match &logical_key {
+        Key::Character(c) if trigger.pressed => Some(c.clone()),
+        Key::Space if trigger.pressed => Some(" ".into()),
+        _ => None,
+    };
+    ew.write(KeyboardInput {
+        key_code,
+        logical_key,
+        state: if trigger.pressed {
+            ButtonState::Pressed
+        } else {
+            ButtonState::Released
+        },
+        text,
+        repeat: trigger.repeat,
+        window: embed_within
+            .map(|parent| parent.0)
+            .unwrap_or(webview_entity),
+    });
+}
+
+pub(crate) fn forward_page_wheel(
+    trigger: Trigger<OnPageWheel>,
+    mut ew: EventWriter<MouseWheel>,
+    webviews: Query<(&ForwardInput, Option<&EmbedWithin>)>,
+) {
+    let webview_entity = trigger.target();
+    let Ok((forward, embed_within)) = webviews.get(webview_entity) else {
+        return;
+    };
+    if !forward.wheel {
+        return;
+    }
+    // The positive delta of the page scrolls down, whereas the positive delta of bevy scrolls up.
+    ew.write(MouseWheel {
+        unit: if trigger.delta_mode == 0 {
+            MouseScrollUnit::Pixel
+        } else {
+            MouseScrollUnit::Line
+        },
+        x: -trigger.delta_x,
+        y: -trigger.delta_y,
+        window: embed_within
+            .map(|parent| parent.0)
+            .unwrap_or(webview_entity),
+    });
+}
+
+/// Converts `KeyboardEvent.code` into [`KeyCode`].
+fn key_code(code: &str) -> KeyCode {
+    match code {
+        "Backquote" => KeyCode::Backquote,
+        "Backslash" => KeyCode::Backslash,
+        "BracketLeft" => KeyCode::BracketLeft,
+        "BracketRight" => KeyCode::BracketRight,
+        "Comma" => KeyCode::Comma,
+        "Digit0" => KeyCode::Digit0,
+        "Digit1" => KeyCode::Digit1,
+        "Digit2" => KeyCode::Digit2,
+        "Digit3" => KeyCode::Digit3,
+        "Digit4" => KeyCode::Digit4,
+        "Digit5" => KeyCode::Digit5,
+        "Digit6" => KeyCode::Digit6,
+        "Digit7" => KeyCode::Digit7,
+        "Digit8" => KeyCode::Digit8,
+        "Digit9" => KeyCode::Digit9,
+        "Equal" => KeyCode::Equal,
+        "IntlBackslash" => KeyCode::IntlBackslash,
+        "IntlRo" => KeyCode::IntlRo,
+        "IntlYen" => KeyCode::IntlYen,
+        "KeyA" => KeyCode::KeyA,
+        "KeyB" => KeyCode::KeyB,
+        "KeyC" => KeyCode::KeyC,
+        "KeyD" => KeyCode::KeyD,
+        "KeyE" => KeyCode::KeyE,
+        "KeyF" => KeyCode::KeyF,
+        "KeyG" => KeyCode::KeyG,
+        "KeyH" => KeyCode::KeyH,
+        "KeyI" => KeyCode::KeyI,
+        "KeyJ" => KeyCode::KeyJ,
+        "KeyK" => KeyCode::KeyK,
+        "KeyL" => KeyCode::KeyL,
+        "KeyM" => KeyCode::KeyM,
+        "KeyN" => KeyCode::KeyN,
+        "KeyO" => KeyCode::KeyO,
+        "KeyP" => KeyCode::KeyP,
+        "KeyQ" => KeyCode::KeyQ,
+        "KeyR" => KeyCode::KeyR,
+        "KeyS" => KeyCode::KeyS,
+        "KeyT" => KeyCode::KeyT,
+        "KeyU" => KeyCode::KeyU,
+        "KeyV" => KeyCode::KeyV,
+        "KeyW" => KeyCode::KeyW,
+        "KeyX" => KeyCode::KeyX,
+        "KeyY" => KeyCode::KeyY,
+        "KeyZ" => KeyCode::KeyZ,
+        "Minus" => KeyCode::Minus,
+        "Period" => KeyCode::Period,
+        "Quote" => KeyCode::Quote,
+        "Semicolon" => KeyCode::Semicolon,
+        "Slash" => KeyCode::Slash,
+        "AltLeft" => KeyCode::AltLeft,
+        "AltRight" => KeyCode::AltRight,
+        "Backspace" => KeyCode::Backspace,
+        "CapsLock" => KeyCode::CapsLock,
+        "ContextMenu" => KeyCode::ContextMenu,
+        "ControlLeft" => KeyCode::ControlLeft,
+        "ControlRight" => KeyCode::ControlRight,
+        "Enter" => KeyCode::Enter,
+        "ShiftLeft" => KeyCode::ShiftLeft,
+        "ShiftRight" => KeyCode::ShiftRight,
+        "Space" => KeyCode::Space,
+        "Tab" => KeyCode::Tab,
+        "Convert" => KeyCode::Convert,
+        "KanaMode" => KeyCode::KanaMode,
+        "Lang1" => KeyCode::Lang1,
+        "Lang2" => KeyCode::Lang2,
+        "Lang3" => KeyCode::Lang3,
+        "Lang4" => KeyCode::Lang4,
+        "Lang5" => KeyCode::Lang5,
+        "NonConvert" => KeyCode::NonConvert,
+        "Delete" => KeyCode::Delete,
+        "End" => KeyCode::End,
+        "Help" => KeyCode::Help,
+        "Home" => KeyCode::Home,
+        "Insert" => KeyCode::Insert,
+        "PageDown" => KeyCode::PageDown,
+        "PageUp" => KeyCode::PageUp,
+        "ArrowDown" => KeyCode::ArrowDown,
+        "ArrowLeft" => KeyCode::ArrowLeft,
+        "ArrowRight" => KeyCode::ArrowRight,
+        "ArrowUp" => KeyCode::ArrowUp,
+        "NumLock" => KeyCode::NumLock,
+        "Numpad0" => KeyCode::Numpad0,
+        "Numpad1" => KeyCode::Numpad1,
+        "Numpad2" => KeyCode::Numpad2,
+        "Numpad3" => KeyCode::Numpad3,
+        "Numpad4" => KeyCode::Numpad4,
+        "Numpad5" => KeyCode::Numpad5,
+        "Numpad6" => KeyCode::Numpad6,
+        "Numpad7" => KeyCode::Numpad7,
+        "Numpad8" => KeyCode::Numpad8,
+        "Numpad9" => KeyCode::Numpad9,
+        "NumpadAdd" => KeyCode::NumpadAdd,
+        "NumpadBackspace" => KeyCode::NumpadBackspace,
+        "NumpadClear" => KeyCode::NumpadClear,
+        "NumpadClearEntry" => KeyCode::NumpadClearEntry,
+        "NumpadComma" => KeyCode::NumpadComma,
+        "NumpadDecimal" => KeyCode::NumpadDecimal,
+        "NumpadDivide" => KeyCode::NumpadDivide,
+        "NumpadEnter" => KeyCode::NumpadEnter,
+        "NumpadEqual" => KeyCode::NumpadEqual,
+        "NumpadHash" => KeyCode::NumpadHash,
+        "NumpadMultiply" => KeyCode::NumpadMultiply,
+        "NumpadParenLeft" => KeyCode::NumpadParenLeft,
+        "NumpadParenRight" => KeyCode::NumpadParenRight,
+        "NumpadStar" => KeyCode::NumpadStar,
+        "NumpadSubtract" => KeyCode::NumpadSubtract,
+        "Escape" => KeyCode::Escape,
+        "Fn" => KeyCode::Fn,
+        "FnLock" => KeyCode::FnLock,
+        "PrintScreen" => KeyCode::PrintScreen,
+        "ScrollLock" => KeyCode::ScrollLock,
+        "Pause" => KeyCode::Pause,
+        "BrowserBack" => KeyCode::BrowserBack,
+        "BrowserFavorites" => KeyCode::BrowserFavorites,
+        "BrowserForward" => KeyCode::BrowserForward,
+        "BrowserHome" => KeyCode::BrowserHome,
+        "BrowserRefresh" => KeyCode::BrowserRefresh,
+        "BrowserSearch" => KeyCode::BrowserSearch,
+        "BrowserStop" => KeyCode::BrowserStop,
+        "Eject" => KeyCode::Eject,
+        "LaunchApp1" => KeyCode::LaunchApp1,
+        "LaunchApp2" => KeyCode::LaunchApp2,
+        "LaunchMail" => KeyCode::LaunchMail,
+        "MediaPlayPause" => KeyCode::MediaPlayPause,
+        "MediaSelect" => KeyCode::MediaSelect,
+        "MediaStop" => KeyCode::MediaStop,
+        "MediaTrackNext" => KeyCode::MediaTrackNext,
+        "MediaTrackPrevious" => KeyCode::MediaTrackPrevious,
+        "Power" => KeyCode::Power,
+        "Sleep" => KeyCode::Sleep,
+        "AudioVolumeDown" => KeyCode::AudioVolumeDown,
+        "AudioVolumeMute" => KeyCode::AudioVolumeMute,
+        "AudioVolumeUp" => KeyCode::AudioVolumeUp,
+        "WakeUp" => KeyCode::WakeUp,
+        "F1" => KeyCode::F1,
+        "F2" => KeyCode::F2,
+        "F3" => KeyCode::F3,
+        "F4" => KeyCode::F4,
+        "F5" => KeyCode::F5,
+        "F6" => KeyCode::F6,
+        "F7" => KeyCode::F7,
+        "F8" => KeyCode::F8,
+        "F9" => KeyCode::F9,
+        "F10" => KeyCode::F10,
+        "F11" => KeyCode::F11,
+        "F12" => KeyCode::F12,
+        "F13" => KeyCode::F13,
+        "F14" => KeyCode::F14,
+        "F15" => KeyCode::F15,
+        "F16" => KeyCode::F16,
+        "F17" => KeyCode::F17,
+        "F18" => KeyCode::F18,
+        "F19" => KeyCode::F19,
+        "F20" => KeyCode::F20,
+        "F21" => KeyCode::F21,
+        "F22" => KeyCode::F22,
+        "F23" => KeyCode::F23,
+        "F24" => KeyCode::F24,
+        "MetaLeft" | "OSLeft" => KeyCode::SuperLeft,
+        "MetaRight" | "OSRight" => KeyCode::SuperRight,
+        _ => KeyCode::Unidentified(NativeKeyCode::Unidentified),
+    }
+}
+
+/// Converts `KeyboardEvent.key` into [`Key`].
+fn logical_key(key: &str) -> Key {
+    match key {
+        " " => Key::Space,
+        "Alt" => Key::Alt,
+        "Control" => Key::Control,
+        "Shift" => Key::Shift,
+        "Meta" | "OS" => Key::Super,
+        "CapsLock" => Key::CapsLock,
+        "Enter" => Key::Enter,
+        "Tab" => Key::Tab,
+        "Escape" => Key::Escape,
+        "Backspace" => Key::Backspace,
+        "Delete" => Key::Delete,
+        "Insert" => Key::Insert,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
+        "ArrowUp" => Key::ArrowUp,
+        "ArrowDown" => Key::ArrowDown,
+        "ArrowLeft" => Key::ArrowLeft,
+        "ArrowRight" => Key::ArrowRight,
+        "PrintScreen" => Key::PrintScreen,
+        "Pause" => Key::Pause,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
+        key if key.chars().count() == 1 => Key::Character(key.into()),
+        key => Key::Unidentified(NativeKey::Web(key.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::forward_input::{key_code, logical_key};
+    use bevy::input::keyboard::Key;
+    use bevy::prelude::*;
+
+    #[test]
+    fn convert_dom_code() {
+        assert_eq!(key_code("KeyP"), KeyCode::KeyP);
+        assert_eq!(key_code("MetaLeft"), KeyCode::SuperLeft);
+        assert_eq!(key_code("F12"), KeyCode::F12);
+    }
+
+    #[test]
+    fn convert_dom_key() {
+        assert_eq!(logical_key("p"), Key::Character("p".into()));
+        assert_eq!(logical_key(" "), Key::Space);
+        assert_eq!(logical_key("Escape"), Key::Escape);
+    }
+}
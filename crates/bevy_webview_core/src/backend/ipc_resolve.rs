@@ -0,0 +1,20 @@
+use crate::backend::WebviewBackend;
+use bevy::log::error;
+use bevy::prelude::{EventReader, NonSendMut};
+use bevy_flurx_ipc::prelude::IpcResolveEvent;
+
+pub(crate) fn resolve_ipc_event<B: WebviewBackend>(
+    mut er: EventReader<IpcResolveEvent>,
+    mut backend: NonSendMut<B>,
+) {
+    for IpcResolveEvent {
+        entity,
+        resolve_id,
+        output,
+    } in er.read()
+    {
+        if let Err(e) = backend.resolve_ipc(*entity, *resolve_id, output) {
+            error!("{e}");
+        }
+    }
+}
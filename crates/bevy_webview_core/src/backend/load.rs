@@ -0,0 +1,30 @@
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use crate::prelude::{ReloadWebview, Webview};
+use bevy::log::error;
+use bevy::prelude::{Changed, Entity, NonSendMut, Query, Ref, Trigger};
+
+pub(crate) fn load_changed_webview<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    webviews: Query<(Entity, &Webview, Ref<WebviewInitialized>), Changed<Webview>>,
+) {
+    for (webview_entity, webview, initialized) in webviews.iter() {
+        // The backend has already loaded the page when it initialized the webview.
+        if initialized.is_added() {
+            continue;
+        }
+        if let Err(e) = backend.load(webview_entity, webview) {
+            error!("Failed to load webview {webview_entity}: {e}");
+        }
+    }
+}
+
+pub(crate) fn apply_reload<B: WebviewBackend>(
+    trigger: Trigger<ReloadWebview>,
+    mut backend: NonSendMut<B>,
+) {
+    let webview_entity = trigger.target();
+    if let Err(e) = backend.reload(webview_entity) {
+        error!("Failed to reload webview {webview_entity}: {e}");
+    }
+}
@@ -0,0 +1,315 @@
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use crate::prelude::{
+    CaptureOffscreenFrame, Offscreen, OffscreenCapture, OffscreenInput, OffscreenKeyboardFocus,
+    OffscreenSurface, WebviewImage,
+};
+use bevy::asset::{Assets, RenderAssetUsages};
+use bevy::image::Image;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseScrollUnit;
+use bevy::log::error;
+use bevy::math::{UVec2, Vec2, Vec3};
+use bevy::picking::events::{Move, Pointer, Pressed, Released, Scroll};
+use bevy::picking::pointer::PointerButton;
+use bevy::prelude::{
+    Added, Commands, Entity, EventReader, GlobalTransform, MouseButton, NonSend, NonSendMut, Query,
+    ResMut, Trigger, With, Without,
+};
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// The pixels of the page rendered by the backend.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OffscreenFrame {
+    /// The size of the frame in physical pixels.
+    pub size: UVec2,
+
+    /// The pixels in `Rgba8UnormSrgb` format.
+    pub data: Vec<u8>,
+}
+
+/// The number of pixels scrolled per line when the wheel is scrolled in [`MouseScrollUnit::Line`].
+const LINE_HEIGHT: f32 = 16.;
+
+pub(crate) fn error_if_offscreen_unsupported<B: WebviewBackend>(
+    backend: NonSend<B>,
+    webviews: Query<Entity, Added<Offscreen>>,
+) {
+    if backend.supports_offscreen() {
+        return;
+    }
+    for webview_entity in webviews.iter() {
+        error!(
+            "Webview {webview_entity} has `Offscreen`, but the backend doesn't support offscreen rendering; WebviewImage will stay blank"
+        );
+    }
+}
+
+pub(crate) fn create_offscreen_images(
+    mut commands: Commands,
+    images: Option<ResMut<Assets<Image>>>,
+    webviews: Query<(Entity, &Offscreen), Without<WebviewImage>>,
+) {
+    let Some(mut images) = images else {
+        return;
+    };
+    for (webview_entity, offscreen) in webviews.iter() {
+        let image = Image::new_fill(
+            extent(offscreen.size),
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        commands
+            .entity(webview_entity)
+            .insert(WebviewImage(images.add(image)));
+    }
+}
+
+pub(crate) fn capture_offscreen_frames<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    images: Option<ResMut<Assets<Image>>>,
+    webviews: Query<(Entity, &Offscreen, &WebviewImage), With<WebviewInitialized>>,
+) {
+    let Some(mut images) = images else {
+        return;
+    };
+    if !backend.supports_offscreen() {
+        return;
+    }
+    for (webview_entity, offscreen, image) in webviews.iter() {
+        if offscreen.capture == OffscreenCapture::EveryFrame {
+            copy_frame(&mut *backend, webview_entity, image, &mut images);
+        }
+    }
+}
+
+pub(crate) fn apply_capture_offscreen_frame<B: WebviewBackend>(
+    trigger: Trigger<CaptureOffscreenFrame>,
+    mut backend: NonSendMut<B>,
+    images: Option<ResMut<Assets<Image>>>,
+    webviews: Query<&WebviewImage, With<WebviewInitialized>>,
+) {
+    if !backend.supports_offscreen() {
+        return;
+    }
+    let webview_entity = trigger.target();
+    let (Some(mut images), Ok(image)) = (images, webviews.get(webview_entity)) else {
+        return;
+    };
+    copy_frame(&mut *backend, webview_entity, image, &mut images);
+}
+
+pub(crate) fn forward_offscreen_input<B: WebviewBackend>(
+    trigger: Trigger<OffscreenInput>,
+    mut backend: NonSendMut<B>,
+) {
+    let webview_entity = trigger.target();
+    if let Err(e) = backend.send_offscreen_input(webview_entity, trigger.event()) {
+        error!("Failed to forward the input into webview {webview_entity}: {e}");
+    }
+}
+
+pub(crate) fn forward_keyboard_input(
+    mut commands: Commands,
+    mut er: EventReader<KeyboardInput>,
+    focused: Query<Entity, (With<Offscreen>, With<OffscreenKeyboardFocus>)>,
+) {
+    for input in er.read() {
+        for webview_entity in focused.iter() {
+            commands.trigger_targets(
+                OffscreenInput::Keyboard {
+                    key: input.logical_key.clone(),
+                    state: input.state,
+                },
+                webview_entity,
+            );
+        }
+    }
+}
+
+pub(crate) fn forward_pointer_move(
+    trigger: Trigger<Pointer<Move>>,
+    mut commands: Commands,
+    surfaces: Query<(&OffscreenSurface, &GlobalTransform)>,
+    webviews: Query<&Offscreen>,
+) {
+    let Some((webview_entity, position)) =
+        page_position(trigger.target(), trigger.hit.position, &surfaces, &webviews)
+    else {
+        return;
+    };
+    commands.trigger_targets(OffscreenInput::CursorMoved { position }, webview_entity);
+}
+
+pub(crate) fn forward_pointer_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    mut commands: Commands,
+    surfaces: Query<(&OffscreenSurface, &GlobalTransform)>,
+    webviews: Query<&Offscreen>,
+    focused: Query<Entity, With<OffscreenKeyboardFocus>>,
+) {
+    let Some((webview_entity, position)) =
+        page_position(trigger.target(), trigger.hit.position, &surfaces, &webviews)
+    else {
+        return;
+    };
+    for entity in focused.iter() {
+        commands.entity(entity).remove::<OffscreenKeyboardFocus>();
+    }
+    commands
+        .entity(webview_entity)
+        .insert(OffscreenKeyboardFocus);
+    commands.trigger_targets(
+        OffscreenInput::MouseButton {
+            button: as_mouse_button(trigger.button),
+            state: ButtonState::Pressed,
+            position,
+        },
+        webview_entity,
+    );
+}
+
+pub(crate) fn forward_pointer_released(
+    trigger: Trigger<Pointer<Released>>,
+    mut commands: Commands,
+    surfaces: Query<(&OffscreenSurface, &GlobalTransform)>,
+    webviews: Query<&Offscreen>,
+) {
+    let Some((webview_entity, position)) =
+        page_position(trigger.target(), trigger.hit.position, &surfaces, &webviews)
+    else {
+        return;
+    };
+    commands.trigger_targets(
+        OffscreenInput::MouseButton {
+            button: as_mouse_button(trigger.button),
+            state: ButtonState::Released,
+            position,
+        },
+        webview_entity,
+    );
+}
+
+pub(crate) fn forward_pointer_scroll(
+    trigger: Trigger<Pointer<Scroll>>,
+    mut commands: Commands,
+    surfaces: Query<(&OffscreenSurface, &GlobalTransform)>,
+    webviews: Query<&Offscreen>,
+) {
+    let Some((webview_entity, position)) =
+        page_position(trigger.target(), trigger.hit.position, &surfaces, &webviews)
+    else {
+        return;
+    };
+    let delta = match trigger.unit {
+        MouseScrollUnit::Line => Vec2::new(trigger.x, trigger.y) * LINE_HEIGHT,
+        MouseScrollUnit::Pixel => Vec2::new(trigger.x, trigger.y),
+    };
+    commands.trigger_targets(OffscreenInput::Wheel { delta, position }, webview_entity);
+}
+
+fn copy_frame<B: WebviewBackend>(
+    backend: &mut B,
+    webview_entity: Entity,
+    image: &WebviewImage,
+    images: &mut Assets<Image>,
+) {
+    let frame = match backend.capture_frame(webview_entity) {
+        Ok(Some(frame)) => frame,
+        Ok(None) => return,
+        Err(e) => {
+            error!("Failed to capture webview {webview_entity}: {e}");
+            return;
+        }
+    };
+    let expected_len = frame.size.x as usize * frame.size.y as usize * 4;
+    if frame.data.len() != expected_len {
+        error!(
+            "Failed to capture webview {webview_entity}: the frame of {}x{} has {} bytes, but {expected_len} bytes are expected",
+            frame.size.x,
+            frame.size.y,
+            frame.data.len()
+        );
+        return;
+    }
+    let Some(image) = images.get_mut(&image.0) else {
+        return;
+    };
+    if image.size() != frame.size {
+        image.resize(extent(frame.size));
+    }
+    image.data = Some(frame.data);
+}
+
+/// Converts the hit position on [`OffscreenSurface`] into the page position.
+fn page_position(
+    surface_entity: Entity,
+    hit_position: Option<Vec3>,
+    surfaces: &Query<(&OffscreenSurface, &GlobalTransform)>,
+    webviews: &Query<&Offscreen>,
+) -> Option<(Entity, Vec2)> {
+    let (surface, transform) = surfaces.get(surface_entity).ok()?;
+    let offscreen = webviews.get(surface.webview).ok()?;
+    let local = transform.affine().inverse().transform_point3(hit_position?);
+    let uv = Vec2::new(
+        local.x / surface.size.x + 0.5,
+        0.5 - local.y / surface.size.y,
+    );
+    Some((
+        surface.webview,
+        uv.clamp(Vec2::ZERO, Vec2::ONE) * offscreen.size.as_vec2(),
+    ))
+}
+
+#[inline]
+const fn as_mouse_button(button: PointerButton) -> MouseButton {
+    match button {
+        PointerButton::Primary => MouseButton::Left,
+        PointerButton::Secondary => MouseButton::Right,
+        PointerButton::Middle => MouseButton::Middle,
+    }
+}
+
+#[inline]
+const fn extent(size: UVec2) -> Extent3d {
+    Extent3d {
+        width: size.x,
+        height: size.y,
+        depth_or_array_layers: 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::offscreen::page_position;
+    use crate::prelude::{Offscreen, OffscreenSurface};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+
+    #[test]
+    fn convert_hit_position_into_page_position() {
+        let mut world = World::new();
+        let webview = world.spawn(Offscreen::new(UVec2::new(800, 600))).id();
+        let surface = world
+            .spawn((
+                OffscreenSurface {
+                    webview,
+                    size: Vec2::new(4., 3.),
+                },
+                GlobalTransform::from_translation(Vec3::new(10., 0., 0.)),
+            ))
+            .id();
+        let position = world
+            .run_system_once(
+                move |surfaces: Query<(&OffscreenSurface, &GlobalTransform)>,
+                      webviews: Query<&Offscreen>| {
+                    page_position(surface, Some(Vec3::new(9., 0.75, 0.)), &surfaces, &webviews)
+                },
+            )
+            .unwrap();
+        assert_eq!(position, Some((webview, Vec2::new(200., 150.))));
+    }
+}
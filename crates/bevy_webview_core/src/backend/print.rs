@@ -0,0 +1,222 @@
+use crate::backend::WebviewBackend;
+use bevy::log::error;
+use bevy::math::Vec2;
+use bevy::prelude::{Entity, Event, EventWriter, NonSendMut, Reflect, Res, Resource, Trigger};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Prints the page with the print dialog.
+///
+/// This event is sent via [`Trigger`] targeting the webview entity,
+/// and the result is sent as [`PrintCompleted`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn print(mut commands: Commands, webviews: Query<Entity, With<Webview>>) {
+///     for webview_entity in webviews.iter() {
+///         commands.entity(webview_entity).trigger(PrintWebview::default());
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Default, Copy, Clone, PartialEq)]
+pub struct PrintWebview {
+    /// The page margins.
+    pub margins: PrintMargins,
+}
+
+/// Exports the page as a PDF file without showing the print dialog.
+///
+/// This event is sent via [`Trigger`] targeting the webview entity,
+/// and the result is sent as [`PrintCompleted`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn export(mut commands: Commands, webviews: Query<Entity, With<Webview>>) {
+///     for webview_entity in webviews.iter() {
+///         commands.entity(webview_entity).trigger(ExportPdf {
+///             path: "report.pdf".into(),
+///             page_size: PageSize::A4,
+///             margins: PrintMargins::all(10.),
+///         });
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ExportPdf {
+    /// The path to write the PDF to.
+    pub path: PathBuf,
+
+    /// The paper size.
+    pub page_size: PageSize,
+
+    /// The page margins.
+    pub margins: PrintMargins,
+}
+
+/// The paper size of the printed page.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Reflect)]
+pub enum PageSize {
+    /// 210mm x 297mm
+    #[default]
+    A4,
+
+    /// 297mm x 420mm
+    A3,
+
+    /// 148mm x 210mm
+    A5,
+
+    /// 215.9mm x 279.4mm
+    Letter,
+
+    /// 215.9mm x 355.6mm
+    Legal,
+
+    /// The custom size in millimeters.
+    Custom {
+        /// The paper width in millimeters.
+        width: f32,
+        /// The paper height in millimeters.
+        height: f32,
+    },
+}
+
+impl PageSize {
+    /// Returns the paper size in millimeters.
+    pub const fn size_mm(&self) -> Vec2 {
+        match self {
+            Self::A4 => Vec2::new(210., 297.),
+            Self::A3 => Vec2::new(297., 420.),
+            Self::A5 => Vec2::new(148., 210.),
+            Self::Letter => Vec2::new(215.9, 279.4),
+            Self::Legal => Vec2::new(215.9, 355.6),
+            Self::Custom { width, height } => Vec2::new(*width, *height),
+        }
+    }
+}
+
+/// The page margins in millimeters.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Reflect)]
+pub struct PrintMargins {
+    /// The top margin.
+    pub top: f32,
+
+    /// The right margin.
+    pub right: f32,
+
+    /// The bottom margin.
+    pub bottom: f32,
+
+    /// The left margin.
+    pub left: f32,
+}
+
+impl PrintMargins {
+    /// Creates the margins that are the same on all sides.
+    pub const fn all(margin: f32) -> Self {
+        Self {
+            top: margin,
+            right: margin,
+            bottom: margin,
+            left: margin,
+        }
+    }
+}
+
+/// Fired when printing or PDF export completes, whether it was successful or not.
+#[derive(Clone, Debug, Event, Reflect)]
+pub struct PrintCompleted {
+    /// The entity associated with the webview from which this event was fired.
+    pub webview_entity: Entity,
+
+    /// Where the page was printed.
+    pub output: PrintOutput,
+
+    /// Whether printing succeed or not.
+    ///
+    /// For [`PrintOutput::Printer`], some backends report `true` once the print dialog is shown
+    /// because the engine doesn't tell when printing finishes.
+    pub succeed: bool,
+}
+
+/// Represents where the page was printed.
+#[derive(Clone, Debug, Eq, PartialEq, Reflect)]
+pub enum PrintOutput {
+    /// Printed with the print dialog.
+    Printer,
+
+    /// Exported as the PDF file.
+    Pdf(PathBuf),
+}
+
+/// Reports the completion of printing from [`WebviewBackend`].
+///
+/// Call [`PrintCompletion::complete`] when printing has finished, even if it is asynchronous.
+#[derive(Clone)]
+pub struct PrintCompletion {
+    queue: PrintCompletedQueue,
+    webview_entity: Entity,
+    output: PrintOutput,
+}
+
+impl PrintCompletion {
+    /// Sends [`PrintCompleted`] in the next frame.
+    pub fn complete(self, succeed: bool) {
+        self.queue.0.lock().unwrap().push(PrintCompleted {
+            webview_entity: self.webview_entity,
+            output: self.output,
+            succeed,
+        });
+    }
+}
+
+#[derive(Resource, Clone, Default)]
+pub(crate) struct PrintCompletedQueue(Arc<Mutex<Vec<PrintCompleted>>>);
+
+pub(crate) fn apply_print<B: WebviewBackend>(
+    trigger: Trigger<PrintWebview>,
+    mut backend: NonSendMut<B>,
+    queue: Res<PrintCompletedQueue>,
+) {
+    let webview_entity = trigger.target();
+    let completion = PrintCompletion {
+        queue: queue.clone(),
+        webview_entity,
+        output: PrintOutput::Printer,
+    };
+    if let Err(e) = backend.print(webview_entity, trigger.event(), completion.clone()) {
+        error!("Failed to print webview {webview_entity}: {e}");
+        completion.complete(false);
+    }
+}
+
+pub(crate) fn apply_export_pdf<B: WebviewBackend>(
+    trigger: Trigger<ExportPdf>,
+    mut backend: NonSendMut<B>,
+    queue: Res<PrintCompletedQueue>,
+) {
+    let webview_entity = trigger.target();
+    let completion = PrintCompletion {
+        queue: queue.clone(),
+        webview_entity,
+        output: PrintOutput::Pdf(trigger.path.clone()),
+    };
+    if let Err(e) = backend.export_pdf(webview_entity, trigger.event(), completion.clone()) {
+        error!("Failed to export webview {webview_entity} as PDF: {e}");
+        completion.complete(false);
+    }
+}
+
+pub(crate) fn send_print_completed(
+    queue: Res<PrintCompletedQueue>,
+    mut ew: EventWriter<PrintCompleted>,
+) {
+    let Ok(mut completed) = queue.0.try_lock() else {
+        return;
+    };
+    ew.write_batch(completed.drain(..));
+}
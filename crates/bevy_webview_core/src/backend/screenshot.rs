@@ -0,0 +1,290 @@
+use crate::backend::WebviewBackend;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bevy::log::error;
+use bevy::prelude::{
+    Commands, Entity, Event, EventReader, EventWriter, In, NonSendMut, Res, Resource, Trigger,
+};
+use bevy_flurx::prelude::{Action, Then, once, wait};
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The initialization script that renders the page into a canvas when the engine can't snapshot natively.
+///
+/// The backend must inject it to use the default implementation of [`WebviewBackend::take_screenshot`].
+/// It's a last resort: the stylesheets are copied into an SVG `foreignObject`,
+/// so cross-origin resources are not rendered, and some engines such as WebKit reject exporting the canvas.
+pub const SCREENSHOT_SCRIPT: &str = include_str!("../../scripts/screenshot.js");
+
+/// Takes the screenshot of the page.
+///
+/// This event is sent via [`Trigger`] targeting the webview entity,
+/// and the result is sent as [`ScreenshotCaptured`] with the same [`TakeScreenshot::request_id`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn take(mut commands: Commands, webviews: Query<Entity, With<Webview>>) {
+///     for webview_entity in webviews.iter() {
+///         commands.entity(webview_entity).trigger(TakeScreenshot::new());
+///     }
+/// }
+///
+/// fn save(mut er: EventReader<ScreenshotCaptured>) {
+///     for event in er.read() {
+///         if let Ok(screenshot) = &event.result {
+///             screenshot.save("screenshot.png").unwrap();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TakeScreenshot {
+    /// The id that identifies the [`ScreenshotCaptured`] of this request.
+    pub request_id: u64,
+}
+
+impl TakeScreenshot {
+    /// Creates a new [`TakeScreenshot`] with a unique [`TakeScreenshot::request_id`].
+    pub fn new() -> Self {
+        static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(0);
+        Self {
+            request_id: NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for TakeScreenshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The event is fired when the screenshot requested with [`TakeScreenshot`] has been captured.
+#[derive(Event, Debug, Clone, Eq, PartialEq)]
+pub struct ScreenshotCaptured {
+    /// The webview entity.
+    pub webview_entity: Entity,
+
+    /// The [`TakeScreenshot::request_id`] of the request.
+    pub request_id: u64,
+
+    /// The screenshot, or the error message if failed.
+    pub result: Result<Screenshot, String>,
+}
+
+/// The PNG-encoded screenshot of the page.
+#[repr(transparent)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Screenshot(pub Vec<u8>);
+
+impl Screenshot {
+    /// Returns the PNG bytes.
+    #[inline]
+    pub fn png(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Writes the PNG to the file.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, &self.0)
+    }
+
+    /// Decodes the PNG into [`Image`](bevy::image::Image).
+    #[cfg(feature = "image")]
+    pub fn to_image(&self) -> Result<bevy::image::Image, bevy::image::TextureError> {
+        use bevy::asset::RenderAssetUsages;
+        use bevy::image::{CompressedImageFormats, Image, ImageSampler, ImageType};
+
+        Image::from_buffer(
+            &self.0,
+            ImageType::Extension("png"),
+            CompressedImageFormats::NONE,
+            true,
+            ImageSampler::Default,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+/// Sends the result of the screenshot captured natively by the backend as [`ScreenshotCaptured`].
+///
+/// It can be called from any thread, such as the callback of the native snapshot API.
+#[derive(Clone)]
+pub struct ScreenshotCompletion {
+    queue: ScreenshotCapturedQueue,
+    webview_entity: Entity,
+    request_id: u64,
+}
+
+impl ScreenshotCompletion {
+    /// Returns the script that renders the page with [`SCREENSHOT_SCRIPT`] for this request.
+    ///
+    /// The page emits the result with the event id `FLURX|webview::screenshot` instead of this completion.
+    pub fn dom_script(&self) -> String {
+        format!("window.__FLURX__.__takeScreenshot({})", self.request_id)
+    }
+
+    /// Sends the PNG bytes of the screenshot, or the error message if failed.
+    pub fn complete(self, result: Result<Vec<u8>, String>) {
+        if let Err(e) = &result {
+            error!(
+                "Failed to take the screenshot of webview {}: {e}",
+                self.webview_entity
+            );
+        }
+        self.queue.0.lock().unwrap().push(ScreenshotCaptured {
+            webview_entity: self.webview_entity,
+            request_id: self.request_id,
+            result: result.map(Screenshot),
+        });
+    }
+}
+
+#[derive(Resource, Clone, Default)]
+pub(crate) struct ScreenshotCapturedQueue(Arc<Mutex<Vec<ScreenshotCaptured>>>);
+
+/// Takes the screenshot of the webview and waits until it has been captured.
+///
+/// Only the [`ScreenshotCaptured`] of this request is awaited, so the screenshots requested before are ignored.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_reactor(mut commands: Commands, webviews: Query<Entity, With<Webview>>) {
+///     let webview_entity = webviews.single().unwrap();
+///     commands.spawn(Reactor::schedule(move |task| async move {
+///         if let Ok(screenshot) = task.will(Update, take_screenshot(webview_entity)).await {
+///             screenshot.save("screenshot.png").unwrap();
+///         }
+///     }));
+/// }
+/// ```
+pub fn take_screenshot(webview_entity: Entity) -> Action<Entity, Result<Screenshot, String>> {
+    let request = TakeScreenshot::new();
+    once::run(
+        move |In(webview_entity): In<Entity>, mut commands: Commands| {
+            commands.entity(webview_entity).trigger(request);
+        },
+    )
+    .with(webview_entity)
+    .then(wait::output(
+        move |mut er: EventReader<ScreenshotCaptured>| {
+            er.read()
+                .find(|event| {
+                    event.webview_entity == webview_entity && event.request_id == request.request_id
+                })
+                .map(|event| event.result.clone())
+        },
+    ))
+}
+
+/// The result of the screenshot rendered by [`SCREENSHOT_SCRIPT`].
+#[derive(Event, Deserialize)]
+pub(crate) struct DomScreenshot {
+    request_id: u64,
+    data_url: Option<String>,
+    error: Option<String>,
+}
+
+pub(crate) fn apply_take_screenshot<B: WebviewBackend>(
+    trigger: Trigger<TakeScreenshot>,
+    mut backend: NonSendMut<B>,
+    queue: Res<ScreenshotCapturedQueue>,
+) {
+    let webview_entity = trigger.target();
+    let completion = ScreenshotCompletion {
+        queue: queue.clone(),
+        webview_entity,
+        request_id: trigger.request_id,
+    };
+    if let Err(e) = backend.take_screenshot(webview_entity, completion.clone()) {
+        completion.complete(Err(e.to_string()));
+    }
+}
+
+pub(crate) fn send_screenshot_captured(
+    queue: Res<ScreenshotCapturedQueue>,
+    mut ew: EventWriter<ScreenshotCaptured>,
+) {
+    let Ok(mut captured) = queue.0.try_lock() else {
+        return;
+    };
+    ew.write_batch(captured.drain(..));
+}
+
+pub(crate) fn receive_dom_screenshot(
+    trigger: Trigger<DomScreenshot>,
+    mut ew: EventWriter<ScreenshotCaptured>,
+) {
+    let webview_entity = trigger.target();
+    let result = decode_data_url(trigger.event());
+    if let Err(e) = &result {
+        error!("Failed to take the screenshot of webview {webview_entity}: {e}");
+    }
+    ew.write(ScreenshotCaptured {
+        webview_entity,
+        request_id: trigger.request_id,
+        result,
+    });
+}
+
+fn decode_data_url(screenshot: &DomScreenshot) -> Result<Screenshot, String> {
+    if let Some(error) = screenshot.error.as_ref() {
+        return Err(error.clone());
+    }
+    let data_url = screenshot
+        .data_url
+        .as_deref()
+        .ok_or("The page returned no screenshot")?;
+    let base64 = data_url
+        .strip_prefix("data:image/png;base64,")
+        .ok_or("The screenshot is not a PNG data url")?;
+    STANDARD
+        .decode(base64)
+        .map(Screenshot)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::screenshot::{DomScreenshot, Screenshot, decode_data_url};
+
+    #[test]
+    fn decode_png_data_url() {
+        let screenshot = DomScreenshot {
+            request_id: 0,
+            data_url: Some("data:image/png;base64,AQID".to_string()),
+            error: None,
+        };
+        assert_eq!(decode_data_url(&screenshot), Ok(Screenshot(vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn err_if_page_failed() {
+        let screenshot = DomScreenshot {
+            request_id: 0,
+            data_url: None,
+            error: Some("SecurityError".to_string()),
+        };
+        assert_eq!(
+            decode_data_url(&screenshot),
+            Err("SecurityError".to_string())
+        );
+    }
+
+    #[test]
+    fn err_if_not_png() {
+        let screenshot = DomScreenshot {
+            request_id: 0,
+            data_url: Some("data:image/jpeg;base64,AQID".to_string()),
+            error: None,
+        };
+        assert!(decode_data_url(&screenshot).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+use crate::backend::WebviewBackend;
+use crate::prelude::WebviewVisible;
+use bevy::log::error;
+use bevy::prelude::{Changed, Entity, NonSendMut, Query};
+
+pub(crate) fn apply_visible<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    views: Query<(Entity, &WebviewVisible), Changed<WebviewVisible>>,
+) {
+    for (entity, visible) in views.iter() {
+        if let Err(e) = backend.set_visible(entity, visible.0) {
+            error!("{e}");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::backend::WebviewBackend;
+use crate::bundle::WebviewInitialized;
+use crate::prelude::WebviewZoom;
+use bevy::log::error;
+use bevy::prelude::{Added, Changed, Entity, NonSendMut, Or, Query};
+
+pub(crate) fn apply_zoom<B: WebviewBackend>(
+    mut backend: NonSendMut<B>,
+    views: Query<(Entity, &WebviewZoom), Or<(Changed<WebviewZoom>, Added<WebviewInitialized>)>>,
+) {
+    for (entity, zoom) in views.iter() {
+        if let Err(e) = backend.set_zoom(entity, zoom.0) {
+            error!("Failed to zoom webview {entity}: {e}");
+        }
+    }
+}
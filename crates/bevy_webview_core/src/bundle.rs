@@ -1,8 +1,10 @@
 use crate::prelude::{
-    AutoPlay, Background, Bounds, DragDropEvent, DragEntered, DragLeave, DragOver, Dropped,
-    EmbedWithin, EnableClipboard, GripZone, HotkeysZoom, Incognito, InitializeFocused,
-    IsOpenDevtools, PassedUrl, Resizable, Theme, UseDevtools, UseHttpsScheme, WebviewUri,
-    WebviewVisible,
+    AutoPlay, Background, Bounds, DragDropEvent, DragEntered, DragHandles, DragLeave, DragOver,
+    Dropped, EmbedWithin, EnableClipboard, ForwardInput, GripZone, HotkeysZoom, Incognito,
+    InitializeFocused, IsOpenDevtools, MaximizeOnDoubleClick, Maximized, Navigated,
+    PageLoadFinished, PageLoadStarted, PassedUrl, Resizable, ResizeEdges, ResizeSettings, Theme,
+    UseDevtools, UseHttpsScheme, WebviewResizeEnded, WebviewResizeStarted, WebviewResized,
+    WebviewUri, WebviewVisible, WebviewZoom,
 };
 use bevy::prelude::{
     App, Component, Plugin, Reflect, ReflectComponent, ReflectDeserialize, ReflectSerialize,
@@ -10,10 +12,14 @@ use bevy::prelude::{
 use serde::{Deserialize, Serialize};
 
 pub mod embedding;
+#[cfg(feature = "offscreen")]
+pub mod offscreen;
 pub mod webview;
 
 #[allow(missing_docs)]
 pub mod prelude {
+    #[cfg(feature = "offscreen")]
+    pub use crate::bundle::offscreen::*;
     pub use crate::bundle::{WebviewInitialized, embedding::*, webview::*};
 }
 
@@ -27,10 +33,23 @@ pub struct WebViewBundlesPlugin;
 
 impl Plugin for WebViewBundlesPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<WebviewInitialized>()
+        app.add_plugins(embedding::WebviewLayoutPlugin)
+            .add_plugins(embedding::WebviewZIndexPlugin)
+            .add_plugins(embedding::WebviewSnapPlugin)
+            .add_plugins(embedding::MaximizePlugin)
+            .add_plugins(webview::WebviewClickThroughPlugin)
+            .register_type::<WebviewInitialized>()
             .register_type::<Bounds>()
             .register_type::<GripZone>()
+            .register_type::<DragHandles>()
+            .register_type::<MaximizeOnDoubleClick>()
+            .register_type::<Maximized>()
             .register_type::<Resizable>()
+            .register_type::<ResizeSettings>()
+            .register_type::<ResizeEdges>()
+            .register_type::<WebviewResizeStarted>()
+            .register_type::<WebviewResized>()
+            .register_type::<WebviewResizeEnded>()
             .register_type::<EmbedWithin>()
             .register_type::<AutoPlay>()
             .register_type::<Background>()
@@ -41,7 +60,9 @@ impl Plugin for WebViewBundlesPlugin {
             .register_type::<WebviewVisible>()
             .register_type::<Theme>()
             .register_type::<InitializeFocused>()
+            .register_type::<ForwardInput>()
             .register_type::<HotkeysZoom>()
+            .register_type::<WebviewZoom>()
             .register_type::<Incognito>()
             .register_type::<UseHttpsScheme>()
             .register_type::<PassedUrl>()
@@ -50,6 +71,9 @@ impl Plugin for WebViewBundlesPlugin {
             .register_type::<DragOver>()
             .register_type::<DragLeave>()
             .register_type::<Dropped>()
+            .register_type::<PageLoadStarted>()
+            .register_type::<PageLoadFinished>()
+            .register_type::<Navigated>()
             .register_type::<EmbedWithin>()
             .register_type::<Bounds>()
             .register_type::<Resizable>()
@@ -57,6 +81,22 @@ impl Plugin for WebViewBundlesPlugin {
             .add_event::<DragEntered>()
             .add_event::<DragOver>()
             .add_event::<DragLeave>()
-            .add_event::<Dropped>();
+            .add_event::<Dropped>()
+            .add_event::<PageLoadStarted>()
+            .add_event::<PageLoadFinished>()
+            .add_event::<Navigated>()
+            .add_event::<WebviewResizeStarted>()
+            .add_event::<WebviewResized>()
+            .add_event::<WebviewResizeEnded>();
+
+        #[cfg(feature = "ui")]
+        app.add_plugins(embedding::FollowUiNodePlugin);
+
+        #[cfg(feature = "offscreen")]
+        app.register_type::<offscreen::Offscreen>()
+            .register_type::<offscreen::OffscreenCapture>()
+            .register_type::<offscreen::WebviewImage>()
+            .register_type::<offscreen::OffscreenSurface>()
+            .register_type::<offscreen::OffscreenKeyboardFocus>();
     }
 }
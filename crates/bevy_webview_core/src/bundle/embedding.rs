@@ -4,13 +4,41 @@ use bevy::prelude::{
     Component, Entity, Reflect, ReflectComponent, ReflectDeserialize, ReflectSerialize,
 };
 pub use bounds::Bounds;
-pub use grip_zone::GripZone;
-pub use resize::ResizeMode;
+pub(crate) use grip_zone::MaximizePlugin;
+pub use grip_zone::{DragHandles, GripZone, MaximizeOnDoubleClick, Maximized};
+pub(crate) use layout::WebviewLayoutPlugin;
+pub use layout::{LayoutLength, LayoutMargin, WebviewAnchor, WebviewLayout};
+#[cfg(feature = "persist")]
+pub use persistence::{
+    LAYOUT_FILE_VERSION, LayoutFileFormat, PersistedLayout, PersistedLayouts,
+    WebviewLayoutPersistencePlugin,
+};
+pub use resize::{
+    ResizeEdges, ResizeMode, ResizeSettings, WebviewResizeEnded, WebviewResizeStarted,
+    WebviewResized,
+};
 use serde::{Deserialize, Serialize};
+pub(crate) use snap::WebviewSnapPlugin;
+pub use snap::{
+    DockSide, DockZones, Docked, PreventOverlap, WebviewDocked, WebviewSnap, WebviewUndocked,
+};
+#[cfg(feature = "ui")]
+pub use ui_node::FollowUiNode;
+#[cfg(feature = "ui")]
+pub(crate) use ui_node::FollowUiNodePlugin;
+pub(crate) use z_index::WebviewZIndexPlugin;
+pub use z_index::{BringToFront, RaiseOnClick, SendToBack, WebviewFocused, WebviewZIndex};
 
 mod bounds;
 mod grip_zone;
+mod layout;
+#[cfg(feature = "persist")]
+mod persistence;
 mod resize;
+mod snap;
+#[cfg(feature = "ui")]
+mod ui_node;
+mod z_index;
 
 /// Holds the window entity to embed the webview in.
 ///
@@ -36,7 +64,16 @@ mod resize;
 /// }
 #[repr(transparent)]
 #[derive(Component, Copy, Clone, Eq, PartialEq, Reflect, Serialize, Deserialize)]
-#[require(Bounds, Resizable, GripZone)]
+#[require(
+    Bounds,
+    Resizable,
+    ResizeSettings,
+    GripZone,
+    DragHandles,
+    MaximizeOnDoubleClick,
+    WebviewZIndex,
+    RaiseOnClick
+)]
 #[reflect(Component, Serialize, Deserialize)]
 pub struct EmbedWithin(pub Entity);
 
@@ -21,15 +21,33 @@ pub struct Bounds {
 }
 
 impl Bounds {
-    // noinspection DuplicatedCode
+    /// Returns the display area as [`Rect`].
+    #[inline]
+    pub fn rect(&self) -> Rect {
+        Rect::from_corners(self.position, self.position + self.size)
+    }
+
     /// Returns the resize direction of the webview if the cursor is within the resize area.
+    ///
+    /// The width of the resize area is 5px.
     #[inline(always)]
     pub fn maybe_resizable(
         &self,
         cursor_pos: Vec2,
         toolbar_height: Option<f32>,
     ) -> Option<ResizeMode> {
-        const MARGIN_VEC: Vec2 = Vec2::splat(5.);
+        self.maybe_resizable_within(cursor_pos, toolbar_height, 5.)
+    }
+
+    // noinspection DuplicatedCode
+    /// Returns the resize direction of the webview if the cursor is within the resize area of the specified width.
+    pub fn maybe_resizable_within(
+        &self,
+        cursor_pos: Vec2,
+        toolbar_height: Option<f32>,
+        margin: f32,
+    ) -> Option<ResizeMode> {
+        let margin_vec = Vec2::splat(margin);
         let tool = Vec2::new(0., toolbar_height.unwrap_or(0.));
         let o = self.position - tool;
         let s = self.size + tool;
@@ -43,7 +61,7 @@ impl Bounds {
         {
             return None;
         }
-        if !Rect::from_center_size(rect.center(), rect.size() + 2. * MARGIN_VEC)
+        if !Rect::from_center_size(rect.center(), rect.size() + 2. * margin_vec)
             .contains(cursor_pos)
         {
             return None;
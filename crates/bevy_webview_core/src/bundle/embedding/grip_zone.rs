@@ -1,5 +1,11 @@
-use bevy::prelude::{Component, ReflectComponent, ReflectDeserialize, ReflectSerialize};
+use crate::bundle::embedding::{Bounds, EmbedWithin};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::{
+    App, Component, EventReader, Plugin, Query, ReflectComponent, ReflectDeserialize,
+    ReflectSerialize, Update, Vec2, Window, With,
+};
 use bevy::prelude::{Reflect, ReflectDefault};
+use bevy::window::WindowResized;
 use serde::{Deserialize, Serialize};
 
 /// `GripZone` specifies the height at which the webview can be gripped
@@ -17,3 +23,145 @@ use serde::{Deserialize, Serialize};
 )]
 #[reflect(Component, Default, Serialize, Deserialize)]
 pub struct GripZone(pub u32);
+
+/// The CSS selector of the elements that work as drag handles in addition to [`GripZone`].
+///
+/// Dragging the matched element moves the webview like the grip zone,
+/// but interactive children such as buttons, inputs and links inside the handle are excluded.
+/// Elements with `data-flurx-no-drag` attribute are also excluded.
+///
+/// Default is `[data-flurx-drag]`, so any element with `data-flurx-drag` attribute is a drag handle.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::PrimaryWindow;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_webview(mut commands: Commands, window: Query<Entity, With<PrimaryWindow>>) {
+///     commands.spawn((
+///         Webview::default(),
+///         EmbedWithin(window.single().unwrap()),
+///         GripZone(0),
+///         DragHandles::new(".title-bar, [data-flurx-drag]"),
+///     ));
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Reflect, Clone, Eq, PartialEq, Hash, Component, Serialize, Deserialize)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct DragHandles(pub String);
+
+impl DragHandles {
+    /// Creates the drag handles matched by the CSS selector.
+    pub fn new(selector: impl Into<String>) -> Self {
+        Self(selector.into())
+    }
+}
+
+impl Default for DragHandles {
+    fn default() -> Self {
+        Self::new("[data-flurx-drag]")
+    }
+}
+
+/// Whether to maximize the webview within the parent window by double-clicking [`GripZone`] or [`DragHandles`].
+///
+/// Double-clicking the maximized webview restores the bounds before maximizing.
+///
+/// Default is `false`.
+#[repr(transparent)]
+#[derive(
+    Debug, Reflect, Copy, Clone, Eq, PartialEq, Hash, Component, Default, Serialize, Deserialize,
+)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct MaximizeOnDoubleClick(pub bool);
+
+/// Inserted while the webview is maximized within the parent window.
+///
+/// The maximized webview follows the window size.
+#[derive(Debug, Reflect, Copy, Clone, PartialEq, Component, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Maximized {
+    /// The bounds before maximizing, which are restored when unmaximized.
+    pub restore_bounds: Bounds,
+}
+
+pub(crate) struct MaximizePlugin;
+
+impl Plugin for MaximizePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WindowResized>()
+            .add_systems(Update, follow_maximized_window);
+    }
+}
+
+fn follow_maximized_window(
+    mut er: EventReader<WindowResized>,
+    mut webviews: Query<(&mut Bounds, &EmbedWithin), With<Maximized>>,
+    windows: Query<&Window>,
+) {
+    let resized = er.read().map(|event| event.window).collect::<HashSet<_>>();
+    if resized.is_empty() {
+        return;
+    }
+    for (mut bounds, parent) in webviews.iter_mut() {
+        if !resized.contains(&parent.0) {
+            continue;
+        }
+        let Ok(window) = windows.get(parent.0) else {
+            continue;
+        };
+        bounds.position = Vec2::ZERO;
+        bounds.size = window.size().max(bounds.min_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::embedding::grip_zone::follow_maximized_window;
+    use crate::prelude::{Bounds, EmbedWithin, Maximized};
+    use bevy::prelude::*;
+    use bevy::window::WindowResized;
+
+    #[test]
+    fn follow_resized_window_while_maximized() {
+        let mut app = App::new();
+        app.add_event::<WindowResized>()
+            .add_systems(Update, follow_maximized_window);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let restore_bounds = Bounds {
+            position: Vec2::new(10., 10.),
+            size: Vec2::new(100., 100.),
+            ..default()
+        };
+        let webview = app
+            .world_mut()
+            .spawn((
+                EmbedWithin(window),
+                Maximized { restore_bounds },
+                Bounds {
+                    size: Vec2::new(1280., 720.),
+                    ..default()
+                },
+            ))
+            .id();
+
+        let mut window_component = app.world_mut().get_mut::<Window>(window).unwrap();
+        window_component.resolution.set(400., 300.);
+        app.world_mut().send_event(WindowResized {
+            window,
+            width: 400.,
+            height: 300.,
+        });
+        app.update();
+        let bounds = app.world().get::<Bounds>(webview).unwrap();
+        assert_eq!(bounds.position, Vec2::ZERO);
+        assert_eq!(bounds.size, Vec2::new(400., 300.));
+        assert_eq!(
+            app.world().get::<Maximized>(webview),
+            Some(&Maximized { restore_bounds })
+        );
+    }
+}
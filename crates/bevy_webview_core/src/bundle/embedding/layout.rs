@@ -0,0 +1,375 @@
+use crate::bundle::embedding::{Bounds, EmbedWithin};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::{
+    App, Component, DetectChanges, DetectChangesMut, EventReader, Plugin, Query, Ref, Reflect,
+    ReflectComponent, ReflectDefault, ReflectDeserialize, ReflectSerialize, Update, Vec2, Window,
+};
+use bevy::window::WindowResized;
+use serde::{Deserialize, Serialize};
+
+/// Lays out the embedded webview relative to the parent window.
+///
+/// [`Bounds`] is recomputed from this component when the parent window is resized or this component is changed,
+/// so the webview follows the window.
+///
+/// Note that the bounds changed by dragging or resizing the webview are overwritten on the next recomputation.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::PrimaryWindow;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_sidebar(mut commands: Commands, window: Query<Entity, With<PrimaryWindow>>) {
+///     commands.spawn((
+///         Webview::default(),
+///         EmbedWithin(window.single().unwrap()),
+///         WebviewLayout {
+///             anchor: WebviewAnchor::TopRight,
+///             width: LayoutLength::Percent(30.),
+///             height: LayoutLength::Percent(100.),
+///             margin: LayoutMargin::all(8.),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct WebviewLayout {
+    /// The edge or corner of the window the webview is attached to.
+    pub anchor: WebviewAnchor,
+
+    /// The width of the webview.
+    pub width: LayoutLength,
+
+    /// The height of the webview.
+    pub height: LayoutLength,
+
+    /// The space between the webview and the window edges.
+    pub margin: LayoutMargin,
+
+    /// The maximum size of the webview.
+    ///
+    /// The minimum size is [`Bounds::min_size`].
+    pub max_size: Option<Vec2>,
+
+    /// Locks the ratio of width to height.
+    ///
+    /// The size is shrunk to keep the ratio within the specified width and height.
+    pub aspect_ratio: Option<f32>,
+}
+
+impl WebviewLayout {
+    /// Computes the bounds within the window of the specified logical size.
+    pub fn compute_bounds(&self, window_size: Vec2, min_size: Vec2) -> Bounds {
+        let margin = &self.margin;
+        let available = (window_size
+            - Vec2::new(margin.left + margin.right, margin.top + margin.bottom))
+        .max(Vec2::ZERO);
+        let mut size = Vec2::new(
+            self.width.resolve(available.x),
+            self.height.resolve(available.y),
+        );
+        if let Some(max_size) = self.max_size {
+            size = size.min(max_size);
+        }
+        if let Some(ratio) = self.aspect_ratio.filter(|ratio| 0. < *ratio) {
+            size = if ratio < size.x / size.y {
+                Vec2::new(size.y * ratio, size.y)
+            } else {
+                Vec2::new(size.x, size.x / ratio)
+            };
+        }
+        let size = size.max(min_size);
+
+        let (horizontal, vertical) = self.anchor.alignment();
+        let position = Vec2::new(
+            align(horizontal, margin.left, margin.right, window_size.x, size.x),
+            align(vertical, margin.top, margin.bottom, window_size.y, size.y),
+        );
+        Bounds {
+            position,
+            size,
+            min_size,
+        }
+    }
+}
+
+impl Default for WebviewLayout {
+    fn default() -> Self {
+        Self {
+            anchor: WebviewAnchor::default(),
+            width: LayoutLength::Percent(100.),
+            height: LayoutLength::Percent(100.),
+            margin: LayoutMargin::default(),
+            max_size: None,
+            aspect_ratio: None,
+        }
+    }
+}
+
+/// The edge or corner of the window the webview is attached to.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Reflect, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
+pub enum WebviewAnchor {
+    /// The top left corner.
+    #[default]
+    TopLeft,
+
+    /// The center of the top edge.
+    Top,
+
+    /// The top right corner.
+    TopRight,
+
+    /// The center of the left edge.
+    Left,
+
+    /// The center of the window.
+    Center,
+
+    /// The center of the right edge.
+    Right,
+
+    /// The bottom left corner.
+    BottomLeft,
+
+    /// The center of the bottom edge.
+    Bottom,
+
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl WebviewAnchor {
+    const fn alignment(&self) -> (Alignment, Alignment) {
+        match self {
+            Self::TopLeft => (Alignment::Start, Alignment::Start),
+            Self::Top => (Alignment::Center, Alignment::Start),
+            Self::TopRight => (Alignment::End, Alignment::Start),
+            Self::Left => (Alignment::Start, Alignment::Center),
+            Self::Center => (Alignment::Center, Alignment::Center),
+            Self::Right => (Alignment::End, Alignment::Center),
+            Self::BottomLeft => (Alignment::Start, Alignment::End),
+            Self::Bottom => (Alignment::Center, Alignment::End),
+            Self::BottomRight => (Alignment::End, Alignment::End),
+        }
+    }
+}
+
+/// The length of the webview.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum LayoutLength {
+    /// Logical pixels.
+    Px(f32),
+
+    /// Percentage of the window size excluding the margins.
+    Percent(f32),
+}
+
+impl LayoutLength {
+    #[inline]
+    fn resolve(&self, available: f32) -> f32 {
+        match self {
+            Self::Px(px) => *px,
+            Self::Percent(percent) => available * percent / 100.,
+        }
+    }
+}
+
+/// The space between the webview and the window edges in logical pixels.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
+pub struct LayoutMargin {
+    /// The top margin.
+    pub top: f32,
+
+    /// The right margin.
+    pub right: f32,
+
+    /// The bottom margin.
+    pub bottom: f32,
+
+    /// The left margin.
+    pub left: f32,
+}
+
+impl LayoutMargin {
+    /// Creates the margins that are the same on all sides.
+    pub const fn all(margin: f32) -> Self {
+        Self {
+            top: margin,
+            right: margin,
+            bottom: margin,
+            left: margin,
+        }
+    }
+}
+
+enum Alignment {
+    Start,
+    Center,
+    End,
+}
+
+#[inline]
+fn align(alignment: Alignment, start_margin: f32, end_margin: f32, window: f32, size: f32) -> f32 {
+    match alignment {
+        Alignment::Start => start_margin,
+        Alignment::Center => (window - size + start_margin - end_margin) / 2.,
+        Alignment::End => window - end_margin - size,
+    }
+}
+
+pub(crate) struct WebviewLayoutPlugin;
+
+impl Plugin for WebviewLayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WebviewLayout>()
+            .register_type::<WebviewAnchor>()
+            .register_type::<LayoutLength>()
+            .register_type::<LayoutMargin>()
+            .add_event::<WindowResized>()
+            .add_systems(Update, apply_layout);
+    }
+}
+
+pub(crate) fn apply_layout(
+    mut er: EventReader<WindowResized>,
+    mut webviews: Query<(&mut Bounds, Ref<WebviewLayout>, Ref<EmbedWithin>)>,
+    windows: Query<&Window>,
+) {
+    let resized = er.read().map(|event| event.window).collect::<HashSet<_>>();
+    for (mut bounds, layout, parent) in webviews.iter_mut() {
+        if !layout.is_changed() && !parent.is_changed() && !resized.contains(&parent.0) {
+            continue;
+        }
+        let Ok(window) = windows.get(parent.0) else {
+            continue;
+        };
+        let new_bounds = layout.compute_bounds(window.size(), bounds.min_size);
+        bounds.set_if_neq(new_bounds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::embedding::layout::apply_layout;
+    use crate::prelude::{
+        Bounds, EmbedWithin, LayoutLength, LayoutMargin, WebviewAnchor, WebviewLayout,
+    };
+    use bevy::prelude::*;
+    use bevy::window::WindowResized;
+
+    const WINDOW_SIZE: Vec2 = Vec2::new(1000., 800.);
+
+    #[test]
+    fn fill_window_by_default() {
+        let bounds = WebviewLayout::default().compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(bounds.position, Vec2::ZERO);
+        assert_eq!(bounds.size, WINDOW_SIZE);
+    }
+
+    #[test]
+    fn anchor_bottom_right_with_margin() {
+        let layout = WebviewLayout {
+            anchor: WebviewAnchor::BottomRight,
+            width: LayoutLength::Px(200.),
+            height: LayoutLength::Px(100.),
+            margin: LayoutMargin::all(10.),
+            ..default()
+        };
+        let bounds = layout.compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(bounds.position, Vec2::new(790., 690.));
+        assert_eq!(bounds.size, Vec2::new(200., 100.));
+    }
+
+    #[test]
+    fn percent_excludes_margin() {
+        let layout = WebviewLayout {
+            width: LayoutLength::Percent(50.),
+            margin: LayoutMargin {
+                left: 100.,
+                right: 100.,
+                ..default()
+            },
+            ..default()
+        };
+        let bounds = layout.compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(bounds.position, Vec2::new(100., 0.));
+        assert_eq!(bounds.size, Vec2::new(400., 800.));
+    }
+
+    #[test]
+    fn follow_resized_window() {
+        let mut app = App::new();
+        app.add_event::<WindowResized>()
+            .add_systems(Update, apply_layout);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let webview = app
+            .world_mut()
+            .spawn((
+                EmbedWithin(window),
+                WebviewLayout {
+                    anchor: WebviewAnchor::Right,
+                    width: LayoutLength::Px(100.),
+                    ..default()
+                },
+            ))
+            .id();
+        app.update();
+
+        let mut window_component = app.world_mut().get_mut::<Window>(window).unwrap();
+        window_component.resolution.set(400., 300.);
+        app.world_mut().send_event(WindowResized {
+            window,
+            width: 400.,
+            height: 300.,
+        });
+        app.update();
+        let bounds = app.world().get::<Bounds>(webview).unwrap();
+        assert_eq!(bounds.position, Vec2::new(300., 0.));
+        assert_eq!(bounds.size, Vec2::new(100., 300.));
+    }
+
+    #[test]
+    fn center() {
+        let layout = WebviewLayout {
+            anchor: WebviewAnchor::Center,
+            width: LayoutLength::Px(200.),
+            height: LayoutLength::Px(100.),
+            ..default()
+        };
+        let bounds = layout.compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(bounds.position, Vec2::new(400., 350.));
+    }
+
+    #[test]
+    fn clamp_to_max_and_min_size() {
+        let layout = WebviewLayout {
+            max_size: Some(Vec2::new(300., 300.)),
+            ..default()
+        };
+        let bounds = layout.compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(bounds.size, Vec2::new(300., 300.));
+
+        let bounds = layout.compute_bounds(Vec2::new(100., 100.), Vec2::new(200., 150.));
+        assert_eq!(bounds.size, Vec2::new(200., 150.));
+    }
+
+    #[test]
+    fn lock_aspect_ratio() {
+        let layout = WebviewLayout {
+            aspect_ratio: Some(2.),
+            ..default()
+        };
+        let bounds = layout.compute_bounds(Vec2::new(1600., 1600.), Vec2::ZERO);
+        assert_eq!(bounds.size, Vec2::new(1600., 800.));
+
+        let bounds = layout.compute_bounds(Vec2::new(3200., 900.), Vec2::ZERO);
+        assert_eq!(bounds.size, Vec2::new(1800., 900.));
+    }
+}
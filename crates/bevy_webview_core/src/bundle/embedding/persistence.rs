@@ -0,0 +1,544 @@
+use crate::bundle::embedding::layout::apply_layout;
+use crate::prelude::{Bounds, EmbedWithin, WebviewVisible, WebviewZIndex};
+use bevy::app::AppExit;
+use bevy::log::warn;
+use bevy::prelude::{
+    Added, App, Changed, EventReader, IntoScheduleConfigs, Last, Name, Or, Plugin, Query, Ref, Res,
+    ResMut, Resource, Startup, Time, Update, Vec2, Window,
+};
+use bevy::window::WindowCloseRequested;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The version of the layout file format.
+///
+/// The file saved with the other version is ignored,
+/// and renamed to `<file name>.v<version>.bak` so that it isn't overwritten.
+pub const LAYOUT_FILE_VERSION: u32 = 1;
+
+/// Saves [`Bounds`], [`WebviewVisible`] and [`WebviewZIndex`] of the embedded webviews,
+/// and restores them when the webviews are spawned next time.
+///
+/// Only the webviews with [`Name`] are saved, and the name is used as the key.
+/// The restored bounds are clamped to the current window size.
+///
+/// This plugin is available with `persist` feature flag.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// App::new().add_plugins(WebviewLayoutPersistencePlugin::in_config_dir("my_game"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebviewLayoutPersistencePlugin {
+    /// The path to the layout file.
+    pub path: PathBuf,
+
+    /// The format of the layout file.
+    pub format: LayoutFileFormat,
+
+    /// The time to wait after the last change before writing the file.
+    ///
+    /// The file is also written when the app exits or a window is requested to close.
+    pub save_delay: Duration,
+}
+
+impl WebviewLayoutPersistencePlugin {
+    /// Saves the layouts to `webview_layout.ron` in the `app_name` directory of the user config directory.
+    ///
+    /// If the config directory can't be found, the current directory is used instead.
+    pub fn in_config_dir(app_name: &str) -> Self {
+        let dir = dirs::config_dir().unwrap_or_default().join(app_name);
+        Self::new(dir.join("webview_layout.ron"))
+    }
+
+    /// Saves the layouts to the file in RON format.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            format: LayoutFileFormat::Ron,
+            save_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Sets the format of the layout file.
+    ///
+    /// The extension of the file is also replaced.
+    pub fn with_format(mut self, format: LayoutFileFormat) -> Self {
+        self.path.set_extension(format.extension());
+        self.format = format;
+        self
+    }
+}
+
+impl Plugin for WebviewLayoutPersistencePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LayoutFile {
+            path: self.path.clone(),
+            format: self.format,
+            save_delay: self.save_delay,
+        })
+        .init_resource::<PersistedLayouts>()
+        .init_resource::<PendingSave>()
+        .add_event::<AppExit>()
+        .add_event::<WindowCloseRequested>()
+        .add_systems(Startup, load_layouts)
+        .add_systems(
+            Update,
+            (restore_layouts.after(apply_layout), record_layouts).chain(),
+        )
+        // Runs after the systems sending `AppExit` so that the last changes are flushed in the same frame.
+        .add_systems(Last, save_layouts);
+    }
+}
+
+/// The format of the layout file.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LayoutFileFormat {
+    /// [RON](https://github.com/ron-rs/ron)
+    #[default]
+    Ron,
+
+    /// JSON
+    Json,
+}
+
+impl LayoutFileFormat {
+    /// Returns the file extension without the dot.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Ron => "ron",
+            Self::Json => "json",
+        }
+    }
+
+    fn serialize(&self, layouts: &PersistedLayouts) -> Result<String, String> {
+        match self {
+            Self::Ron => ron::ser::to_string_pretty(layouts, ron::ser::PrettyConfig::default())
+                .map_err(|e| e.to_string()),
+            Self::Json => serde_json::to_string_pretty(layouts).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn deserialize(&self, source: &str) -> Result<PersistedLayouts, String> {
+        match self {
+            Self::Ron => ron::from_str(source).map_err(|e| e.to_string()),
+            Self::Json => serde_json::from_str(source).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// The layouts of the embedded webviews keyed by [`Name`].
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedLayouts {
+    /// The version of the format.
+    pub version: u32,
+
+    /// The layouts keyed by the webview name.
+    pub webviews: BTreeMap<String, PersistedLayout>,
+}
+
+impl Default for PersistedLayouts {
+    fn default() -> Self {
+        Self {
+            version: LAYOUT_FILE_VERSION,
+            webviews: BTreeMap::new(),
+        }
+    }
+}
+
+/// The layout of the embedded webview.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedLayout {
+    /// The display area.
+    pub bounds: Bounds,
+
+    /// Whether the webview is visible.
+    pub visible: bool,
+
+    /// The stacking order.
+    pub z_index: i32,
+}
+
+impl PersistedLayout {
+    /// Returns the bounds clamped to fit within the window of the specified logical size.
+    pub fn clamped_bounds(&self, window_size: Vec2) -> Bounds {
+        let min_size = self.bounds.min_size;
+        let size = self.bounds.size.min(window_size).max(min_size);
+        let max_position = (window_size - size).max(Vec2::ZERO);
+        Bounds {
+            position: self.bounds.position.clamp(Vec2::ZERO, max_position),
+            size,
+            min_size,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct LayoutFile {
+    path: PathBuf,
+    format: LayoutFileFormat,
+    save_delay: Duration,
+}
+
+/// The elapsed time when the layouts were last changed, if they have not been saved yet.
+#[derive(Resource, Default)]
+struct PendingSave(Option<Duration>);
+
+fn load_layouts(file: Res<LayoutFile>, mut layouts: ResMut<PersistedLayouts>) {
+    let Ok(source) = std::fs::read_to_string(&file.path) else {
+        return;
+    };
+    match file.format.deserialize(&source) {
+        Ok(loaded) if loaded.version == LAYOUT_FILE_VERSION => {
+            *layouts = loaded;
+        }
+        Ok(loaded) => {
+            warn!(
+                "Ignored the webview layouts of version {} in {:?}",
+                loaded.version, file.path
+            );
+            back_up(&file.path, &format!("v{}.bak", loaded.version));
+        }
+        Err(e) => {
+            warn!("Failed to read the webview layouts {:?}: {e}", file.path);
+            back_up(&file.path, "bak");
+        }
+    }
+}
+
+/// Renames the layout file to `<file name>.<suffix>` so that it isn't overwritten by the next save.
+fn back_up(path: &Path, suffix: &str) {
+    let backup = append_extension(path, suffix);
+    match std::fs::rename(path, &backup) {
+        Ok(()) => warn!("Moved the webview layouts {path:?} to {backup:?}"),
+        Err(e) => warn!("Failed to back up the webview layouts {path:?}: {e}"),
+    }
+}
+
+fn append_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(extension);
+    PathBuf::from(path)
+}
+
+fn restore_layouts(
+    layouts: Res<PersistedLayouts>,
+    mut webviews: Query<
+        (
+            &Name,
+            &EmbedWithin,
+            &mut Bounds,
+            &mut WebviewZIndex,
+            Option<&mut WebviewVisible>,
+        ),
+        Added<EmbedWithin>,
+    >,
+    windows: Query<&Window>,
+) {
+    for (name, parent, mut bounds, mut z_index, visible) in webviews.iter_mut() {
+        let Some(layout) = layouts.webviews.get(name.as_str()) else {
+            continue;
+        };
+        if let Ok(window) = windows.get(parent.0) {
+            *bounds = layout.clamped_bounds(window.size());
+        }
+        z_index.0 = layout.z_index;
+        if let Some(mut visible) = visible {
+            visible.0 = layout.visible;
+        }
+    }
+}
+
+fn record_layouts(
+    mut layouts: ResMut<PersistedLayouts>,
+    mut pending: ResMut<PendingSave>,
+    time: Res<Time>,
+    webviews: Query<
+        (
+            &Name,
+            Ref<EmbedWithin>,
+            &Bounds,
+            &WebviewZIndex,
+            Option<&WebviewVisible>,
+        ),
+        (
+            Or<(
+                Changed<Bounds>,
+                Changed<WebviewZIndex>,
+                Changed<WebviewVisible>,
+            )>,
+        ),
+    >,
+) {
+    for (name, embed_within, bounds, z_index, visible) in webviews.iter() {
+        // The layout restored in this frame is already persisted, even if it has been clamped to the window.
+        if embed_within.is_added() && layouts.webviews.contains_key(name.as_str()) {
+            continue;
+        }
+        let layout = PersistedLayout {
+            bounds: *bounds,
+            visible: visible.is_none_or(|visible| visible.0),
+            z_index: z_index.0,
+        };
+        if layouts.webviews.get(name.as_str()) != Some(&layout) {
+            layouts.webviews.insert(name.to_string(), layout);
+            pending.0 = Some(time.elapsed());
+        }
+    }
+}
+
+fn save_layouts(
+    mut er_exit: EventReader<AppExit>,
+    mut er_close: EventReader<WindowCloseRequested>,
+    mut pending: ResMut<PendingSave>,
+    file: Res<LayoutFile>,
+    layouts: Res<PersistedLayouts>,
+    time: Res<Time>,
+) {
+    let exiting = er_exit.read().count() > 0 || er_close.read().count() > 0;
+    let Some(changed_at) = pending.0 else {
+        return;
+    };
+    if !exiting && time.elapsed() < changed_at + file.save_delay {
+        return;
+    }
+    pending.0 = None;
+    if let Err(e) = write_layouts(&file.path, file.format, &layouts) {
+        warn!("Failed to save the webview layouts {:?}: {e}", file.path);
+    }
+}
+
+fn write_layouts(
+    path: &Path,
+    format: LayoutFileFormat,
+    layouts: &PersistedLayouts,
+) -> Result<(), String> {
+    let source = format.serialize(layouts)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    // Writes to the temporary file first so that the layout file isn't broken if the app is killed while writing.
+    let tmp = append_extension(path, "tmp");
+    std::fs::write(&tmp, source).map_err(|e| e.to_string())?;
+    std::fs::rename(&tmp, path).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::embedding::persistence::{
+        LAYOUT_FILE_VERSION, LayoutFileFormat, PendingSave, PersistedLayout, PersistedLayouts,
+        write_layouts,
+    };
+    use crate::prelude::{
+        Bounds, EmbedWithin, WebviewLayoutPersistencePlugin, WebviewVisible, WebviewZIndex,
+    };
+    use bevy::prelude::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn clamp_to_window() {
+        let layout = PersistedLayout {
+            bounds: Bounds {
+                position: Vec2::new(900., -10.),
+                size: Vec2::new(300., 900.),
+                min_size: Vec2::ZERO,
+            },
+            visible: true,
+            z_index: 0,
+        };
+        let bounds = layout.clamped_bounds(Vec2::new(1000., 800.));
+        assert_eq!(bounds.position, Vec2::new(700., 0.));
+        assert_eq!(bounds.size, Vec2::new(300., 800.));
+    }
+
+    #[test]
+    fn restore_saved_layout() {
+        for format in [LayoutFileFormat::Ron, LayoutFileFormat::Json] {
+            let path = std::env::temp_dir()
+                .join(format!("bevy_webview_core_{}", std::process::id()))
+                .join("restore_saved_layout")
+                .with_extension(format.extension());
+            let layout = PersistedLayout {
+                bounds: Bounds {
+                    position: Vec2::new(10., 20.),
+                    size: Vec2::new(300., 200.),
+                    min_size: Vec2::ZERO,
+                },
+                visible: false,
+                z_index: 3,
+            };
+            let mut layouts = PersistedLayouts::default();
+            layouts.webviews.insert("panel".to_string(), layout);
+            write_layouts(&path, format, &layouts).unwrap();
+
+            let mut app = App::new();
+            app.add_plugins((
+                MinimalPlugins,
+                WebviewLayoutPersistencePlugin::new(&path).with_format(format),
+            ));
+            let window = app.world_mut().spawn(Window::default()).id();
+            app.update();
+            let webview = app
+                .world_mut()
+                .spawn((
+                    Name::new("panel"),
+                    EmbedWithin(window),
+                    WebviewVisible(true),
+                ))
+                .id();
+            app.update();
+
+            assert_eq!(app.world().get::<Bounds>(webview), Some(&layout.bounds));
+            assert_eq!(
+                app.world().get::<WebviewVisible>(webview),
+                Some(&WebviewVisible(false))
+            );
+            assert_eq!(
+                app.world().get::<WebviewZIndex>(webview),
+                Some(&WebviewZIndex(3))
+            );
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+
+    #[test]
+    fn back_up_other_version() {
+        let dir = std::env::temp_dir()
+            .join(format!("bevy_webview_core_{}", std::process::id()))
+            .join("back_up_other_version");
+        let path = dir.join("webview_layout.json");
+        let layouts = PersistedLayouts {
+            version: LAYOUT_FILE_VERSION + 1,
+            ..default()
+        };
+        write_layouts(&path, LayoutFileFormat::Json, &layouts).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            WebviewLayoutPersistencePlugin::new(&path).with_format(LayoutFileFormat::Json),
+        ));
+        app.update();
+
+        assert!(!path.exists());
+        let backup = dir.join(format!(
+            "webview_layout.json.v{}.bak",
+            LAYOUT_FILE_VERSION + 1
+        ));
+        let backed_up: PersistedLayouts =
+            serde_json::from_str(&std::fs::read_to_string(&backup).unwrap()).unwrap();
+        assert_eq!(backed_up, layouts);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn save_after_delay() {
+        let path = std::env::temp_dir()
+            .join(format!("bevy_webview_core_{}", std::process::id()))
+            .join("save_after_delay.json");
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            WebviewLayoutPersistencePlugin::new(&path).with_format(LayoutFileFormat::Json),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let window = app.world_mut().spawn(Window::default()).id();
+        app.world_mut().spawn((
+            Name::new("panel"),
+            EmbedWithin(window),
+            Bounds {
+                position: Vec2::new(1., 2.),
+                size: Vec2::new(3., 4.),
+                min_size: Vec2::ZERO,
+            },
+        ));
+        app.update();
+        app.update();
+        assert!(!path.exists());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let saved: PersistedLayouts =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.webviews["panel"].bounds.position, Vec2::new(1., 2.));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn not_save_restored_layout() {
+        let path = std::env::temp_dir()
+            .join(format!("bevy_webview_core_{}", std::process::id()))
+            .join("not_save_restored_layout.json");
+        let mut layouts = PersistedLayouts::default();
+        layouts.webviews.insert(
+            "panel".to_string(),
+            PersistedLayout {
+                bounds: Bounds {
+                    position: Vec2::new(5000., 20.),
+                    size: Vec2::new(300., 200.),
+                    min_size: Vec2::ZERO,
+                },
+                visible: true,
+                z_index: 0,
+            },
+        );
+        write_layouts(&path, LayoutFileFormat::Json, &layouts).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            WebviewLayoutPersistencePlugin::new(&path).with_format(LayoutFileFormat::Json),
+        ));
+        let window = app.world_mut().spawn(Window::default()).id();
+        app.update();
+        app.world_mut()
+            .spawn((Name::new("panel"), EmbedWithin(window)));
+        app.update();
+
+        // The bounds are clamped to the window, but the file is not rewritten.
+        assert!(app.world().resource::<PendingSave>().0.is_none());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn save_on_exit_in_same_frame() {
+        let path = std::env::temp_dir()
+            .join(format!("bevy_webview_core_{}", std::process::id()))
+            .join("save_on_exit_in_same_frame.json");
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            WebviewLayoutPersistencePlugin::new(&path).with_format(LayoutFileFormat::Json),
+        ))
+        .add_systems(PostUpdate, |mut ew: EventWriter<AppExit>| {
+            ew.write(AppExit::Success);
+        });
+        let window = app.world_mut().spawn(Window::default()).id();
+        app.world_mut().spawn((
+            Name::new("panel"),
+            EmbedWithin(window),
+            Bounds {
+                position: Vec2::new(1., 2.),
+                size: Vec2::new(3., 4.),
+                min_size: Vec2::ZERO,
+            },
+        ));
+        app.update();
+
+        let saved: PersistedLayouts =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.webviews["panel"].bounds.position, Vec2::new(1., 2.));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
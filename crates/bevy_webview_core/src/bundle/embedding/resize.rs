@@ -1,4 +1,8 @@
-use bevy::prelude::{Component, Reflect, ReflectComponent, ReflectDeserialize, ReflectSerialize};
+use crate::bundle::embedding::Bounds;
+use bevy::prelude::{
+    Component, Entity, Event, Reflect, ReflectComponent, ReflectDefault, ReflectDeserialize,
+    ReflectSerialize, Vec2,
+};
 use bevy::window::SystemCursorIcon;
 use serde::{Deserialize, Serialize};
 
@@ -38,3 +42,348 @@ impl ResizeMode {
         }
     }
 }
+
+/// The resize configuration of the embedded webview.
+///
+/// This takes effect only while [`Resizable`](crate::bundle::embedding::Resizable) is true.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::PrimaryWindow;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_webview(mut commands: Commands, window: Query<Entity, With<PrimaryWindow>>) {
+///     commands.spawn((
+///         Webview::default(),
+///         EmbedWithin(window.single().unwrap()),
+///         ResizeSettings {
+///             edges: ResizeEdges::RIGHT_AND_BOTTOM,
+///             margin: 8.,
+///             max_size: Some(Vec2::new(800., 600.)),
+///             increment: Some(Vec2::splat(10.)),
+///             aspect_ratio: Some(16. / 9.),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, PartialEq, Debug, Copy, Clone, Serialize, Deserialize)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct ResizeSettings {
+    /// The edges and corners that can be dragged to resize.
+    pub edges: ResizeEdges,
+
+    /// The width in logical pixels of the resize area outside the webview.
+    pub margin: f32,
+
+    /// The maximum size of the webview.
+    ///
+    /// The minimum size is [`Bounds::min_size`].
+    pub max_size: Option<Vec2>,
+
+    /// Snaps the size to multiples of this value.
+    pub increment: Option<Vec2>,
+
+    /// Keeps the ratio of width to height while resizing.
+    pub aspect_ratio: Option<f32>,
+}
+
+impl Default for ResizeSettings {
+    fn default() -> Self {
+        Self {
+            edges: ResizeEdges::ALL,
+            margin: 5.,
+            max_size: None,
+            increment: None,
+            aspect_ratio: None,
+        }
+    }
+}
+
+impl ResizeSettings {
+    /// Returns the resize direction if the cursor is within the resize area of the active edges.
+    pub fn resize_mode(
+        &self,
+        bounds: &Bounds,
+        cursor_pos: Vec2,
+        toolbar_height: Option<f32>,
+    ) -> Option<ResizeMode> {
+        bounds
+            .maybe_resizable_within(cursor_pos, toolbar_height, self.margin)
+            .filter(|mode| self.edges.contains(*mode))
+    }
+
+    /// Resizes `bounds` toward the mouse position, and applies the constraints.
+    ///
+    /// The edges opposite to the dragged ones stay in place.
+    pub fn resize(&self, bounds: &mut Bounds, mode: &ResizeMode, mouse_position: Vec2) {
+        let anchor = bounds.position + bounds.size;
+        bounds.transform(mode, mouse_position, 0.);
+
+        let mut size = bounds.size;
+        if let Some(increment) = self.increment {
+            size = snap_to_increment(size, increment);
+        }
+        let min_size = bounds.min_size;
+        let max_size = self.max_size.unwrap_or(Vec2::INFINITY).max(min_size);
+        size = size.min(max_size).max(min_size);
+        if let Some(ratio) = self.aspect_ratio.filter(|ratio| 0. < *ratio) {
+            // The dragged axis is clamped again so that the derived axis also stays within the limits.
+            size = match mode {
+                ResizeMode::Top | ResizeMode::Bottom => {
+                    let height = size
+                        .y
+                        .min(max_size.x / ratio)
+                        .max(min_size.x / ratio)
+                        .max(min_size.y);
+                    Vec2::new(height * ratio, height)
+                }
+                _ => {
+                    let width = size
+                        .x
+                        .min(max_size.y * ratio)
+                        .max(min_size.y * ratio)
+                        .max(min_size.x);
+                    Vec2::new(width, width / ratio)
+                }
+            };
+        }
+        bounds.size = size;
+
+        if matches!(
+            mode,
+            ResizeMode::Left | ResizeMode::TopLeft | ResizeMode::BottomLeft
+        ) {
+            bounds.position.x = anchor.x - bounds.size.x;
+        }
+        if matches!(
+            mode,
+            ResizeMode::Top | ResizeMode::TopLeft | ResizeMode::TopRight
+        ) {
+            bounds.position.y = anchor.y - bounds.size.y;
+        }
+    }
+}
+
+/// The edges and corners of the embedded webview that can be dragged to resize.
+#[derive(Reflect, Eq, PartialEq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
+pub struct ResizeEdges {
+    /// The left edge.
+    pub left: bool,
+    /// The top edge.
+    pub top: bool,
+    /// The right edge.
+    pub right: bool,
+    /// The bottom edge.
+    pub bottom: bool,
+    /// The top-left corner.
+    pub top_left: bool,
+    /// The top-right corner.
+    pub top_right: bool,
+    /// The bottom-left corner.
+    pub bottom_left: bool,
+    /// The bottom-right corner.
+    pub bottom_right: bool,
+}
+
+impl ResizeEdges {
+    /// All edges and corners.
+    pub const ALL: Self = Self {
+        left: true,
+        top: true,
+        right: true,
+        bottom: true,
+        top_left: true,
+        top_right: true,
+        bottom_left: true,
+        bottom_right: true,
+    };
+
+    /// No edges and corners.
+    pub const NONE: Self = Self {
+        left: false,
+        top: false,
+        right: false,
+        bottom: false,
+        top_left: false,
+        top_right: false,
+        bottom_left: false,
+        bottom_right: false,
+    };
+
+    /// The right and bottom edges, and the bottom-right corner.
+    pub const RIGHT_AND_BOTTOM: Self = Self {
+        right: true,
+        bottom: true,
+        bottom_right: true,
+        ..Self::NONE
+    };
+
+    /// Returns whether the edge or corner of the resize direction is active.
+    pub const fn contains(&self, mode: ResizeMode) -> bool {
+        match mode {
+            ResizeMode::Left => self.left,
+            ResizeMode::TopLeft => self.top_left,
+            ResizeMode::Top => self.top,
+            ResizeMode::TopRight => self.top_right,
+            ResizeMode::Right => self.right,
+            ResizeMode::BottomRight => self.bottom_right,
+            ResizeMode::Bottom => self.bottom,
+            ResizeMode::BottomLeft => self.bottom_left,
+        }
+    }
+}
+
+impl Default for ResizeEdges {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// The event is fired when the user starts resizing the embedded webview.
+#[derive(Event, Reflect, PartialEq, Debug, Copy, Clone)]
+pub struct WebviewResizeStarted {
+    /// The entity associated with the webview.
+    pub webview_entity: Entity,
+
+    /// The resize direction.
+    pub mode: ResizeMode,
+}
+
+/// The event is fired every time the bounds are changed by resizing.
+#[derive(Event, Reflect, PartialEq, Debug, Copy, Clone)]
+pub struct WebviewResized {
+    /// The entity associated with the webview.
+    pub webview_entity: Entity,
+
+    /// The resized bounds.
+    pub bounds: Bounds,
+}
+
+/// The event is fired when the user finishes resizing the embedded webview.
+///
+/// This is suitable for persisting the layout.
+#[derive(Event, Reflect, PartialEq, Debug, Copy, Clone)]
+pub struct WebviewResizeEnded {
+    /// The entity associated with the webview.
+    pub webview_entity: Entity,
+
+    /// The final bounds.
+    pub bounds: Bounds,
+}
+
+#[inline]
+fn snap_to_increment(size: Vec2, increment: Vec2) -> Vec2 {
+    let snap = |value: f32, increment: f32| {
+        if 0. < increment {
+            (value / increment).round() * increment
+        } else {
+            value
+        }
+    };
+    Vec2::new(snap(size.x, increment.x), snap(size.y, increment.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Bounds, ResizeEdges, ResizeMode, ResizeSettings};
+    use bevy::prelude::*;
+
+    #[test]
+    fn ignore_inactive_edges() {
+        let settings = ResizeSettings {
+            edges: ResizeEdges::RIGHT_AND_BOTTOM,
+            ..default()
+        };
+        let bounds = new_bounds();
+        assert_eq!(
+            settings.resize_mode(&bounds, Vec2::new(7., 50.), None),
+            None
+        );
+        assert_eq!(
+            settings.resize_mode(&bounds, Vec2::new(112., 50.), None),
+            Some(ResizeMode::Right)
+        );
+    }
+
+    #[test]
+    fn wider_margin() {
+        let settings = ResizeSettings {
+            margin: 10.,
+            ..default()
+        };
+        let bounds = new_bounds();
+        assert_eq!(
+            settings.resize_mode(&bounds, Vec2::new(118., 50.), None),
+            Some(ResizeMode::Right)
+        );
+        assert_eq!(
+            ResizeSettings::default().resize_mode(&bounds, Vec2::new(118., 50.), None),
+            None
+        );
+    }
+
+    #[test]
+    fn clamp_to_max_size_from_left() {
+        let settings = ResizeSettings {
+            max_size: Some(Vec2::new(150., 150.)),
+            ..default()
+        };
+        let mut bounds = new_bounds();
+        settings.resize(&mut bounds, &ResizeMode::Left, Vec2::new(-100., 50.));
+        assert_eq!(bounds.size, Vec2::new(150., 100.));
+        assert_eq!(bounds.position, Vec2::new(-40., 10.));
+    }
+
+    #[test]
+    fn snap_to_increment() {
+        let settings = ResizeSettings {
+            increment: Some(Vec2::splat(20.)),
+            ..default()
+        };
+        let mut bounds = new_bounds();
+        settings.resize(&mut bounds, &ResizeMode::BottomRight, Vec2::new(137., 125.));
+        assert_eq!(bounds.size, Vec2::new(120., 120.));
+        assert_eq!(bounds.position, Vec2::new(10., 10.));
+    }
+
+    #[test]
+    fn keep_aspect_ratio() {
+        let settings = ResizeSettings {
+            aspect_ratio: Some(2.),
+            ..default()
+        };
+        let mut bounds = new_bounds();
+        settings.resize(&mut bounds, &ResizeMode::Right, Vec2::new(210., 50.));
+        assert_eq!(bounds.size, Vec2::new(200., 100.));
+
+        settings.resize(&mut bounds, &ResizeMode::Top, Vec2::new(50., 60.));
+        assert_eq!(bounds.size, Vec2::new(100., 50.));
+        assert_eq!(bounds.position, Vec2::new(10., 60.));
+    }
+
+    #[test]
+    fn keep_aspect_ratio_within_max_size() {
+        let settings = ResizeSettings {
+            aspect_ratio: Some(2.),
+            max_size: Some(Vec2::new(300., 120.)),
+            ..default()
+        };
+        let mut bounds = new_bounds();
+        settings.resize(&mut bounds, &ResizeMode::Right, Vec2::new(510., 50.));
+        assert_eq!(bounds.size, Vec2::new(240., 120.));
+
+        settings.resize(&mut bounds, &ResizeMode::Bottom, Vec2::new(50., 510.));
+        assert_eq!(bounds.size, Vec2::new(240., 120.));
+    }
+
+    fn new_bounds() -> Bounds {
+        Bounds {
+            position: Vec2::new(10., 10.),
+            size: Vec2::new(100., 100.),
+            ..default()
+        }
+    }
+}
@@ -0,0 +1,470 @@
+use crate::bundle::embedding::{Bounds, EmbedWithin, WebviewResized};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::{
+    App, Commands, Component, Entity, Event, EventReader, EventWriter, Plugin, Query, Rect,
+    Reflect, ReflectComponent, ReflectDefault, ReflectDeserialize, ReflectSerialize, Update, Vec2,
+    Window,
+};
+use bevy::window::WindowResized;
+use serde::{Deserialize, Serialize};
+
+/// Snaps the embedded webview to the window edges and the edges of the other webviews while dragging it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::PrimaryWindow;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_panel(mut commands: Commands, window: Query<Entity, With<PrimaryWindow>>) {
+///     commands.spawn((
+///         Webview::default(),
+///         EmbedWithin(window.single().unwrap()),
+///         WebviewSnap::default(),
+///         DockZones::default(),
+///         PreventOverlap,
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct WebviewSnap {
+    /// The distance in logical pixels within which the webview snaps to the edge.
+    pub threshold: f32,
+
+    /// Whether to snap to the window edges.
+    pub window_edges: bool,
+
+    /// Whether to snap to the edges of the other webviews embedded in the same window.
+    pub webviews: bool,
+}
+
+impl Default for WebviewSnap {
+    fn default() -> Self {
+        Self {
+            threshold: 10.,
+            window_edges: true,
+            webviews: true,
+        }
+    }
+}
+
+impl WebviewSnap {
+    /// Returns the position of `bounds` snapped to the nearest edge within the threshold.
+    ///
+    /// `others` are the rects of the other webviews embedded in the same window.
+    pub fn snap(&self, bounds: &Bounds, window_size: Vec2, others: &[Rect]) -> Vec2 {
+        let rect = bounds.rect();
+        let size = bounds.size;
+        let mut xs = Vec::new();
+        let mut ys = Vec::new();
+        if self.window_edges {
+            xs.extend([0., window_size.x - size.x]);
+            ys.extend([0., window_size.y - size.y]);
+        }
+        if self.webviews {
+            for other in others {
+                if overlaps(
+                    rect.min.y,
+                    rect.max.y,
+                    other.min.y,
+                    other.max.y,
+                    self.threshold,
+                ) {
+                    xs.extend([
+                        other.max.x,
+                        other.min.x - size.x,
+                        other.min.x,
+                        other.max.x - size.x,
+                    ]);
+                }
+                if overlaps(
+                    rect.min.x,
+                    rect.max.x,
+                    other.min.x,
+                    other.max.x,
+                    self.threshold,
+                ) {
+                    ys.extend([
+                        other.max.y,
+                        other.min.y - size.y,
+                        other.min.y,
+                        other.max.y - size.y,
+                    ]);
+                }
+            }
+        }
+        Vec2::new(
+            nearest(bounds.position.x, &xs, self.threshold),
+            nearest(bounds.position.y, &ys, self.threshold),
+        )
+    }
+}
+
+/// Prevents the embedded webview from overlapping the other webviews while dragging it.
+#[derive(
+    Component, Copy, Clone, Debug, Default, Eq, PartialEq, Reflect, Serialize, Deserialize,
+)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct PreventOverlap;
+
+impl PreventOverlap {
+    /// Returns the position closest to `to` that the webview of `size` moving from `from` can take without overlapping `others`.
+    ///
+    /// If the webview can't move diagonally, it slides along the blocking edge.
+    /// If it already overlaps at `from`, such as after being resized or spawned there, it moves to `to` freely
+    /// so that it can be dragged out.
+    pub fn resolve(&self, size: Vec2, from: Vec2, to: Vec2, others: &[Rect]) -> Vec2 {
+        let collides = |position: Vec2| {
+            let rect = Rect::from_corners(position, position + size);
+            others
+                .iter()
+                .any(|other| !rect.intersect(*other).is_empty())
+        };
+        if collides(from) {
+            return to;
+        }
+        [to, Vec2::new(to.x, from.y), Vec2::new(from.x, to.y)]
+            .into_iter()
+            .find(|position| !collides(*position))
+            .unwrap_or(from)
+    }
+}
+
+/// Docks the embedded webview to the half of the window when it is released near the window edge.
+///
+/// While docked, [`Docked`] is inserted and the webview follows the window size.
+/// Dragging the docked webview undocks it and restores the size before docking,
+/// and resizing it undocks it with the resized size.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct DockZones {
+    /// The distance in logical pixels from the window edge within which the webview is docked.
+    pub threshold: f32,
+}
+
+impl Default for DockZones {
+    fn default() -> Self {
+        Self { threshold: 4. }
+    }
+}
+
+impl DockZones {
+    /// Returns the side of the window where the webview will be docked.
+    ///
+    /// The left and right edges take priority over the top and bottom edges.
+    pub fn dock_side(&self, bounds: &Bounds, window_size: Vec2) -> Option<DockSide> {
+        let rect = bounds.rect();
+        if rect.min.x <= self.threshold {
+            Some(DockSide::Left)
+        } else if window_size.x - self.threshold <= rect.max.x {
+            Some(DockSide::Right)
+        } else if rect.min.y <= self.threshold {
+            Some(DockSide::Top)
+        } else if window_size.y - self.threshold <= rect.max.y {
+            Some(DockSide::Bottom)
+        } else {
+            None
+        }
+    }
+}
+
+/// The half of the window the webview is docked to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum DockSide {
+    /// The left half.
+    Left,
+
+    /// The right half.
+    Right,
+
+    /// The top half.
+    Top,
+
+    /// The bottom half.
+    Bottom,
+}
+
+impl DockSide {
+    /// Computes the bounds filling the half of the window of the specified logical size.
+    pub fn compute_bounds(&self, window_size: Vec2, min_size: Vec2) -> Bounds {
+        let half = window_size / 2.;
+        let (position, size) = match self {
+            Self::Left => (Vec2::ZERO, Vec2::new(half.x, window_size.y)),
+            Self::Right => (Vec2::new(half.x, 0.), Vec2::new(half.x, window_size.y)),
+            Self::Top => (Vec2::ZERO, Vec2::new(window_size.x, half.y)),
+            Self::Bottom => (Vec2::new(0., half.y), Vec2::new(window_size.x, half.y)),
+        };
+        Bounds {
+            position,
+            size: size.max(min_size),
+            min_size,
+        }
+    }
+}
+
+/// Inserted while the webview is docked by [`DockZones`].
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Docked {
+    /// The side of the window the webview is docked to.
+    pub side: DockSide,
+
+    /// The size of the webview before docking, which is restored when undocked.
+    pub undocked_size: Vec2,
+}
+
+/// The event is fired when the webview is docked.
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub struct WebviewDocked {
+    /// The entity associated with the webview.
+    pub webview_entity: Entity,
+
+    /// The side of the window the webview has been docked to.
+    pub side: DockSide,
+}
+
+/// The event is fired when the docked webview is undocked.
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub struct WebviewUndocked {
+    /// The entity associated with the webview.
+    pub webview_entity: Entity,
+
+    /// The side of the window the webview had been docked to.
+    pub side: DockSide,
+}
+
+pub(crate) struct WebviewSnapPlugin;
+
+impl Plugin for WebviewSnapPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WebviewSnap>()
+            .register_type::<PreventOverlap>()
+            .register_type::<DockZones>()
+            .register_type::<DockSide>()
+            .register_type::<Docked>()
+            .register_type::<WebviewDocked>()
+            .register_type::<WebviewUndocked>()
+            .add_event::<WebviewDocked>()
+            .add_event::<WebviewUndocked>()
+            .add_event::<WindowResized>()
+            .add_event::<WebviewResized>()
+            .add_systems(Update, (follow_docked_window, undock_resized));
+    }
+}
+
+fn follow_docked_window(
+    mut er: EventReader<WindowResized>,
+    mut webviews: Query<(&mut Bounds, &Docked, &EmbedWithin)>,
+    windows: Query<&Window>,
+) {
+    let resized = er.read().map(|event| event.window).collect::<HashSet<_>>();
+    if resized.is_empty() {
+        return;
+    }
+    for (mut bounds, docked, parent) in webviews.iter_mut() {
+        if !resized.contains(&parent.0) {
+            continue;
+        }
+        let Ok(window) = windows.get(parent.0) else {
+            continue;
+        };
+        *bounds = docked.side.compute_bounds(window.size(), bounds.min_size);
+    }
+}
+
+fn undock_resized(
+    mut commands: Commands,
+    mut er: EventReader<WebviewResized>,
+    mut ew: EventWriter<WebviewUndocked>,
+    webviews: Query<&Docked>,
+) {
+    for event in er.read() {
+        let Ok(docked) = webviews.get(event.webview_entity) else {
+            continue;
+        };
+        commands.entity(event.webview_entity).remove::<Docked>();
+        ew.write(WebviewUndocked {
+            webview_entity: event.webview_entity,
+            side: docked.side,
+        });
+    }
+}
+
+#[inline]
+fn overlaps(min1: f32, max1: f32, min2: f32, max2: f32, threshold: f32) -> bool {
+    min1 <= max2 + threshold && min2 - threshold <= max1
+}
+
+fn nearest(value: f32, candidates: &[f32], threshold: f32) -> f32 {
+    candidates
+        .iter()
+        .copied()
+        .filter(|candidate| (candidate - value).abs() <= threshold)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::embedding::snap::undock_resized;
+    use crate::prelude::{
+        Bounds, DockSide, DockZones, Docked, PreventOverlap, WebviewResized, WebviewSnap,
+        WebviewUndocked,
+    };
+    use bevy::prelude::*;
+
+    const WINDOW_SIZE: Vec2 = Vec2::new(1000., 800.);
+
+    #[test]
+    fn snap_to_window_edges() {
+        let snap = WebviewSnap::default();
+        let bounds = new_bounds(Vec2::new(5., 695.));
+        assert_eq!(snap.snap(&bounds, WINDOW_SIZE, &[]), Vec2::new(0., 700.));
+    }
+
+    #[test]
+    fn not_snap_out_of_threshold() {
+        let snap = WebviewSnap::default();
+        let bounds = new_bounds(Vec2::new(11., 300.));
+        assert_eq!(snap.snap(&bounds, WINDOW_SIZE, &[]), Vec2::new(11., 300.));
+    }
+
+    #[test]
+    fn snap_to_other_webview() {
+        let snap = WebviewSnap::default();
+        let other = Rect::new(300., 300., 400., 400.);
+        let bounds = new_bounds(Vec2::new(405., 350.));
+        assert_eq!(
+            snap.snap(&bounds, WINDOW_SIZE, &[other]),
+            Vec2::new(400., 350.)
+        );
+    }
+
+    #[test]
+    fn not_snap_to_distant_webview() {
+        let snap = WebviewSnap::default();
+        let other = Rect::new(300., 0., 400., 100.);
+        let bounds = new_bounds(Vec2::new(405., 500.));
+        assert_eq!(
+            snap.snap(&bounds, WINDOW_SIZE, &[other]),
+            Vec2::new(405., 500.)
+        );
+    }
+
+    #[test]
+    fn slide_along_other_webview() {
+        let other = Rect::new(300., 300., 400., 400.);
+        let position = PreventOverlap.resolve(
+            Vec2::new(100., 100.),
+            Vec2::new(400., 200.),
+            Vec2::new(390., 250.),
+            &[other],
+        );
+        assert_eq!(position, Vec2::new(390., 200.));
+    }
+
+    #[test]
+    fn allow_touching() {
+        let other = Rect::new(300., 300., 400., 400.);
+        let position = PreventOverlap.resolve(
+            Vec2::new(100., 100.),
+            Vec2::new(450., 300.),
+            Vec2::new(400., 300.),
+            &[other],
+        );
+        assert_eq!(position, Vec2::new(400., 300.));
+    }
+
+    #[test]
+    fn move_out_if_already_overlapping() {
+        let other = Rect::new(300., 300., 400., 400.);
+        let position = PreventOverlap.resolve(
+            Vec2::new(100., 100.),
+            Vec2::new(350., 350.),
+            Vec2::new(360., 340.),
+            &[other],
+        );
+        assert_eq!(position, Vec2::new(360., 340.));
+    }
+
+    #[test]
+    fn dock_to_left_half() {
+        let zones = DockZones::default();
+        let bounds = new_bounds(Vec2::new(0., 300.));
+        let side = zones.dock_side(&bounds, WINDOW_SIZE);
+        assert_eq!(side, Some(DockSide::Left));
+
+        let docked = DockSide::Left.compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(docked.position, Vec2::ZERO);
+        assert_eq!(docked.size, Vec2::new(500., 800.));
+    }
+
+    #[test]
+    fn dock_to_bottom_half() {
+        let zones = DockZones::default();
+        let bounds = new_bounds(Vec2::new(300., 700.));
+        assert_eq!(
+            zones.dock_side(&bounds, WINDOW_SIZE),
+            Some(DockSide::Bottom)
+        );
+
+        let docked = DockSide::Bottom.compute_bounds(WINDOW_SIZE, Vec2::ZERO);
+        assert_eq!(docked.position, Vec2::new(0., 400.));
+        assert_eq!(docked.size, Vec2::new(1000., 400.));
+    }
+
+    #[test]
+    fn not_dock_away_from_edges() {
+        let zones = DockZones::default();
+        let bounds = new_bounds(Vec2::new(300., 300.));
+        assert_eq!(zones.dock_side(&bounds, WINDOW_SIZE), None);
+    }
+
+    #[test]
+    fn undock_when_resized() {
+        let mut app = App::new();
+        app.add_event::<WebviewResized>()
+            .add_event::<WebviewUndocked>()
+            .add_systems(Update, undock_resized);
+        let bounds = new_bounds(Vec2::ZERO);
+        let webview = app
+            .world_mut()
+            .spawn((
+                bounds,
+                Docked {
+                    side: DockSide::Left,
+                    undocked_size: Vec2::new(200., 200.),
+                },
+            ))
+            .id();
+        app.world_mut().send_event(WebviewResized {
+            webview_entity: webview,
+            bounds,
+        });
+        app.update();
+        assert!(app.world().get::<Docked>(webview).is_none());
+        let undocked = app
+            .world_mut()
+            .resource_mut::<Events<WebviewUndocked>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            undocked,
+            [WebviewUndocked {
+                webview_entity: webview,
+                side: DockSide::Left,
+            }]
+        );
+    }
+
+    fn new_bounds(position: Vec2) -> Bounds {
+        Bounds {
+            position,
+            size: Vec2::new(100., 100.),
+            ..default()
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::bundle::embedding::Bounds;
+use crate::prelude::WebviewVisible;
+use bevy::prelude::{
+    App, Component, DetectChangesMut, Entity, GlobalTransform, InheritedVisibility,
+    IntoScheduleConfigs, Plugin, PostUpdate, Query, Rect, Reflect, ReflectComponent, Vec2,
+};
+use bevy::transform::TransformSystem;
+use bevy::ui::{CalculatedClip, ComputedNode};
+
+/// Drives [`Bounds`] of the embedded webview from the `bevy_ui` node.
+///
+/// The node rect clipped by the ancestors is applied to [`Bounds`] every frame,
+/// and the webview is hidden while the node is invisible or completely clipped.
+/// [`WebviewVisible`] is only written when the visibility of the node changes,
+/// so it can still be changed manually while the node stays visible.
+///
+/// The node must be rendered into the window in which the webview is embedded.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::PrimaryWindow;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_panel(mut commands: Commands, window: Query<Entity, With<PrimaryWindow>>) {
+///     let node = commands
+///         .spawn(Node {
+///             width: Val::Percent(30.),
+///             height: Val::Percent(100.),
+///             ..default()
+///         })
+///         .id();
+///     commands.spawn((
+///         Webview::default(),
+///         EmbedWithin(window.single().unwrap()),
+///         FollowUiNode(node),
+///     ));
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(FollowedNodeVisibility)]
+pub struct FollowUiNode(pub Entity);
+
+/// The visibility of the followed node applied to [`WebviewVisible`] last time.
+#[derive(Component, Default)]
+pub(crate) struct FollowedNodeVisibility(Option<bool>);
+
+pub(crate) struct FollowUiNodePlugin;
+
+impl Plugin for FollowUiNodePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FollowUiNode>().add_systems(
+            PostUpdate,
+            follow_ui_node.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+fn follow_ui_node(
+    mut webviews: Query<(
+        &FollowUiNode,
+        &mut Bounds,
+        &mut WebviewVisible,
+        &mut FollowedNodeVisibility,
+    )>,
+    nodes: Query<(
+        &ComputedNode,
+        &GlobalTransform,
+        &InheritedVisibility,
+        Option<&CalculatedClip>,
+    )>,
+) {
+    for (follow, mut bounds, mut visible, mut node_visibility) in webviews.iter_mut() {
+        let Ok((node, transform, inherited_visibility, clip)) = nodes.get(follow.0) else {
+            continue;
+        };
+        let rect = node_rect(
+            node.size(),
+            transform.translation().truncate(),
+            clip.map(|clip| clip.clip),
+            node.inverse_scale_factor(),
+        );
+        let node_visible = inherited_visibility.get() && !rect.is_empty();
+        if node_visibility.0 != Some(node_visible) {
+            node_visibility.0 = Some(node_visible);
+            visible.set_if_neq(WebviewVisible(node_visible));
+        }
+        if !rect.is_empty() {
+            bounds.set_if_neq(Bounds {
+                position: rect.min,
+                size: rect.size(),
+                min_size: bounds.min_size,
+            });
+        }
+    }
+}
+
+/// Returns the logical rect of the node.
+///
+/// `size`, `center` and `clip` are physical pixels.
+fn node_rect(size: Vec2, center: Vec2, clip: Option<Rect>, inverse_scale_factor: f32) -> Rect {
+    let rect = Rect::from_center_size(center, size);
+    let rect = match clip {
+        Some(clip) => rect.intersect(clip),
+        None => rect,
+    };
+    Rect {
+        min: rect.min * inverse_scale_factor,
+        max: rect.max * inverse_scale_factor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::embedding::ui_node::{follow_ui_node, node_rect};
+    use crate::prelude::{Bounds, FollowUiNode, WebviewVisible};
+    use bevy::prelude::*;
+    use bevy::ui::ComputedNode;
+
+    #[test]
+    fn convert_to_logical_rect() {
+        let rect = node_rect(Vec2::new(200., 100.), Vec2::new(200., 100.), None, 0.5);
+        assert_eq!(rect, Rect::new(50., 25., 150., 75.));
+    }
+
+    #[test]
+    fn clip_rect() {
+        let clip = Rect::new(0., 0., 150., 1000.);
+        let rect = node_rect(Vec2::new(200., 100.), Vec2::new(200., 100.), Some(clip), 1.);
+        assert_eq!(rect, Rect::new(100., 50., 150., 150.));
+    }
+
+    #[test]
+    fn empty_if_clipped_completely() {
+        let clip = Rect::new(0., 0., 50., 50.);
+        let rect = node_rect(Vec2::new(200., 100.), Vec2::new(200., 100.), Some(clip), 1.);
+        assert!(rect.is_empty());
+    }
+
+    #[test]
+    fn write_visibility_only_when_node_visibility_changes() {
+        let mut app = App::new();
+        app.add_systems(Update, follow_ui_node);
+        let node = app
+            .world_mut()
+            .spawn((
+                ComputedNode {
+                    size: Vec2::new(100., 100.),
+                    inverse_scale_factor: 1.,
+                    ..default()
+                },
+                GlobalTransform::from_translation(Vec3::new(50., 50., 0.)),
+                InheritedVisibility::VISIBLE,
+            ))
+            .id();
+        let webview = app
+            .world_mut()
+            .spawn((FollowUiNode(node), Bounds::default(), WebviewVisible(true)))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .get_mut::<WebviewVisible>(webview)
+            .unwrap()
+            .0 = false;
+        app.update();
+        assert_eq!(
+            app.world().get::<WebviewVisible>(webview),
+            Some(&WebviewVisible(false))
+        );
+
+        *app.world_mut()
+            .get_mut::<InheritedVisibility>(node)
+            .unwrap() = InheritedVisibility::HIDDEN;
+        app.update();
+        *app.world_mut()
+            .get_mut::<InheritedVisibility>(node)
+            .unwrap() = InheritedVisibility::VISIBLE;
+        app.update();
+        assert_eq!(
+            app.world().get::<WebviewVisible>(webview),
+            Some(&WebviewVisible(true))
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use crate::bundle::embedding::EmbedWithin;
+use bevy::prelude::{
+    App, Component, Entity, Event, Plugin, Query, Reflect, ReflectComponent, ReflectDefault,
+    ReflectDeserialize, ReflectSerialize, Trigger,
+};
+use serde::{Deserialize, Serialize};
+
+/// The stacking order of the embedded webview within the parent window.
+///
+/// The webview with the larger value is displayed in front.
+/// If the values are the same, the webview spawned later is displayed in front.
+///
+/// [`BringToFront`] and [`SendToBack`] renumber the indices of the webviews within the same window
+/// to the consecutive values from zero while keeping their order, so that the indices don't grow on every click.
+#[repr(transparent)]
+#[derive(
+    Component,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Reflect,
+    Serialize,
+    Deserialize,
+)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct WebviewZIndex(pub i32);
+
+/// Whether to bring the embedded webview to the front when anywhere in the page is clicked.
+///
+/// Default is `false`; the webview is brought to the front only when the [`GripZone`](crate::prelude::GripZone) is grabbed.
+#[repr(transparent)]
+#[derive(
+    Component, Copy, Clone, Debug, Default, Eq, PartialEq, Reflect, Serialize, Deserialize,
+)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct RaiseOnClick(pub bool);
+
+/// Brings the embedded webview in front of the other webviews within the same window.
+///
+/// This event is sent via [`Trigger`] targeting the webview entity.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn bring_to_front(mut commands: Commands, webviews: Query<Entity, With<EmbedWithin>>) {
+///     for webview_entity in webviews.iter() {
+///         commands.entity(webview_entity).trigger(BringToFront);
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BringToFront;
+
+/// Sends the embedded webview behind the other webviews within the same window.
+///
+/// This event is sent via [`Trigger`] targeting the webview entity.
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SendToBack;
+
+/// The event is fired when the page of the embedded webview is clicked.
+///
+/// The page notifies the click with the ipc event `FLURX|webview::pointer_down`.
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub struct WebviewFocused {
+    /// The entity associated with the webview from which this event was fired.
+    pub webview_entity: Entity,
+}
+
+pub(crate) struct WebviewZIndexPlugin;
+
+impl Plugin for WebviewZIndexPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WebviewZIndex>()
+            .register_type::<RaiseOnClick>()
+            .register_type::<WebviewFocused>()
+            .add_event::<WebviewFocused>()
+            .add_observer(apply_bring_to_front)
+            .add_observer(apply_send_to_back);
+    }
+}
+
+fn apply_bring_to_front(
+    trigger: Trigger<BringToFront>,
+    mut webviews: Query<(Entity, &EmbedWithin, &mut WebviewZIndex)>,
+) {
+    let webview_entity = trigger.target();
+    let Ok((_, _, z_index)) = webviews.get(webview_entity) else {
+        return;
+    };
+    let z_index = z_index.0;
+    if siblings(webview_entity, &webviews).all(|sibling| sibling < z_index) {
+        return;
+    }
+    restack(webview_entity, true, &mut webviews);
+}
+
+fn apply_send_to_back(
+    trigger: Trigger<SendToBack>,
+    mut webviews: Query<(Entity, &EmbedWithin, &mut WebviewZIndex)>,
+) {
+    let webview_entity = trigger.target();
+    let Ok((_, _, z_index)) = webviews.get(webview_entity) else {
+        return;
+    };
+    let z_index = z_index.0;
+    if siblings(webview_entity, &webviews).all(|sibling| z_index < sibling) {
+        return;
+    }
+    restack(webview_entity, false, &mut webviews);
+}
+
+/// Renumbers the z-indices of the webviews within the same window to the consecutive values from zero,
+/// and places the webview at the front or the back.
+///
+/// The webviews with the same value keep the same value, so that their order by spawn is kept.
+fn restack(
+    webview_entity: Entity,
+    front: bool,
+    webviews: &mut Query<(Entity, &EmbedWithin, &mut WebviewZIndex)>,
+) {
+    let Ok((_, parent, _)) = webviews.get(webview_entity) else {
+        return;
+    };
+    let parent = parent.0;
+    let mut ranks = siblings(webview_entity, webviews).collect::<Vec<_>>();
+    ranks.sort_unstable();
+    ranks.dedup();
+    let (target, offset) = if front {
+        (ranks.len() as i32, 0)
+    } else {
+        (0, 1)
+    };
+    for (entity, embed_within, mut z_index) in webviews.iter_mut() {
+        if embed_within.0 != parent {
+            continue;
+        }
+        let new_index = if entity == webview_entity {
+            target
+        } else {
+            let (Ok(rank) | Err(rank)) = ranks.binary_search(&z_index.0);
+            rank as i32 + offset
+        };
+        z_index.set_if_neq(WebviewZIndex(new_index));
+    }
+}
+
+/// Returns the z-indices of the other webviews embedded in the same window.
+fn siblings<'a>(
+    webview_entity: Entity,
+    webviews: &'a Query<(Entity, &EmbedWithin, &mut WebviewZIndex)>,
+) -> impl Iterator<Item = i32> + 'a {
+    let parent = webviews
+        .get(webview_entity)
+        .ok()
+        .map(|(_, parent, _)| parent.0);
+    webviews
+        .iter()
+        .filter(move |(entity, embed_within, _)| {
+            *entity != webview_entity && Some(embed_within.0) == parent
+        })
+        .map(|(_, _, z_index)| z_index.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::embedding::z_index::WebviewZIndexPlugin;
+    use crate::prelude::{BringToFront, EmbedWithin, SendToBack, WebviewZIndex};
+    use bevy::prelude::*;
+
+    #[test]
+    fn bring_to_front() {
+        let mut app = App::new();
+        app.add_plugins(WebviewZIndexPlugin);
+        let window = app.world_mut().spawn_empty().id();
+        let webview1 = app.world_mut().spawn(EmbedWithin(window)).id();
+        let webview2 = app
+            .world_mut()
+            .spawn((EmbedWithin(window), WebviewZIndex(3)))
+            .id();
+
+        app.world_mut().entity_mut(webview1).trigger(BringToFront);
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview1),
+            Some(&WebviewZIndex(1))
+        );
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview2),
+            Some(&WebviewZIndex(0))
+        );
+    }
+
+    #[test]
+    fn not_grow_z_index_while_raising_alternately() {
+        let mut app = App::new();
+        app.add_plugins(WebviewZIndexPlugin);
+        let window = app.world_mut().spawn_empty().id();
+        let webview1 = app.world_mut().spawn(EmbedWithin(window)).id();
+        let webview2 = app.world_mut().spawn(EmbedWithin(window)).id();
+
+        for _ in 0..10 {
+            app.world_mut().entity_mut(webview1).trigger(BringToFront);
+            app.world_mut().entity_mut(webview2).trigger(BringToFront);
+        }
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview1),
+            Some(&WebviewZIndex(0))
+        );
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview2),
+            Some(&WebviewZIndex(1))
+        );
+    }
+
+    #[test]
+    fn not_change_if_already_front() {
+        let mut app = App::new();
+        app.add_plugins(WebviewZIndexPlugin);
+        let window = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn(EmbedWithin(window));
+        let webview2 = app
+            .world_mut()
+            .spawn((EmbedWithin(window), WebviewZIndex(5)))
+            .id();
+        app.world_mut().flush();
+        app.world_mut().clear_trackers();
+
+        app.world_mut().entity_mut(webview2).trigger(BringToFront);
+        let z_index = app
+            .world()
+            .entity(webview2)
+            .get_ref::<WebviewZIndex>()
+            .unwrap();
+        assert_eq!(*z_index, WebviewZIndex(5));
+        assert!(!z_index.is_changed());
+    }
+
+    #[test]
+    fn send_to_back() {
+        let mut app = App::new();
+        app.add_plugins(WebviewZIndexPlugin);
+        let window = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn(EmbedWithin(window));
+        let webview2 = app
+            .world_mut()
+            .spawn((EmbedWithin(window), WebviewZIndex(3)))
+            .id();
+
+        app.world_mut().entity_mut(webview2).trigger(SendToBack);
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview2),
+            Some(&WebviewZIndex(0))
+        );
+    }
+
+    #[test]
+    fn ignore_webviews_in_other_windows() {
+        let mut app = App::new();
+        app.add_plugins(WebviewZIndexPlugin);
+        let window1 = app.world_mut().spawn_empty().id();
+        let window2 = app.world_mut().spawn_empty().id();
+        let webview1 = app.world_mut().spawn(EmbedWithin(window1)).id();
+        app.world_mut()
+            .spawn((EmbedWithin(window2), WebviewZIndex(3)));
+
+        app.world_mut().entity_mut(webview1).trigger(BringToFront);
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview1),
+            Some(&WebviewZIndex(0))
+        );
+    }
+}
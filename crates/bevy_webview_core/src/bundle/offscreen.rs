@@ -0,0 +1,157 @@
+//! Declares the components which render the webview into an [`Image`] instead of a window.
+
+use bevy::asset::Handle;
+use bevy::image::Image;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::Key;
+use bevy::math::{UVec2, Vec2};
+use bevy::prelude::{
+    Component, Entity, Event, MouseButton, Reflect, ReflectComponent, ReflectDefault,
+};
+
+/// Renders the page frames into [`WebviewImage`] instead of displaying the native view.
+///
+/// The image can be used as a texture on a 3D mesh or in [`ImageNode`](bevy::prelude::ImageNode).
+/// Frames are copied from the backend on the CPU, so this mode works without a GPU.
+///
+/// Only backends whose [`WebviewBackend::supports_offscreen`](crate::prelude::WebviewBackend::supports_offscreen)
+/// returns `true` render frames; otherwise an error is logged and `WebviewImage` stays blank.
+/// `bevy_webview_wry` supports it on Linux, macOS and Windows with its `offscreen` feature flag,
+/// where the frames are captured with the native snapshot apis and lag a few frames behind the page.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_offscreen_webview(mut commands: Commands) {
+///     commands.spawn((
+///         Webview::default(),
+///         Offscreen::new(UVec2::new(800, 600)),
+///     ));
+/// }
+///
+/// fn show_in_ui(
+///     mut commands: Commands,
+///     webviews: Query<&WebviewImage, Added<WebviewImage>>,
+/// ) {
+///     for image in webviews.iter() {
+///         commands.spawn(ImageNode::new(image.0.clone()));
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct Offscreen {
+    /// The size of the page in physical pixels.
+    ///
+    /// The backend creates the page with this size, and [`WebviewImage`] is created with it.
+    /// The image is resized to the size of the frames rendered by the backend.
+    pub size: UVec2,
+
+    /// When the page frames are copied into [`WebviewImage`].
+    pub capture: OffscreenCapture,
+}
+
+impl Offscreen {
+    /// Creates a new [`Offscreen`] that captures the page every frame.
+    pub const fn new(size: UVec2) -> Self {
+        Self {
+            size,
+            capture: OffscreenCapture::EveryFrame,
+        }
+    }
+}
+
+impl Default for Offscreen {
+    fn default() -> Self {
+        Self::new(UVec2::new(800, 600))
+    }
+}
+
+/// Represents when the page frames are copied into [`WebviewImage`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Reflect)]
+#[reflect(Default)]
+pub enum OffscreenCapture {
+    /// Copies the latest frame every frame.
+    #[default]
+    EveryFrame,
+
+    /// Copies a snapshot only when [`CaptureOffscreenFrame`] is triggered.
+    OnDemand,
+}
+
+/// The image into which the page frames of the [`Offscreen`] webview are rendered.
+///
+/// This component is inserted automatically after [`Offscreen`] is spawned.
+#[repr(transparent)]
+#[derive(Component, Clone, Debug, Eq, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct WebviewImage(pub Handle<Image>);
+
+/// Copies the current frame of the [`Offscreen`] webview into [`WebviewImage`].
+///
+/// This event is sent via [`Trigger`](bevy::prelude::Trigger) targeting the webview entity.
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CaptureOffscreenFrame;
+
+/// Marks the entity that displays [`WebviewImage`] of the webview.
+///
+/// If picking is enabled, the pointer events on this entity are forwarded into the page as [`OffscreenInput`].
+/// The entity is assumed to be a [`Rectangle`](bevy::math::primitives::Rectangle) mesh in its local XY plane.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct OffscreenSurface {
+    /// The [`Offscreen`] webview entity.
+    pub webview: Entity,
+
+    /// The size of the rectangle mesh in local units.
+    pub size: Vec2,
+}
+
+/// Marker component indicating that the keyboard input is forwarded into the [`Offscreen`] webview.
+///
+/// It is moved to the webview when its [`OffscreenSurface`] is pressed.
+#[derive(Component, Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct OffscreenKeyboardFocus;
+
+/// The input forwarded into the page of the [`Offscreen`] webview.
+///
+/// This event is sent via [`Trigger`](bevy::prelude::Trigger) targeting the webview entity.
+/// All positions are in the pixels of [`Offscreen::size`] relative to the top left of the page.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub enum OffscreenInput {
+    /// The cursor has moved in the page.
+    CursorMoved {
+        /// The cursor position.
+        position: Vec2,
+    },
+
+    /// The mouse button has been pressed or released.
+    MouseButton {
+        /// The mouse button.
+        button: MouseButton,
+        /// Whether the button has been pressed or released.
+        state: ButtonState,
+        /// The cursor position.
+        position: Vec2,
+    },
+
+    /// The mouse wheel has been scrolled.
+    Wheel {
+        /// The scroll amount in pixels.
+        delta: Vec2,
+        /// The cursor position.
+        position: Vec2,
+    },
+
+    /// The key has been pressed or released.
+    Keyboard {
+        /// The logical key.
+        key: Key,
+        /// Whether the key has been pressed or released.
+        state: ButtonState,
+    },
+}
@@ -5,38 +5,47 @@ pub use background::Background;
 use bevy::prelude::Bundle;
 use bevy_flurx_ipc::component::IpcHandlers;
 pub use browser_accelerator_keys::BrowserAcceleratorKeys;
+pub(crate) use click_through::WebviewClickThroughPlugin;
+pub use click_through::{ClickThrough, CursorHitTest, InteractiveRegions};
 pub use csp::Csp;
 pub use enable_clipboard::EnableClipboard;
 pub use focused::InitializeFocused;
+pub use forward_input::ForwardInput;
 pub use handler::*;
 pub use hotkeys_zoom::HotkeysZoom;
 pub use https_scheme::UseHttpsScheme;
 pub use incognito::Incognito;
 pub use initialization_script::InitializationScripts;
 pub use is_open_devtools::IsOpenDevtools;
+pub use reload::ReloadWebview;
 pub use theme::Theme;
 pub use use_devtools::UseDevtools;
 pub use user_agent::UserAgent;
 pub use visible::WebviewVisible;
 pub use webview_uri::*;
+pub use zoom::WebviewZoom;
 
 mod auto_play;
 mod background;
 mod browser_accelerator_keys;
+mod click_through;
 mod csp;
 mod enable_clipboard;
 mod focused;
+mod forward_input;
 mod handler;
 mod hotkeys_zoom;
 mod https_scheme;
 mod incognito;
 mod initialization_script;
 mod is_open_devtools;
+mod reload;
 mod theme;
 mod use_devtools;
 mod user_agent;
 mod visible;
 mod webview_uri;
+mod zoom;
 
 /// The following is a list of required components for generating a webview.
 ///
@@ -82,6 +91,9 @@ pub struct WebViewBundle {
     /// [`HotkeysZoom`]
     pub hotkeys_zoom: HotkeysZoom,
 
+    /// [`WebviewZoom`]
+    pub zoom: WebviewZoom,
+
     /// [`UseHttpsScheme`]
     pub use_https_scheme: UseHttpsScheme,
 
@@ -0,0 +1,242 @@
+use crate::prelude::{Bounds, EmbedWithin};
+use bevy::math::{Rect, Vec2};
+use bevy::prelude::{
+    App, Component, Entity, EventReader, Plugin, PreUpdate, Query, Reflect, ReflectComponent,
+    ReflectDefault, ReflectDeserialize, ReflectSerialize,
+};
+use bevy::window::CursorMoved;
+use serde::{Deserialize, Serialize};
+
+/// Lets the mouse events pass through the transparent areas of the webview to the window underneath.
+///
+/// While enabled, only the hit areas of the page receive the mouse events, and everywhere else is treated as transparent.
+/// This is useful for the overlay webviews such as HUDs that cover the game.
+///
+/// Default is [`ClickThrough::InteractiveElements`]; add this component to enable the hit testing.
+///
+/// ## Limitations
+///
+/// - The pixels themselves are not read; [`ClickThrough::PaintedAreas`] approximates the transparent areas from the boxes and texts the page paints.
+/// - It depends on the backend whether the mouse events can pass through.
+///   `bevy_webview_wry` supports it on Linux(X11) and Windows, and on macOS only for the webviews without [`EmbedWithin`].
+///   On Linux, the webview without [`EmbedWithin`] must be spawned with this component,
+///   because it is hosted in its own child window only in that case.
+///
+/// ```html
+/// <div data-flurx-interactive>
+///     <button>Menu</button>
+/// </div>
+/// ```
+#[derive(
+    Component, Copy, Clone, Debug, Default, Eq, PartialEq, Reflect, Serialize, Deserialize,
+)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+#[require(InteractiveRegions, CursorHitTest)]
+pub enum ClickThrough {
+    /// The whole webview receives the mouse events.
+    Disabled,
+
+    /// Only the areas of the elements with `data-flurx-interactive` attribute receive the mouse events.
+    #[default]
+    InteractiveElements,
+
+    /// The areas where the page paints a background, a border, a shadow, a text or a replaced element such as `<img>`
+    /// receive the mouse events in addition to the elements with `data-flurx-interactive` attribute.
+    ///
+    /// It is useful with [`Background::Transparent`](crate::prelude::Background::Transparent),
+    /// where the areas painting nothing are the transparent pixels.
+    PaintedAreas,
+}
+
+impl ClickThrough {
+    /// Returns the mode passed to the page.
+    pub(crate) const fn as_js(&self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::InteractiveElements => "interactive",
+            Self::PaintedAreas => "painted",
+        }
+    }
+}
+
+/// The hit areas of the page.
+///
+/// The page reports them with the ipc event `FLURX|webview::interactive_regions`
+/// whenever the layout changes.
+/// Each rect is in logical pixels relative to the top-left of the webview.
+#[repr(transparent)]
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct InteractiveRegions(pub Vec<Rect>);
+
+impl InteractiveRegions {
+    /// Returns whether the point is inside any of the regions.
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.0.iter().any(|rect| rect.contains(point))
+    }
+}
+
+/// Whether the webview currently receives the mouse events.
+///
+/// While [`ClickThrough`] is enabled, the page reports when the cursor leaves the hit areas,
+/// and the window underneath turns it on again when the cursor enters [`InteractiveRegions`],
+/// since each of them receives the mouse events only while the other doesn't.
+/// The backend passes the mouse events through to the window underneath if it is `false`.
+///
+/// Default is `true`.
+#[repr(transparent)]
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct CursorHitTest(pub bool);
+
+impl Default for CursorHitTest {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+pub(crate) struct WebviewClickThroughPlugin;
+
+impl Plugin for WebviewClickThroughPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ClickThrough>()
+            .register_type::<InteractiveRegions>()
+            .register_type::<CursorHitTest>()
+            .add_event::<CursorMoved>()
+            .add_systems(PreUpdate, update_cursor_hit_test);
+    }
+}
+
+/// Turns on the hit test when the cursor moved over the window enters [`InteractiveRegions`].
+///
+/// [`Window::cursor_position`](bevy::prelude::Window::cursor_position) is not used
+/// because it stops updating while the webview receives the mouse events.
+fn update_cursor_hit_test(
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut webviews: Query<(
+        Entity,
+        &ClickThrough,
+        &InteractiveRegions,
+        &mut CursorHitTest,
+        Option<&EmbedWithin>,
+        Option<&Bounds>,
+    )>,
+) {
+    let cursor_moved = cursor_moved.read().collect::<Vec<_>>();
+    for (webview_entity, click_through, regions, mut hit_test, embed_within, bounds) in
+        webviews.iter_mut()
+    {
+        if *click_through == ClickThrough::Disabled {
+            hit_test.set_if_neq(CursorHitTest(true));
+            continue;
+        }
+        // The webview spawned with the window covers the whole window.
+        let (window_entity, origin) = match embed_within {
+            Some(parent) => (parent.0, bounds.map(|b| b.position).unwrap_or_default()),
+            None => (webview_entity, Vec2::ZERO),
+        };
+        // While the cursor is outside the window, keeps the last state.
+        let Some(cursor) = cursor_moved
+            .iter()
+            .rev()
+            .find(|moved| moved.window == window_entity)
+        else {
+            continue;
+        };
+        hit_test.set_if_neq(CursorHitTest(regions.contains(cursor.position - origin)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::webview::click_through::WebviewClickThroughPlugin;
+    use crate::prelude::{Bounds, ClickThrough, CursorHitTest, EmbedWithin, InteractiveRegions};
+    use bevy::prelude::*;
+    use bevy::window::CursorMoved;
+
+    fn move_cursor(app: &mut App, window: Entity, position: Vec2) {
+        app.world_mut().send_event(CursorMoved {
+            window,
+            position,
+            delta: None,
+        });
+    }
+
+    #[test]
+    fn pass_through_outside_regions() {
+        let mut app = App::new();
+        app.add_plugins(WebviewClickThroughPlugin);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let webview = app
+            .world_mut()
+            .spawn((
+                EmbedWithin(window),
+                Bounds {
+                    position: Vec2::new(100., 100.),
+                    size: Vec2::new(300., 300.),
+                    ..default()
+                },
+                ClickThrough::InteractiveElements,
+                InteractiveRegions(vec![Rect::new(0., 0., 20., 20.)]),
+            ))
+            .id();
+
+        move_cursor(&mut app, window, Vec2::new(150., 150.));
+        app.update();
+        assert_eq!(
+            app.world().get::<CursorHitTest>(webview),
+            Some(&CursorHitTest(false))
+        );
+
+        move_cursor(&mut app, window, Vec2::new(110., 110.));
+        app.update();
+        assert_eq!(
+            app.world().get::<CursorHitTest>(webview),
+            Some(&CursorHitTest(true))
+        );
+    }
+
+    #[test]
+    fn keep_hit_test_while_cursor_not_moved_over_window() {
+        let mut app = App::new();
+        app.add_plugins(WebviewClickThroughPlugin);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let webview = app
+            .world_mut()
+            .spawn((
+                EmbedWithin(window),
+                ClickThrough::InteractiveElements,
+                InteractiveRegions(vec![Rect::new(0., 0., 20., 20.)]),
+                CursorHitTest(false),
+            ))
+            .id();
+
+        let other_window = app.world_mut().spawn(Window::default()).id();
+        move_cursor(&mut app, other_window, Vec2::new(10., 10.));
+        app.update();
+        assert_eq!(
+            app.world().get::<CursorHitTest>(webview),
+            Some(&CursorHitTest(false))
+        );
+    }
+
+    #[test]
+    fn always_hit_if_disabled() {
+        let mut app = App::new();
+        app.add_plugins(WebviewClickThroughPlugin);
+        let webview = app
+            .world_mut()
+            .spawn((
+                Window::default(),
+                ClickThrough::Disabled,
+                CursorHitTest(false),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<CursorHitTest>(webview),
+            Some(&CursorHitTest(true))
+        );
+    }
+}
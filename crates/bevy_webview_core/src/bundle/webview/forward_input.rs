@@ -0,0 +1,88 @@
+use bevy::input::keyboard::KeyCode;
+use bevy::prelude::{Component, Reflect, ReflectComponent, ReflectDefault};
+
+/// Re-emits the input that the page didn't handle into bevy's input events.
+///
+/// While the webview has focus, bevy doesn't receive [`KeyboardInput`](bevy::input::keyboard::KeyboardInput)
+/// and [`MouseWheel`](bevy::input::mouse::MouseWheel), so the global hotkeys of the game don't work.
+/// With this component, the key and wheel events on which the page didn't call `preventDefault` are forwarded to bevy,
+/// so [`ButtonInput<KeyCode>`](bevy::prelude::ButtonInput) is also updated.
+/// The keys typed into `<input>`, `<textarea>`, `<select>` or `contenteditable` elements are treated as handled by the page,
+/// so that typing in a text field doesn't trigger the hotkeys of the game.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn spawn_webview(mut commands: Commands, window: Query<Entity, With<Window>>) {
+///     commands.spawn((
+///         Webview::default(),
+///         EmbedWithin(window.single().unwrap()),
+///         ForwardInput {
+///             always_forward: vec![KeyCode::Escape, KeyCode::F12],
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Eq, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct ForwardInput {
+    /// Whether to forward the key events.
+    ///
+    /// Default is `true`.
+    pub keyboard: bool,
+
+    /// Whether to forward the wheel events.
+    ///
+    /// Default is `true`.
+    pub wheel: bool,
+
+    /// The keys that are always forwarded even if the page handled them or they are typed into a text field.
+    ///
+    /// They are forwarded even if [`ForwardInput::keyboard`] is `false`.
+    pub always_forward: Vec<KeyCode>,
+}
+
+impl Default for ForwardInput {
+    fn default() -> Self {
+        Self {
+            keyboard: true,
+            wheel: true,
+            always_forward: Vec::new(),
+        }
+    }
+}
+
+impl ForwardInput {
+    /// Returns whether the key event should be forwarded.
+    ///
+    /// `handled` is whether the page called `preventDefault` on the event.
+    pub fn should_forward_key(&self, key_code: KeyCode, handled: bool) -> bool {
+        self.always_forward.contains(&key_code) || (self.keyboard && !handled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::ForwardInput;
+    use bevy::prelude::*;
+
+    #[test]
+    fn forward_unhandled_keys() {
+        let forward = ForwardInput::default();
+        assert!(forward.should_forward_key(KeyCode::KeyP, false));
+        assert!(!forward.should_forward_key(KeyCode::KeyP, true));
+    }
+
+    #[test]
+    fn always_forward_allowed_keys() {
+        let forward = ForwardInput {
+            keyboard: false,
+            always_forward: vec![KeyCode::Escape],
+            ..default()
+        };
+        assert!(forward.should_forward_key(KeyCode::Escape, true));
+        assert!(!forward.should_forward_key(KeyCode::KeyP, false));
+    }
+}
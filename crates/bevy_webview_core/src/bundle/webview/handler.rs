@@ -3,11 +3,13 @@ pub use on_download::*;
 pub use on_dragdrop::*;
 pub use on_navigation::*;
 pub use on_new_window_request::*;
+pub use page_load::*;
 
 mod on_download;
 mod on_dragdrop;
 mod on_navigation;
 mod on_new_window_request;
+mod page_load;
 
 /// Represents the url that is passed to an event handlers such as [`OnNewWindowRequest`].
 ///
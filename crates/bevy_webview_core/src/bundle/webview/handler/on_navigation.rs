@@ -1,5 +1,5 @@
 use super::PassedUrl;
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Entity, Event, Reflect};
 
 pub(crate) type BoxedNavigateHandler = Box<dyn Fn(PassedUrl) -> bool + Send + Sync + 'static>;
 
@@ -26,3 +26,13 @@ impl OnNavigation {
         self.0.take()
     }
 }
+
+/// The event is fired when [`OnNavigation`] returns `true`.
+#[derive(Clone, Debug, Event, Reflect)]
+pub struct Navigated {
+    /// The entity associated with the webview from which this event was fired.
+    pub webview_entity: Entity,
+
+    /// URL of the navigation destination
+    pub uri: PassedUrl,
+}
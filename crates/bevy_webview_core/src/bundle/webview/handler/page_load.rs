@@ -0,0 +1,22 @@
+use super::PassedUrl;
+use bevy::prelude::{Entity, Event, Reflect};
+
+/// Indicates that the content of the page has started loading
+#[derive(Event, Clone, Debug, Reflect)]
+pub struct PageLoadStarted {
+    /// The entity associated with the webview from which this event was fired.
+    pub webview_entity: Entity,
+
+    /// The url of the page to be loaded.
+    pub url: PassedUrl,
+}
+
+/// Indicates that the content of the page has finished loading
+#[derive(Event, Clone, Debug, Reflect)]
+pub struct PageLoadFinished {
+    /// The entity associated with the webview from which this event was fired.
+    pub webview_entity: Entity,
+
+    /// The url of the loaded page.
+    pub url: PassedUrl,
+}
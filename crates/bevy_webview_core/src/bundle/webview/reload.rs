@@ -0,0 +1,18 @@
+use bevy::prelude::Event;
+
+/// Reloads the current page of the webview.
+///
+/// This event is sent via [`Trigger`](bevy::prelude::Trigger) targeting the webview entity.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_webview_core::prelude::*;
+///
+/// fn reload(mut commands: Commands, webviews: Query<Entity, With<Webview>>) {
+///     for webview_entity in webviews.iter() {
+///         commands.entity(webview_entity).trigger(ReloadWebview);
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ReloadWebview;
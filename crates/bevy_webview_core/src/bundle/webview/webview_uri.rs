@@ -46,6 +46,7 @@ use std::path::{Path, PathBuf};
     InitializationScripts,
     Incognito,
     HotkeysZoom,
+    WebviewZoom,
     UseHttpsScheme,
     IpcHandlers,
     OnDownload,
@@ -0,0 +1,17 @@
+use bevy::prelude::{Component, ReflectComponent, ReflectDeserialize, ReflectSerialize};
+use bevy::prelude::{Reflect, ReflectDefault};
+use serde::{Deserialize, Serialize};
+
+/// The zoom level of the page.
+///
+/// Default is `1.0`.
+#[repr(transparent)]
+#[derive(Component, Copy, Clone, Debug, Reflect, PartialEq, Serialize, Deserialize)]
+#[reflect(Component, Default, Serialize, Deserialize)]
+pub struct WebviewZoom(pub f64);
+
+impl Default for WebviewZoom {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
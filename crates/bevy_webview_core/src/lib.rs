@@ -2,10 +2,11 @@
 //!
 //! This crate is a part of [`bevy_webview_projects`](https://github.com/not-elm/bevy_webview_projects).
 
+pub mod backend;
 /// Defines the core webview components.
 pub mod bundle;
 
 #[allow(missing_docs)]
 pub mod prelude {
-    pub use crate::bundle::prelude::*;
+    pub use crate::{backend::prelude::*, bundle::prelude::*};
 }
@@ -0,0 +1,1294 @@
+//! Provides the mock webview backend that simulates a page without a display.
+//!
+//! It allows you to test ipc commands and events end to end with [`MinimalPlugins`](bevy::MinimalPlugins).
+//!
+//! This crate is a part of [`bevy_webview_projects`](https://github.com/not-elm/bevy_webview_projects).
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_webview_mock::prelude::*;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((MinimalPlugins, MockWebviewPlugin));
+//! let webview_entity = app.world_mut().spawn(Webview::default()).id();
+//! app.update();
+//!
+//! let resolve_id = app.invoke_from_page(webview_entity, "command_id", None::<()>);
+//! app.update();
+//! let output = app.mock_page(webview_entity).and_then(|page| page.resolved_output(resolve_id));
+//! ```
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_flurx_ipc::FlurxIpcPlugin;
+use bevy_flurx_ipc::prelude::{
+    IpcCommand, IpcCommands, IpcTriggerMessage, IpcTriggerSender, Payload,
+};
+use bevy_webview_core::backend::{BackendResult, WebviewBackend, WebviewBackendPlugin};
+use bevy_webview_core::bundle::WebViewBundlesPlugin;
+use bevy_webview_core::prelude::{
+    Bounds, ExportPdf, Navigated, OnNavigation, PageLoadFinished, PageLoadStarted, PassedUrl,
+    PrintCompletion, ScreenshotCompletion, Webview, WebviewInitialized, WebviewVisible,
+};
+#[cfg(feature = "offscreen")]
+use bevy_webview_core::prelude::{OffscreenFrame, OffscreenInput};
+use serde::Serialize;
+
+#[allow(missing_docs)]
+pub mod prelude {
+    pub use crate::{
+        EmittedEvent, MockPage, MockWebviewExt, MockWebviewPlugin, MockWebviews, ResolvedIpc,
+    };
+    pub use bevy_flurx_ipc::prelude::*;
+    pub use bevy_webview_core::prelude::*;
+}
+
+/// The url of the page loaded with [`Webview::Html`].
+const HTML_PAGE_URL: &str = "about:blank";
+
+/// Drives the webviews with [`MockWebviews`] instead of the actual webview engine.
+///
+/// The webview is treated as loaded in the frame after [`Webview`] is spawned,
+/// and then [`PageLoadStarted`] and [`PageLoadFinished`] are fired.
+pub struct MockWebviewPlugin;
+
+impl Plugin for MockWebviewPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FlurxIpcPlugin>() {
+            app.add_plugins(FlurxIpcPlugin);
+        }
+        if !app.is_plugin_added::<WebViewBundlesPlugin>() {
+            app.add_plugins(WebViewBundlesPlugin);
+        }
+
+        app.init_non_send_resource::<MockWebviews>()
+            .add_plugins(WebviewBackendPlugin::<MockWebviews>::default())
+            .add_systems(PreUpdate, load_mock_webviews);
+    }
+}
+
+/// A hashmap that manages the simulated pages.
+///
+/// [`World`] holds this as [`NonSend`] as well as the actual webview backends.
+#[derive(Default)]
+pub struct MockWebviews {
+    pages: HashMap<Entity, MockPage>,
+    next_resolve_id: usize,
+}
+
+impl MockWebviews {
+    /// Returns the page associated with the webview entity.
+    #[inline]
+    pub fn get(&self, webview_entity: Entity) -> Option<&MockPage> {
+        self.pages.get(&webview_entity)
+    }
+
+    /// Returns the mutable page associated with the webview entity.
+    #[inline]
+    pub fn get_mut(&mut self, webview_entity: Entity) -> Option<&mut MockPage> {
+        self.pages.get_mut(&webview_entity)
+    }
+}
+
+impl WebviewBackend for MockWebviews {
+    fn load(&mut self, webview_entity: Entity, webview: &Webview) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.url = page_url(webview);
+        }
+        Ok(())
+    }
+
+    fn reload(&mut self, webview_entity: Entity) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.reload_count += 1;
+        }
+        Ok(())
+    }
+
+    fn set_bounds(&mut self, webview_entity: Entity, bounds: &Bounds) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.bounds = Some(*bounds);
+        }
+        Ok(())
+    }
+
+    fn set_visible(&mut self, webview_entity: Entity, visible: bool) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.visible = visible;
+        }
+        Ok(())
+    }
+
+    fn set_zoom(&mut self, webview_entity: Entity, scale_factor: f64) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.zoom = scale_factor;
+        }
+        Ok(())
+    }
+
+    fn set_cursor_hit_test(&mut self, webview_entity: Entity, hit_test: bool) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.cursor_hit_test = hit_test;
+        }
+        Ok(())
+    }
+
+    fn open_devtools(&mut self, webview_entity: Entity) {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.devtools_open = true;
+        }
+    }
+
+    fn close_devtools(&mut self, webview_entity: Entity) {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.devtools_open = false;
+        }
+    }
+
+    fn is_devtools_open(&self, webview_entity: Entity) -> Option<bool> {
+        self.pages
+            .get(&webview_entity)
+            .map(|page| page.devtools_open)
+    }
+
+    fn export_pdf(
+        &mut self,
+        webview_entity: Entity,
+        options: &ExportPdf,
+        completion: PrintCompletion,
+    ) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.exported_pdfs.push(options.clone());
+            completion.complete(true);
+        }
+        Ok(())
+    }
+
+    fn take_screenshot(
+        &mut self,
+        webview_entity: Entity,
+        completion: ScreenshotCompletion,
+    ) -> BackendResult {
+        let Some(page) = self.pages.get_mut(&webview_entity) else {
+            return Ok(());
+        };
+        if let Some(png) = page.screenshot.take() {
+            completion.complete(Ok(png));
+        } else {
+            page.evaluated_scripts.push(completion.dom_script());
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "offscreen")]
+    fn supports_offscreen(&self) -> bool {
+        true
+    }
+
+    #[cfg(feature = "offscreen")]
+    fn capture_frame(&mut self, webview_entity: Entity) -> BackendResult<Option<OffscreenFrame>> {
+        Ok(self
+            .pages
+            .get_mut(&webview_entity)
+            .and_then(|page| page.frame.take()))
+    }
+
+    #[cfg(feature = "offscreen")]
+    fn send_offscreen_input(
+        &mut self,
+        webview_entity: Entity,
+        input: &OffscreenInput,
+    ) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.offscreen_inputs.push(input.clone());
+        }
+        Ok(())
+    }
+
+    fn evaluate_script(&mut self, webview_entity: Entity, script: &str) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.evaluated_scripts.push(script.to_string());
+        }
+        Ok(())
+    }
+
+    fn resolve_ipc(
+        &mut self,
+        webview_entity: Entity,
+        resolve_id: usize,
+        output: &str,
+    ) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.resolved.push(ResolvedIpc {
+                resolve_id,
+                output: output.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn emit_event(
+        &mut self,
+        webview_entity: Entity,
+        _: &str,
+        event_id: &str,
+        payload: &str,
+    ) -> BackendResult {
+        if let Some(page) = self.pages.get_mut(&webview_entity) {
+            page.emitted.push(EmittedEvent {
+                event_id: event_id.to_string(),
+                payload: payload.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The simulated page loaded in the mock webview.
+///
+/// It captures all calls from bevy to javascript.
+pub struct MockPage {
+    url: String,
+    evaluated_scripts: Vec<String>,
+    resolved: Vec<ResolvedIpc>,
+    emitted: Vec<EmittedEvent>,
+    on_navigation: Box<dyn Fn(PassedUrl) -> bool + Send + Sync>,
+    bounds: Option<Bounds>,
+    visible: bool,
+    zoom: f64,
+    cursor_hit_test: bool,
+    devtools_open: bool,
+    reload_count: usize,
+    screenshot: Option<Vec<u8>>,
+    exported_pdfs: Vec<ExportPdf>,
+    #[cfg(feature = "offscreen")]
+    frame: Option<OffscreenFrame>,
+    #[cfg(feature = "offscreen")]
+    offscreen_inputs: Vec<OffscreenInput>,
+}
+
+impl MockPage {
+    /// Returns the url of the current page.
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the scripts evaluated in this page except for ipc resolutions and events.
+    #[inline]
+    pub fn evaluated_scripts(&self) -> &[String] {
+        &self.evaluated_scripts
+    }
+
+    /// Returns the ipc commands resolved by `window.__FLURX__.__resolveIpc`.
+    #[inline]
+    pub fn resolved(&self) -> &[ResolvedIpc] {
+        &self.resolved
+    }
+
+    /// Returns the serialized output of the ipc command if it has been resolved.
+    pub fn resolved_output(&self, resolve_id: usize) -> Option<&str> {
+        self.resolved
+            .iter()
+            .find(|resolved| resolved.resolve_id == resolve_id)
+            .map(|resolved| resolved.output.as_str())
+    }
+
+    /// Returns the events emitted by `window.__FLURX__.__emitEvent`.
+    #[inline]
+    pub fn emitted(&self) -> &[EmittedEvent] {
+        &self.emitted
+    }
+
+    /// Returns the display area applied to the page.
+    ///
+    /// Returns `None` if the webview has no [`Bounds`].
+    #[inline]
+    pub fn bounds(&self) -> Option<&Bounds> {
+        self.bounds.as_ref()
+    }
+
+    /// Returns whether the page is visible.
+    #[inline]
+    pub const fn visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Returns the zoom level of the page.
+    #[inline]
+    pub const fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Returns whether the page receives the mouse events.
+    #[inline]
+    pub const fn cursor_hit_test(&self) -> bool {
+        self.cursor_hit_test
+    }
+
+    /// Returns whether the devtools is opened.
+    #[inline]
+    pub const fn is_devtools_open(&self) -> bool {
+        self.devtools_open
+    }
+
+    /// Returns how many times the page has been reloaded.
+    #[inline]
+    pub const fn reload_count(&self) -> usize {
+        self.reload_count
+    }
+
+    /// Returns the PDF export requests.
+    ///
+    /// The mock backend does not write the PDF files.
+    #[inline]
+    pub fn exported_pdfs(&self) -> &[ExportPdf] {
+        &self.exported_pdfs
+    }
+
+    /// Sets the PNG returned natively on the next [`TakeScreenshot`](bevy_webview_core::prelude::TakeScreenshot).
+    ///
+    /// If it is not set, the screenshot is requested to the page as the engine can't snapshot.
+    pub fn set_screenshot(&mut self, png: Vec<u8>) {
+        self.screenshot.replace(png);
+    }
+
+    /// Renders the frame that is copied into [`WebviewImage`](bevy_webview_core::prelude::WebviewImage) on the next capture.
+    #[cfg(feature = "offscreen")]
+    pub fn render_frame(&mut self, frame: OffscreenFrame) {
+        self.frame.replace(frame);
+    }
+
+    /// Returns the inputs forwarded into the page.
+    #[cfg(feature = "offscreen")]
+    #[inline]
+    pub fn offscreen_inputs(&self) -> &[OffscreenInput] {
+        &self.offscreen_inputs
+    }
+}
+
+/// The ipc command resolution captured in [`MockPage`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ResolvedIpc {
+    /// The id passed when the command was invoked.
+    pub resolve_id: usize,
+
+    /// The serialized output value of the command.
+    pub output: String,
+}
+
+/// The event captured in [`MockPage`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmittedEvent {
+    /// The event id
+    pub event_id: String,
+
+    /// The serialized event body.
+    pub payload: String,
+}
+
+/// Provides the operations that simulate the page from javascript.
+pub trait MockWebviewExt {
+    /// Pushes the ipc command as if it were invoked from javascript.
+    ///
+    /// Returns the `resolve_id` to find the output with [`MockPage::resolved_output`].
+    fn invoke_from_page<Args: Serialize>(
+        &mut self,
+        webview_entity: Entity,
+        id: impl Into<String>,
+        args: Option<Args>,
+    ) -> usize;
+
+    /// Sends the ipc event as if it were emitted from javascript.
+    fn emit_from_page<P: Serialize>(
+        &mut self,
+        webview_entity: Entity,
+        event_id: impl Into<String>,
+        payload: &P,
+    );
+
+    /// Simulates the navigation to the url.
+    ///
+    /// The [`OnNavigation`] callback decides whether the navigation is allowed,
+    /// and if it is allowed, [`Navigated`] and the page load events are fired.
+    ///
+    /// Returns whether the navigation has been allowed.
+    fn navigate(&mut self, webview_entity: Entity, url: impl Into<String>) -> bool;
+
+    /// Fires [`PageLoadStarted`] and [`PageLoadFinished`] for the current page.
+    fn reload_page(&mut self, webview_entity: Entity);
+
+    /// Returns the page associated with the webview entity.
+    fn mock_page(&self, webview_entity: Entity) -> Option<&MockPage>;
+}
+
+impl MockWebviewExt for App {
+    fn invoke_from_page<Args: Serialize>(
+        &mut self,
+        webview_entity: Entity,
+        id: impl Into<String>,
+        args: Option<Args>,
+    ) -> usize {
+        let resolve_id = {
+            let mut webviews = self.world_mut().non_send_resource_mut::<MockWebviews>();
+            webviews.next_resolve_id += 1;
+            webviews.next_resolve_id
+        };
+        let args = args.map(|args| {
+            serde_json::to_string(&args).expect("Failed to serialize the ipc command args")
+        });
+        self.world().resource::<IpcCommands>().push(IpcCommand {
+            entity: webview_entity,
+            payload: Payload {
+                id: id.into(),
+                args,
+                resolve_id,
+            },
+        });
+        resolve_id
+    }
+
+    fn emit_from_page<P: Serialize>(
+        &mut self,
+        webview_entity: Entity,
+        event_id: impl Into<String>,
+        payload: &P,
+    ) {
+        self.world()
+            .resource::<IpcTriggerSender>()
+            .send(IpcTriggerMessage {
+                target: Some(webview_entity),
+                event_id: event_id.into(),
+                payload: serde_json::to_string(payload)
+                    .expect("Failed to serialize the ipc event payload"),
+            });
+    }
+
+    fn navigate(&mut self, webview_entity: Entity, url: impl Into<String>) -> bool {
+        let uri = PassedUrl(url.into());
+        {
+            let mut webviews = self.world_mut().non_send_resource_mut::<MockWebviews>();
+            let Some(page) = webviews.get_mut(webview_entity) else {
+                return false;
+            };
+            if !(page.on_navigation)(uri.clone()) {
+                return false;
+            }
+            page.url = uri.0.clone();
+        }
+        self.world_mut().send_event(Navigated {
+            webview_entity,
+            uri,
+        });
+        self.reload_page(webview_entity);
+        true
+    }
+
+    fn reload_page(&mut self, webview_entity: Entity) {
+        let Some(url) = self.mock_page(webview_entity).map(|page| page.url.clone()) else {
+            return;
+        };
+        let world = self.world_mut();
+        world.send_event(PageLoadStarted {
+            webview_entity,
+            url: PassedUrl(url.clone()),
+        });
+        world.send_event(PageLoadFinished {
+            webview_entity,
+            url: PassedUrl(url),
+        });
+    }
+
+    fn mock_page(&self, webview_entity: Entity) -> Option<&MockPage> {
+        self.world()
+            .non_send_resource::<MockWebviews>()
+            .get(webview_entity)
+    }
+}
+
+fn load_mock_webviews(
+    mut commands: Commands,
+    mut mock_webviews: NonSendMut<MockWebviews>,
+    mut views: Query<
+        (
+            Entity,
+            &Webview,
+            &WebviewVisible,
+            &mut OnNavigation,
+            Option<&Name>,
+        ),
+        Without<WebviewInitialized>,
+    >,
+    mut started: EventWriter<PageLoadStarted>,
+    mut finished: EventWriter<PageLoadFinished>,
+) {
+    for (webview_entity, webview, visible, mut on_navigation, name) in views.iter_mut() {
+        let url = page_url(webview);
+        if name.is_none() {
+            commands
+                .entity(webview_entity)
+                .insert(Name::new(format!("mock-{webview_entity}")));
+        }
+        commands
+            .entity(webview_entity)
+            .insert(WebviewInitialized(()));
+        mock_webviews.pages.insert(
+            webview_entity,
+            MockPage {
+                url: url.clone(),
+                evaluated_scripts: Vec::new(),
+                resolved: Vec::new(),
+                emitted: Vec::new(),
+                on_navigation: on_navigation.take().unwrap_or(Box::new(|_| true)),
+                bounds: None,
+                visible: visible.0,
+                zoom: 1.,
+                cursor_hit_test: true,
+                devtools_open: false,
+                reload_count: 0,
+                screenshot: None,
+                exported_pdfs: Vec::new(),
+                #[cfg(feature = "offscreen")]
+                frame: None,
+                #[cfg(feature = "offscreen")]
+                offscreen_inputs: Vec::new(),
+            },
+        );
+        started.write(PageLoadStarted {
+            webview_entity,
+            url: PassedUrl(url.clone()),
+        });
+        finished.write(PageLoadFinished {
+            webview_entity,
+            url: PassedUrl(url),
+        });
+    }
+}
+
+fn page_url(webview: &Webview) -> String {
+    match webview {
+        Webview::Uri(uri) => uri.0.clone(),
+        Webview::Html(_) => HTML_PAGE_URL.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::input::keyboard::KeyboardInput;
+    use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+    use bevy::prelude::*;
+    use bevy::window::CursorMoved;
+    use bevy_flurx::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[command]
+    fn hello(In(name): In<String>) -> Action<String, String> {
+        once::run(|In(name): In<String>| format!("hello {name}")).with(name)
+    }
+
+    #[derive(Event, Serialize, Deserialize)]
+    struct Ping {
+        count: usize,
+    }
+
+    #[derive(Resource, Default)]
+    struct PingCount(usize);
+
+    #[test]
+    fn resolve_ipc_command() {
+        let mut app = test_app();
+        let webview_entity = app
+            .world_mut()
+            .spawn((Webview::default(), IpcHandlers::new([hello])))
+            .id();
+        app.update();
+
+        let resolve_id = app.invoke_from_page(webview_entity, "hello", Some("bevy"));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(
+            app.mock_page(webview_entity)
+                .and_then(|page| page.resolved_output(resolve_id)),
+            Some("\"hello bevy\"")
+        );
+    }
+
+    #[test]
+    fn receive_event_from_page() {
+        let mut app = test_app();
+        app.init_resource::<PingCount>()
+            .add_ipc_trigger::<Ping>("ping")
+            .add_observer(|trigger: Trigger<Ping>, mut count: ResMut<PingCount>| {
+                count.0 += trigger.count;
+            });
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        app.emit_from_page(webview_entity, "ping", &Ping { count: 3 });
+        app.update();
+        assert_eq!(app.world().resource::<PingCount>().0, 3);
+    }
+
+    #[test]
+    fn capture_emitted_event() {
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .trigger(EmitIpcEvent {
+                id: "count".to_string(),
+                payload: EventPayload::new(1),
+            });
+        app.update();
+        assert_eq!(
+            app.mock_page(webview_entity).map(|page| page.emitted()),
+            Some(
+                [EmittedEvent {
+                    event_id: "count".to_string(),
+                    payload: "1".to_string(),
+                }]
+                .as_slice()
+            )
+        );
+    }
+
+    #[test]
+    fn fire_page_load_on_spawn() {
+        let mut app = test_app();
+        let webview_entity = app
+            .world_mut()
+            .spawn(Webview::Uri(WebviewUri::new("https://bevyengine.org/")))
+            .id();
+        app.update();
+
+        let finished = app.world().resource::<Events<PageLoadFinished>>();
+        let urls = finished
+            .iter_current_update_events()
+            .filter(|event| event.webview_entity == webview_entity)
+            .map(|event| event.url.0.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(urls, vec!["https://bevyengine.org/"]);
+    }
+
+    #[test]
+    fn deny_navigation() {
+        let mut app = test_app();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                OnNavigation::new(|url| !url.0.starts_with("https://deny")),
+            ))
+            .id();
+        app.update();
+
+        assert!(!app.navigate(webview_entity, "https://deny.example.com/"));
+        assert!(app.navigate(webview_entity, "https://allow.example.com/"));
+        assert_eq!(
+            app.mock_page(webview_entity).map(|page| page.url()),
+            Some("https://allow.example.com/")
+        );
+        let navigated = app.world().resource::<Events<Navigated>>();
+        assert_eq!(navigated.iter_current_update_events().count(), 1);
+    }
+
+    #[test]
+    fn apply_changed_components() {
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        app.world_mut().entity_mut(webview_entity).insert((
+            WebviewVisible(false),
+            WebviewZoom(1.5),
+            Webview::Uri(WebviewUri::new("https://bevyengine.org/")),
+        ));
+        app.update();
+        let page = app.mock_page(webview_entity).unwrap();
+        assert!(!page.visible());
+        assert_eq!(page.zoom(), 1.5);
+        assert_eq!(page.url(), "https://bevyengine.org/");
+    }
+
+    #[test]
+    fn apply_bounds_on_initialized() {
+        let mut app = test_app();
+        let bounds = Bounds {
+            position: Vec2::new(10., 20.),
+            size: Vec2::new(300., 200.),
+            ..default()
+        };
+        let webview_entity = app.world_mut().spawn((Webview::default(), bounds)).id();
+        app.update();
+
+        assert_eq!(
+            app.mock_page(webview_entity).and_then(|page| page.bounds()),
+            Some(&bounds)
+        );
+    }
+
+    #[test]
+    fn open_devtools() {
+        let mut app = test_app();
+        let webview_entity = app
+            .world_mut()
+            .spawn((Webview::default(), UseDevtools(true), IsOpenDevtools(true)))
+            .id();
+        app.update();
+        app.update();
+        assert!(app.mock_page(webview_entity).unwrap().is_devtools_open());
+
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .insert(IsOpenDevtools(false));
+        app.update();
+        assert!(!app.mock_page(webview_entity).unwrap().is_devtools_open());
+    }
+
+    #[test]
+    fn reload_webview() {
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .trigger(ReloadWebview);
+        assert_eq!(app.mock_page(webview_entity).unwrap().reload_count(), 1);
+    }
+
+    #[test]
+    fn take_screenshot_natively() {
+        #[derive(Resource)]
+        struct Captured(Result<Screenshot, String>);
+
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+        app.world_mut()
+            .non_send_resource_mut::<MockWebviews>()
+            .get_mut(webview_entity)
+            .unwrap()
+            .set_screenshot(vec![1, 2, 3]);
+
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let result = task.will(Update, take_screenshot(webview_entity)).await;
+                task.will(Update, once::res::insert().with(Captured(result)))
+                    .await;
+            }));
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().get_resource::<Captured>().map(|c| &c.0),
+            Some(&Ok(Screenshot(vec![1, 2, 3])))
+        );
+    }
+
+    #[test]
+    fn ignore_screenshot_of_previous_request() {
+        #[derive(Resource)]
+        struct Captured(Result<Screenshot, String>);
+
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+        app.world_mut()
+            .non_send_resource_mut::<MockWebviews>()
+            .get_mut(webview_entity)
+            .unwrap()
+            .set_screenshot(vec![9]);
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .trigger(TakeScreenshot::new());
+        app.world_mut()
+            .non_send_resource_mut::<MockWebviews>()
+            .get_mut(webview_entity)
+            .unwrap()
+            .set_screenshot(vec![1, 2, 3]);
+
+        app.world_mut()
+            .spawn(Reactor::schedule(move |task| async move {
+                let result = task.will(Update, take_screenshot(webview_entity)).await;
+                task.will(Update, once::res::insert().with(Captured(result)))
+                    .await;
+            }));
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().get_resource::<Captured>().map(|c| &c.0),
+            Some(&Ok(Screenshot(vec![1, 2, 3])))
+        );
+    }
+
+    #[test]
+    fn take_screenshot_in_page() {
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        let request = TakeScreenshot::new();
+        app.world_mut().entity_mut(webview_entity).trigger(request);
+        assert_eq!(
+            app.mock_page(webview_entity).unwrap().evaluated_scripts(),
+            &[format!(
+                "window.__FLURX__.__takeScreenshot({})",
+                request.request_id
+            )]
+        );
+
+        app.emit_from_page(
+            webview_entity,
+            "FLURX|webview::screenshot",
+            &serde_json::json!({
+                "request_id": request.request_id,
+                "data_url": "data:image/png;base64,AQID",
+            }),
+        );
+        app.update();
+        let captured = app.world().resource::<Events<ScreenshotCaptured>>();
+        assert_eq!(
+            captured
+                .iter_current_update_events()
+                .map(|event| event.result.clone())
+                .collect::<Vec<_>>(),
+            vec![Ok(Screenshot(vec![1, 2, 3]))]
+        );
+    }
+
+    #[test]
+    fn print_webview() {
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .trigger(PrintWebview::default());
+        app.update();
+        assert_eq!(
+            app.mock_page(webview_entity).unwrap().evaluated_scripts(),
+            &["window.print()".to_string()]
+        );
+        let completed = app.world().resource::<Events<PrintCompleted>>();
+        assert_eq!(
+            completed
+                .iter_current_update_events()
+                .map(|event| (event.output.clone(), event.succeed))
+                .collect::<Vec<_>>(),
+            vec![(PrintOutput::Printer, true)]
+        );
+    }
+
+    #[test]
+    fn export_pdf() {
+        let mut app = test_app();
+        let webview_entity = app.world_mut().spawn(Webview::default()).id();
+        app.update();
+
+        let export = ExportPdf {
+            path: "report.pdf".into(),
+            page_size: PageSize::Letter,
+            margins: PrintMargins::all(10.),
+        };
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .trigger(export.clone());
+        app.update();
+        assert_eq!(
+            app.mock_page(webview_entity).unwrap().exported_pdfs(),
+            &[export]
+        );
+        let completed = app.world().resource::<Events<PrintCompleted>>();
+        assert_eq!(
+            completed
+                .iter_current_update_events()
+                .map(|event| (event.output.clone(), event.succeed))
+                .collect::<Vec<_>>(),
+            vec![(PrintOutput::Pdf("report.pdf".into()), true)]
+        );
+    }
+
+    #[cfg(feature = "offscreen")]
+    #[test]
+    fn copy_frame_into_image() {
+        let mut app = test_app();
+        app.add_plugins(AssetPlugin::default())
+            .init_asset::<Image>();
+        let webview_entity = app
+            .world_mut()
+            .spawn((Webview::default(), Offscreen::new(UVec2::new(1, 1))))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .non_send_resource_mut::<MockWebviews>()
+            .get_mut(webview_entity)
+            .unwrap()
+            .render_frame(OffscreenFrame {
+                size: UVec2::new(2, 1),
+                data: vec![255; 8],
+            });
+        app.update();
+        let handle = app.world().get::<WebviewImage>(webview_entity).unwrap();
+        let image = app
+            .world()
+            .resource::<Assets<Image>>()
+            .get(&handle.0)
+            .unwrap();
+        assert_eq!(image.size(), UVec2::new(2, 1));
+        assert_eq!(image.data.as_deref(), Some([255; 8].as_slice()));
+    }
+
+    #[cfg(feature = "offscreen")]
+    #[test]
+    fn ignore_frame_with_wrong_data_length() {
+        let mut app = test_app();
+        app.add_plugins(AssetPlugin::default())
+            .init_asset::<Image>();
+        let webview_entity = app
+            .world_mut()
+            .spawn((Webview::default(), Offscreen::new(UVec2::new(1, 1))))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .non_send_resource_mut::<MockWebviews>()
+            .get_mut(webview_entity)
+            .unwrap()
+            .render_frame(OffscreenFrame {
+                size: UVec2::new(2, 1),
+                data: vec![255; 4],
+            });
+        app.update();
+        let handle = app.world().get::<WebviewImage>(webview_entity).unwrap();
+        let image = app
+            .world()
+            .resource::<Assets<Image>>()
+            .get(&handle.0)
+            .unwrap();
+        assert_eq!(image.size(), UVec2::new(1, 1));
+        assert_eq!(image.data.as_deref(), Some([0; 4].as_slice()));
+    }
+
+    #[cfg(feature = "offscreen")]
+    #[test]
+    fn capture_on_demand() {
+        let mut app = test_app();
+        app.add_plugins(AssetPlugin::default())
+            .init_asset::<Image>();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                Offscreen {
+                    size: UVec2::new(1, 1),
+                    capture: OffscreenCapture::OnDemand,
+                },
+            ))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .non_send_resource_mut::<MockWebviews>()
+            .get_mut(webview_entity)
+            .unwrap()
+            .render_frame(OffscreenFrame {
+                size: UVec2::new(1, 1),
+                data: vec![255; 4],
+            });
+        app.update();
+        let image_data = |app: &App| {
+            let handle = app.world().get::<WebviewImage>(webview_entity).unwrap();
+            let images = app.world().resource::<Assets<Image>>();
+            images.get(&handle.0).unwrap().data.clone()
+        };
+        assert_eq!(image_data(&app), Some(vec![0; 4]));
+
+        app.world_mut()
+            .entity_mut(webview_entity)
+            .trigger(CaptureOffscreenFrame);
+        assert_eq!(image_data(&app), Some(vec![255; 4]));
+    }
+
+    #[cfg(feature = "offscreen")]
+    #[test]
+    fn forward_keyboard_input_to_focused_webview() {
+        use bevy::input::ButtonState;
+        use bevy::input::keyboard::Key;
+
+        let mut app = test_app();
+        app.add_event::<KeyboardInput>();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                Offscreen::default(),
+                OffscreenKeyboardFocus,
+            ))
+            .id();
+        app.update();
+
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::KeyA,
+            logical_key: Key::Character("a".into()),
+            state: ButtonState::Pressed,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+        assert_eq!(
+            app.mock_page(webview_entity).unwrap().offscreen_inputs(),
+            &[OffscreenInput::Keyboard {
+                key: Key::Character("a".into()),
+                state: ButtonState::Pressed,
+            }]
+        );
+    }
+
+    #[test]
+    fn raise_on_click() {
+        let mut app = test_app();
+        let window = app.world_mut().spawn_empty().id();
+        let webview1 = app
+            .world_mut()
+            .spawn((Webview::default(), EmbedWithin(window), RaiseOnClick(true)))
+            .id();
+        app.world_mut()
+            .spawn((Webview::default(), EmbedWithin(window)));
+        app.update();
+
+        app.emit_from_page(
+            webview1,
+            "FLURX|webview::pointer_down",
+            &serde_json::json!({
+                "__FLURX__pointer_down": 0,
+            }),
+        );
+        app.update();
+        let focused = app.world().resource::<Events<WebviewFocused>>();
+        assert_eq!(
+            focused.iter_current_update_events().collect::<Vec<_>>(),
+            vec![&WebviewFocused {
+                webview_entity: webview1
+            }]
+        );
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview1),
+            Some(&WebviewZIndex(1))
+        );
+    }
+
+    #[test]
+    fn not_raise_on_click_by_default() {
+        let mut app = test_app();
+        let window = app.world_mut().spawn_empty().id();
+        let webview1 = app
+            .world_mut()
+            .spawn((Webview::default(), EmbedWithin(window)))
+            .id();
+        app.world_mut()
+            .spawn((Webview::default(), EmbedWithin(window)));
+        app.update();
+
+        app.emit_from_page(
+            webview1,
+            "FLURX|webview::pointer_down",
+            &serde_json::json!({
+                "__FLURX__pointer_down": 0,
+            }),
+        );
+        app.update();
+        assert_eq!(
+            app.world().get::<WebviewZIndex>(webview1),
+            Some(&WebviewZIndex(0))
+        );
+    }
+
+    #[test]
+    fn pass_clicks_through_transparent_areas() {
+        let mut app = test_app();
+        let window = app.world_mut().spawn(Window::default()).id();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                EmbedWithin(window),
+                ClickThrough::InteractiveElements,
+            ))
+            .id();
+        app.update();
+        let page = app.mock_page(webview_entity).unwrap();
+        assert!(
+            page.evaluated_scripts()
+                .contains(&"window.__FLURX__.clickThrough=\"interactive\";".to_string())
+        );
+
+        app.emit_from_page(
+            webview_entity,
+            "FLURX|webview::interactive_regions",
+            &serde_json::json!({
+                "rects": [[0., 0., 20., 20.]],
+            }),
+        );
+        app.update();
+        // The page reports that the cursor left the interactive areas.
+        app.emit_from_page(
+            webview_entity,
+            "FLURX|webview::hit_test",
+            &serde_json::json!({
+                "hit": false,
+            }),
+        );
+        app.update();
+        app.update();
+        assert!(!app.mock_page(webview_entity).unwrap().cursor_hit_test());
+
+        // The window receives the cursor while the mouse events pass through.
+        app.world_mut().send_event(CursorMoved {
+            window,
+            position: Vec2::new(10., 10.),
+            delta: None,
+        });
+        app.update();
+        assert!(app.mock_page(webview_entity).unwrap().cursor_hit_test());
+    }
+
+    #[test]
+    fn forward_unhandled_keys_to_bevy() {
+        let mut app = test_app();
+        let window = app.world_mut().spawn_empty().id();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                EmbedWithin(window),
+                ForwardInput {
+                    always_forward: vec![KeyCode::Escape],
+                    ..default()
+                },
+            ))
+            .id();
+        app.update();
+
+        for (code, key, handled) in [
+            ("KeyP", "p", false),
+            ("KeyA", "a", true),
+            ("Escape", "Escape", true),
+        ] {
+            app.emit_from_page(
+                webview_entity,
+                "FLURX|webview::key",
+                &serde_json::json!({
+                    "code": code,
+                    "key": key,
+                    "pressed": true,
+                    "repeat": false,
+                    "handled": handled,
+                }),
+            );
+        }
+        app.update();
+        let inputs = app.world().resource::<Events<KeyboardInput>>();
+        assert_eq!(
+            inputs
+                .iter_current_update_events()
+                .map(|input| (input.key_code, input.window))
+                .collect::<Vec<_>>(),
+            vec![(KeyCode::KeyP, window), (KeyCode::Escape, window)]
+        );
+    }
+
+    #[test]
+    fn not_forward_keys_typed_into_editable_element() {
+        let mut app = test_app();
+        let window = app.world_mut().spawn_empty().id();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                EmbedWithin(window),
+                ForwardInput {
+                    always_forward: vec![KeyCode::Escape],
+                    ..default()
+                },
+            ))
+            .id();
+        app.update();
+
+        for (code, key) in [("KeyP", "p"), ("Escape", "Escape")] {
+            app.emit_from_page(
+                webview_entity,
+                "FLURX|webview::key",
+                &serde_json::json!({
+                    "code": code,
+                    "key": key,
+                    "pressed": true,
+                    "repeat": false,
+                    "handled": false,
+                    "editable": true,
+                }),
+            );
+        }
+        app.update();
+        let inputs = app.world().resource::<Events<KeyboardInput>>();
+        assert_eq!(
+            inputs
+                .iter_current_update_events()
+                .map(|input| input.key_code)
+                .collect::<Vec<_>>(),
+            vec![KeyCode::Escape]
+        );
+    }
+
+    #[test]
+    fn forward_unhandled_wheel_to_bevy() {
+        let mut app = test_app();
+        let window = app.world_mut().spawn_empty().id();
+        let webview_entity = app
+            .world_mut()
+            .spawn((
+                Webview::default(),
+                EmbedWithin(window),
+                ForwardInput::default(),
+            ))
+            .id();
+        app.update();
+
+        app.emit_from_page(
+            webview_entity,
+            "FLURX|webview::wheel",
+            &serde_json::json!({
+                "deltaX": 0.,
+                "deltaY": 3.,
+                "deltaMode": 1,
+            }),
+        );
+        app.update();
+        let wheels = app.world().resource::<Events<MouseWheel>>();
+        assert_eq!(
+            wheels.iter_current_update_events().collect::<Vec<_>>(),
+            vec![&MouseWheel {
+                unit: MouseScrollUnit::Line,
+                x: 0.,
+                y: -3.,
+                window,
+            }]
+        );
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MockWebviewPlugin));
+        app
+    }
+}
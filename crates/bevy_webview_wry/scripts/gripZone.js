@@ -4,8 +4,35 @@
         writable: true,
         configurable: false,
     });
+    Object.defineProperty(window.__FLURX__, "dragHandleSelector", {
+        value: "[data-flurx-drag]",
+        writable: true,
+        configurable: false,
+    });
+    Object.defineProperty(window.__FLURX__, "__gripGrabbing", {
+        value: false,
+        writable: true,
+        configurable: false,
+    });
+    const INTERACTIVE_SELECTOR = "a[href], button, input, select, textarea, label, [contenteditable], [data-flurx-no-drag]";
+    const isOnDragHandle = (e) => {
+        const target = e.target instanceof Element ? e.target : null;
+        const interactive = target?.closest(INTERACTIVE_SELECTOR);
+        const handle = window.__FLURX__.dragHandleSelector ? target?.closest(window.__FLURX__.dragHandleSelector) : null;
+        if (handle) {
+            // Interactive children inside the handle keep their own behavior.
+            return !interactive || interactive === handle || interactive.contains(handle);
+        }
+        return e.clientY <= window.__FLURX__.gripZoneHeight && !interactive;
+    };
+    window.addEventListener("mousedown", () => {
+        window.__FLURX__.emit("FLURX|webview::pointer_down", {
+            __FLURX__pointer_down: 0
+        });
+    }, true);
     window.onmousedown = (e) => {
-        if (e.clientY <= window.__FLURX__.gripZoneHeight && e.button === 0) {
+        if (e.button === 0 && isOnDragHandle(e)) {
+            window.__FLURX__.__gripGrabbing = true;
             window.__FLURX__.emit("FLURX|grip::grab", {
                 x: e.clientX,
                 y: e.clientY
@@ -14,9 +41,21 @@
     };
     window.onmouseup = (e) => {
         if (e.button === 0) {
+            if (window.__FLURX__.__gripGrabbing) {
+                // Reports the remaining movement before releasing.
+                window.__FLURX__.__flushGripDrag?.();
+                window.__FLURX__.__gripGrabbing = false;
+            }
             window.__FLURX__.emit("FLURX|grip::release", {
                 __FLURX__grip_release: 0
             });
         }
     };
-})();
\ No newline at end of file
+    window.ondblclick = (e) => {
+        if (e.button === 0 && isOnDragHandle(e)) {
+            window.__FLURX__.emit("FLURX|grip::double_click", {
+                __FLURX__grip_double_click: 0
+            });
+        }
+    };
+})();
@@ -1,8 +1,35 @@
 ;(() => {
-    window.onmousemove = (e) => {
-        window.__FLURX__.emit("FLURX|grip::drag", {
-            x: e.movementX,
-            y: e.movementY,
-        });
+    // The movement is accumulated and reported at most once per animation frame,
+    // and only while the grip zone is grabbed.
+    let delta = null;
+
+    const flush = () => {
+        if (delta) {
+            window.__FLURX__.emit("FLURX|grip::drag", delta);
+            delta = null;
+        }
     };
-})();
\ No newline at end of file
+    Object.defineProperty(window.__FLURX__, "__flushGripDrag", {
+        value: flush,
+        writable: false,
+        configurable: false,
+    });
+
+    window.addEventListener("mousemove", (e) => {
+        if (!window.__FLURX__.__gripGrabbing) {
+            return;
+        }
+        if ((e.buttons & 1) === 0) {
+            // The button has been released outside the page.
+            window.__FLURX__.__gripGrabbing = false;
+            delta = null;
+            return;
+        }
+        if (!delta) {
+            delta = {x: 0, y: 0};
+            requestAnimationFrame(flush);
+        }
+        delta.x += e.movementX;
+        delta.y += e.movementY;
+    });
+})();
@@ -0,0 +1,123 @@
+;(() => {
+    const BUTTONS = [1, 4, 2];
+
+    let hovered = null;
+    let pressedTarget = null;
+    let buttons = 0;
+
+    const elementAt = (x, y) => document.elementFromPoint(x, y) ?? document.documentElement;
+
+    const mouseInit = (x, y, button) => ({
+        bubbles: true,
+        cancelable: true,
+        composed: true,
+        view: window,
+        clientX: x,
+        clientY: y,
+        screenX: x,
+        screenY: y,
+        button,
+        buttons,
+    });
+
+    const dispatchPointer = (target, type, x, y, button) => {
+        const init = mouseInit(x, y, button);
+        target.dispatchEvent(new PointerEvent(`pointer${type}`, {...init, pointerId: 1, pointerType: "mouse", isPrimary: true}));
+        return target.dispatchEvent(new MouseEvent(`mouse${type}`, init));
+    };
+
+    const hover = (target, x, y) => {
+        if (hovered === target) {
+            return;
+        }
+        if (hovered) {
+            dispatchPointer(hovered, "out", x, y, 0);
+        }
+        hovered = target;
+        dispatchPointer(target, "over", x, y, 0);
+    };
+
+    const isEditable = (element) => element instanceof HTMLInputElement
+        || element instanceof HTMLTextAreaElement
+        || element?.isContentEditable;
+
+    const scrollableAncestor = (element, dx, dy) => {
+        for (let e = element; e && e !== document.documentElement; e = e.parentElement) {
+            const style = getComputedStyle(e);
+            const scrollableY = dy !== 0 && /(auto|scroll)/.test(style.overflowY) && e.scrollHeight > e.clientHeight;
+            const scrollableX = dx !== 0 && /(auto|scroll)/.test(style.overflowX) && e.scrollWidth > e.clientWidth;
+            if (scrollableX || scrollableY) {
+                return e;
+            }
+        }
+        return document.scrollingElement ?? document.documentElement;
+    };
+
+    const onPointer = (input) => {
+        // The positions are in the physical pixels of the offscreen image.
+        const x = input.x / window.devicePixelRatio;
+        const y = input.y / window.devicePixelRatio;
+        const target = elementAt(x, y);
+        switch (input.type) {
+            case "move":
+                hover(target, x, y);
+                dispatchPointer(target, "move", x, y, 0);
+                break;
+            case "down":
+                hover(target, x, y);
+                buttons |= BUTTONS[input.button];
+                pressedTarget = target;
+                if (dispatchPointer(target, "down", x, y, input.button)) {
+                    target.focus?.();
+                }
+                break;
+            case "up":
+                buttons &= ~BUTTONS[input.button];
+                dispatchPointer(target, "up", x, y, input.button);
+                if (pressedTarget === target) {
+                    const init = mouseInit(x, y, input.button);
+                    target.dispatchEvent(new MouseEvent(input.button === 2 ? "contextmenu" : "click", init));
+                }
+                pressedTarget = null;
+                break;
+            case "wheel": {
+                const dx = input.dx / window.devicePixelRatio;
+                const dy = input.dy / window.devicePixelRatio;
+                const init = {...mouseInit(x, y, 0), deltaX: dx, deltaY: dy, deltaMode: 0};
+                if (target.dispatchEvent(new WheelEvent("wheel", init))) {
+                    scrollableAncestor(target, dx, dy).scrollBy(dx, dy);
+                }
+                break;
+            }
+        }
+    };
+
+    const onKey = (input) => {
+        const target = document.activeElement ?? document.body;
+        const init = {key: input.key, bubbles: true, cancelable: true, composed: true, view: window};
+        if (!input.pressed) {
+            target.dispatchEvent(new KeyboardEvent("keyup", init));
+            return;
+        }
+        if (!target.dispatchEvent(new KeyboardEvent("keydown", init)) || !isEditable(target)) {
+            return;
+        }
+        if (input.key.length === 1) {
+            document.execCommand("insertText", false, input.key);
+        } else if (input.key === "Backspace") {
+            document.execCommand("delete");
+        } else if (input.key === "Delete") {
+            document.execCommand("forwardDelete");
+        } else if (input.key === "Enter" && !(target instanceof HTMLInputElement)) {
+            document.execCommand("insertLineBreak");
+        }
+    };
+
+    window.__FLURX__.__offscreenInput = (input) => {
+        if (input.type === "key") {
+            onKey(input);
+        } else {
+            onPointer(input);
+        }
+    };
+})();
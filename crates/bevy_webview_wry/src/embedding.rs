@@ -6,9 +6,11 @@ use bevy::prelude::{Reflect, Vec2};
 use grip_zone::GripZonePlugin;
 use resize::ResizePlugin;
 use serde::{Deserialize, Serialize};
+use z_index::ZIndexPlugin;
 
 mod grip_zone;
 mod resize;
+mod z_index;
 
 #[allow(missing_docs)]
 pub mod prelude {
@@ -21,7 +23,8 @@ pub(crate) struct EmbeddingWebviewPlugin;
 impl Plugin for EmbeddingWebviewPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<CurrentMoving>()
-            .add_plugins((ResizePlugin, GripZonePlugin));
+            .add_plugins((ResizePlugin, GripZonePlugin))
+            .add_plugins(ZIndexPlugin);
     }
 }
 
@@ -1,21 +1,16 @@
 use crate::embedding::CurrentMoving;
 use crate::prelude::{DragEntered, GripZone, WryWebViews};
-#[cfg(any(target_os = "windows", target_os = "macos"))]
-use crate::util::WryResultLog;
 use bevy::ecs::system::SystemParam;
 use bevy::input::common_conditions::input_just_released;
 #[cfg(not(target_os = "linux"))]
 use bevy::input::mouse::MouseMotion;
 use bevy::prelude::*;
-use bevy::winit::WinitWindows;
 use bevy_flurx_ipc::ipc_trigger::IpcTriggerExt;
-use bevy_webview_core::bundle::embedding::{Bounds, EmbedWithin};
+use bevy_webview_core::bundle::embedding::{
+    Bounds, BringToFront, DockZones, Docked, DragHandles, EmbedWithin, MaximizeOnDoubleClick,
+    Maximized, PreventOverlap, WebviewDocked, WebviewSnap, WebviewUndocked,
+};
 use serde::Deserialize;
-#[cfg(target_os = "windows")]
-use wry::WebViewExtWindows;
-use wry::raw_window_handle::HasWindowHandle;
-#[cfg(any(target_os = "windows", target_os = "macos"))]
-use wry::raw_window_handle::RawWindowHandle;
 
 pub struct GripZonePlugin;
 
@@ -23,18 +18,22 @@ impl Plugin for GripZonePlugin {
     fn build(&self, app: &mut App) {
         app.add_ipc_trigger::<OnGripGrab>("FLURX|grip::grab")
             .add_ipc_trigger::<OnGripRelease>("FLURX|grip::release")
+            .add_ipc_trigger::<OnGripDoubleClick>("FLURX|grip::double_click")
             .add_systems(
                 Update,
                 (
                     drag.run_if(any_with_component::<CurrentMoving>),
                     resize_grip_zone,
+                    apply_drag_handles,
                     all_remove_current_moving
                         .run_if(input_just_released(MouseButton::Left).or(on_event::<DragEntered>)),
+                    dock_released,
                 )
                     .run_if(any_with_component::<GripZone>),
             )
             .add_observer(apply_drag_start)
-            .add_observer(apply_drag_end);
+            .add_observer(apply_drag_end)
+            .add_observer(toggle_maximized);
 
         #[cfg(target_os = "linux")]
         app.add_ipc_trigger::<OnGribDrag>("FLURX|grip::drag");
@@ -56,12 +55,33 @@ fn resize_grip_zone(
     }
 }
 
+fn apply_drag_handles(
+    webviews: Query<(Entity, &DragHandles), Changed<DragHandles>>,
+    wry_webviews: NonSend<WryWebViews>,
+) {
+    for (entity, drag_handles) in webviews.iter() {
+        let Some(webview) = wry_webviews.0.get(&entity) else {
+            continue;
+        };
+        let Ok(selector) = serde_json::to_string(&drag_handles.0) else {
+            continue;
+        };
+        if let Err(e) =
+            webview.evaluate_script(&format!("window.__FLURX__.dragHandleSelector={selector}"))
+        {
+            bevy::log::warn!("Failed to set drag handles: {}", e);
+        }
+    }
+}
+
 #[derive(SystemParam)]
 struct MouseDelta<'w, 's> {
     #[cfg(not(target_os = "linux"))]
     er: EventReader<'w, 's, MouseMotion>,
     /// I was testing on Ubuntu 24.04 ARM64 in Parallels, but `MouseMotion` was getting clearly abnormal coordinates,
     /// so I switched to getting delta from webview.
+    ///
+    /// The page reports the delta only while the grip zone is grabbed, at most once per animation frame.
     #[cfg(target_os = "linux")]
     er: EventReader<'w, 's, OnGribDrag>,
 }
@@ -86,20 +106,51 @@ impl MouseDelta<'_, '_> {
 
 fn drag(
     mut mouse_delta: MouseDelta,
-    mut webviews: Query<(&mut Bounds, &mut CurrentMoving, &EmbedWithin), With<CurrentMoving>>,
+    mut webviews: Query<(
+        &mut Bounds,
+        &mut CurrentMoving,
+        &mut DragPosition,
+        &EmbedWithin,
+        Option<&WebviewSnap>,
+        Option<&PreventOverlap>,
+    )>,
+    others: Query<(&Bounds, &EmbedWithin), Without<CurrentMoving>>,
     windows: Query<&Window>,
 ) {
     let Some(delta) = mouse_delta.delta() else {
         return;
     };
 
-    for (mut bounds, mut moving, parent) in webviews.iter_mut() {
+    for (mut bounds, mut moving, mut drag_position, parent, snap, prevent_overlap) in
+        webviews.iter_mut()
+    {
         let Ok(window) = windows.get(parent.0) else {
             continue;
         };
         let window_size = Vec2::new(window.width(), window.height());
         moving.0 = delta;
-        move_bounds(&mut bounds, moving.0, window_size, None);
+        // Moves the position before snapping so that the webview can leave the snapped edge.
+        let mut dragged = Bounds {
+            position: drag_position.position,
+            ..*bounds
+        };
+        move_bounds(&mut dragged, moving.0, window_size, None);
+        drag_position.position = dragged.position;
+        drag_position.moved = true;
+
+        let others = others
+            .iter()
+            .filter(|(_, other_parent)| other_parent.0 == parent.0)
+            .map(|(other, _)| other.rect())
+            .collect::<Vec<_>>();
+        let mut position = dragged.position;
+        if let Some(snap) = snap {
+            position = snap.snap(&dragged, window_size, &others);
+        }
+        if let Some(prevent_overlap) = prevent_overlap {
+            position = prevent_overlap.resolve(bounds.size, bounds.position, position, &others);
+        }
+        bounds.position = position;
     }
 }
 
@@ -109,6 +160,47 @@ fn all_remove_current_moving(mut commands: Commands, views: Query<Entity, With<C
     }
 }
 
+fn dock_released(
+    mut commands: Commands,
+    mut removed: RemovedComponents<CurrentMoving>,
+    mut ew: EventWriter<WebviewDocked>,
+    mut webviews: Query<
+        (&mut Bounds, &EmbedWithin, &DockZones, &DragPosition),
+        (Without<Docked>, Without<Maximized>),
+    >,
+    windows: Query<&Window>,
+) {
+    for webview_entity in removed.read() {
+        let Ok(mut entity_commands) = commands.get_entity(webview_entity) else {
+            continue;
+        };
+        entity_commands.remove::<DragPosition>();
+        let Ok((mut bounds, parent, dock_zones, drag_position)) = webviews.get_mut(webview_entity)
+        else {
+            continue;
+        };
+        // A click or double click on the grip zone grabs and releases it without moving.
+        if !drag_position.moved {
+            continue;
+        }
+        let Ok(window) = windows.get(parent.0) else {
+            continue;
+        };
+        let Some(side) = dock_zones.dock_side(&bounds, window.size()) else {
+            continue;
+        };
+        entity_commands.insert(Docked {
+            side,
+            undocked_size: bounds.size,
+        });
+        *bounds = side.compute_bounds(window.size(), bounds.min_size);
+        ew.write(WebviewDocked {
+            webview_entity,
+            side,
+        });
+    }
+}
+
 fn move_bounds(bounds: &mut Bounds, offset: Vec2, window_size: Vec2, toolbar_height: Option<f32>) {
     let max = toolbar_height
         .map(|height| Vec2::new(0., height))
@@ -135,56 +227,49 @@ struct OnGribDrag {
     y: f32,
 }
 
+/// The position of the dragged webview before snapping.
+#[derive(Component)]
+struct DragPosition {
+    position: Vec2,
+    /// Whether the webview has been dragged since grabbed.
+    moved: bool,
+}
+
+impl DragPosition {
+    const fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            moved: false,
+        }
+    }
+}
+
 fn apply_drag_start(
     trigger: Trigger<OnGripGrab>,
     mut commands: Commands,
-    wry_webviews: NonSend<WryWebViews>,
-    winit_windows: NonSend<WinitWindows>,
-    webviews: Query<&EmbedWithin>,
+    mut ew: EventWriter<WebviewUndocked>,
+    mut webviews: Query<(&mut Bounds, Option<&Docked>), With<EmbedWithin>>,
 ) {
     let webview_entity = trigger.target();
-    let Ok(EmbedWithin(window_entity)) = webviews.get(webview_entity) else {
-        return;
-    };
-
-    commands
-        .entity(webview_entity)
-        .insert(CurrentMoving(Vec2::new(trigger.x, trigger.y)));
-
-    bring_to_front(webview_entity, window_entity, &wry_webviews, &winit_windows);
-}
-
-fn bring_to_front(
-    window_entity: Entity,
-    webview_entity: &Entity,
-    wry_webviews: &WryWebViews,
-    winit_windows: &WinitWindows,
-) {
-    let Some(_webview) = wry_webviews.0.get(webview_entity) else {
-        return;
-    };
-    let Some(window_handle) = winit_windows
-        .get_window(window_entity)
-        .and_then(|w| w.window_handle().ok())
-        .map(|h| h.as_raw())
-    else {
+    let Ok((mut bounds, docked)) = webviews.get_mut(webview_entity) else {
         return;
     };
-    match window_handle {
-        #[cfg(target_os = "windows")]
-        RawWindowHandle::Win32(handle) => {
-            _webview.reparent(handle.hwnd.get()).output_log_if_failed();
-        }
-        #[cfg(any(target_os = "macos", target_os = "ios"))]
-        RawWindowHandle::AppKit(_) => {
-            use objc2::rc::Retained;
-            use wry::WebViewExtMacOS;
-            _webview
-                .reparent(Retained::into_raw(_webview.ns_window()))
-                .output_log_if_failed();
-        }
-        _ => {}
+    let mut entity_commands = commands.entity(webview_entity);
+    if let Some(docked) = docked {
+        bounds.size = docked.undocked_size.max(bounds.min_size);
+        entity_commands.remove::<Docked>();
+        ew.write(WebviewUndocked {
+            webview_entity,
+            side: docked.side,
+        });
     }
+
+    entity_commands
+        .insert((
+            CurrentMoving(Vec2::new(trigger.x, trigger.y)),
+            DragPosition::new(bounds.position),
+        ))
+        .trigger(BringToFront);
 }
 
 #[allow(non_snake_case)]
@@ -198,10 +283,57 @@ fn apply_drag_end(trigger: Trigger<OnGripRelease>, mut commands: Commands) {
     commands.entity(webview_entity).remove::<CurrentMoving>();
 }
 
+#[allow(non_snake_case)]
+#[derive(Deserialize, Event)]
+struct OnGripDoubleClick {
+    __FLURX__grip_double_click: u8,
+}
+
+fn toggle_maximized(
+    trigger: Trigger<OnGripDoubleClick>,
+    mut commands: Commands,
+    mut webviews: Query<(
+        &mut Bounds,
+        &EmbedWithin,
+        &MaximizeOnDoubleClick,
+        Option<&Maximized>,
+    )>,
+    windows: Query<&Window>,
+) {
+    let webview_entity = trigger.target();
+    let Ok((mut bounds, parent, maximize, maximized)) = webviews.get_mut(webview_entity) else {
+        return;
+    };
+    if !maximize.0 {
+        return;
+    }
+    if let Some(maximized) = maximized {
+        *bounds = maximized.restore_bounds;
+        commands.entity(webview_entity).remove::<Maximized>();
+        return;
+    }
+    let Ok(window) = windows.get(parent.0) else {
+        return;
+    };
+    commands.entity(webview_entity).insert(Maximized {
+        restore_bounds: *bounds,
+    });
+    bounds.position = Vec2::ZERO;
+    bounds.size = window.size().max(bounds.min_size);
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::embedding::grip_zone::move_bounds;
-    use crate::prelude::Bounds;
+    use crate::embedding::CurrentMoving;
+    #[cfg(target_os = "linux")]
+    use crate::embedding::grip_zone::OnGribDrag;
+    use crate::embedding::grip_zone::{
+        DragPosition, MouseDelta, OnGripDoubleClick, dock_released, move_bounds, toggle_maximized,
+    };
+    use crate::prelude::{
+        Bounds, DockSide, DockZones, Docked, EmbedWithin, MaximizeOnDoubleClick, Maximized,
+        WebviewDocked,
+    };
     use bevy::prelude::*;
 
     #[test]
@@ -219,6 +351,190 @@ mod tests {
         assert_eq!(bounds.position, Vec2::new(95., 90.));
     }
 
+    #[test]
+    fn dock_when_released_near_edge() {
+        let (app, webview) = released_app(true);
+        assert_eq!(
+            app.world().get::<Docked>(webview),
+            Some(&Docked {
+                side: DockSide::Left,
+                undocked_size: Vec2::new(200., 200.),
+            })
+        );
+        let bounds = app.world().get::<Bounds>(webview).unwrap();
+        assert_eq!(bounds.position, Vec2::ZERO);
+        assert_eq!(bounds.size, Vec2::new(640., 720.));
+    }
+
+    #[test]
+    fn not_dock_if_released_without_moving() {
+        let (app, webview) = released_app(false);
+        assert!(app.world().get::<Docked>(webview).is_none());
+        assert_eq!(
+            app.world().get::<Bounds>(webview).unwrap().size,
+            Vec2::new(200., 200.)
+        );
+    }
+
+    #[test]
+    fn not_dock_maximized_webview() {
+        let mut app = App::new();
+        app.add_event::<WebviewDocked>()
+            .add_systems(Update, dock_released);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let bounds = Bounds {
+            position: Vec2::ZERO,
+            size: Vec2::new(1280., 720.),
+            ..default()
+        };
+        let webview = app
+            .world_mut()
+            .spawn((
+                EmbedWithin(window),
+                DockZones::default(),
+                CurrentMoving(Vec2::ZERO),
+                DragPosition {
+                    position: Vec2::ZERO,
+                    moved: true,
+                },
+                Maximized {
+                    restore_bounds: new_bounds(),
+                },
+                bounds,
+            ))
+            .id();
+        app.update();
+        app.world_mut()
+            .entity_mut(webview)
+            .remove::<CurrentMoving>();
+        app.update();
+        assert!(app.world().get::<Docked>(webview).is_none());
+        assert_eq!(app.world().get::<Bounds>(webview), Some(&bounds));
+    }
+
+    /// Spawns the webview near the left edge, and releases it.
+    fn released_app(moved: bool) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_event::<WebviewDocked>()
+            .add_systems(Update, dock_released);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let webview = app
+            .world_mut()
+            .spawn((
+                EmbedWithin(window),
+                DockZones::default(),
+                CurrentMoving(Vec2::ZERO),
+                DragPosition {
+                    position: Vec2::new(0., 100.),
+                    moved,
+                },
+                Bounds {
+                    position: Vec2::new(0., 100.),
+                    size: Vec2::new(200., 200.),
+                    ..default()
+                },
+            ))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(webview)
+            .remove::<CurrentMoving>();
+        app.update();
+        (app, webview)
+    }
+
+    #[test]
+    fn toggle_maximized_by_double_click() {
+        let mut app = App::new();
+        app.add_observer(toggle_maximized);
+        let window = app.world_mut().spawn(Window::default()).id();
+        let bounds = new_bounds();
+        let webview = app
+            .world_mut()
+            .spawn((EmbedWithin(window), MaximizeOnDoubleClick(true), bounds))
+            .id();
+
+        app.world_mut()
+            .entity_mut(webview)
+            .trigger(OnGripDoubleClick {
+                __FLURX__grip_double_click: 0,
+            });
+        app.update();
+        assert_eq!(
+            app.world().get::<Maximized>(webview),
+            Some(&Maximized {
+                restore_bounds: bounds
+            })
+        );
+        assert_eq!(
+            app.world().get::<Bounds>(webview).unwrap().size,
+            Vec2::new(1280., 720.)
+        );
+
+        app.world_mut()
+            .entity_mut(webview)
+            .trigger(OnGripDoubleClick {
+                __FLURX__grip_double_click: 0,
+            });
+        app.update();
+        assert!(app.world().get::<Maximized>(webview).is_none());
+        assert_eq!(app.world().get::<Bounds>(webview), Some(&bounds));
+    }
+
+    #[derive(Resource, Default)]
+    struct Delta(Option<Vec2>);
+
+    fn read_delta(mut mouse_delta: MouseDelta, mut delta: ResMut<Delta>) {
+        delta.0 = mouse_delta.delta();
+    }
+
+    fn delta_app() -> App {
+        let mut app = App::new();
+        #[cfg(target_os = "linux")]
+        app.add_event::<OnGribDrag>();
+        #[cfg(not(target_os = "linux"))]
+        app.add_event::<bevy::input::mouse::MouseMotion>();
+        app.init_resource::<Delta>().add_systems(Update, read_delta);
+        app
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sum_drag_deltas_reported_from_page() {
+        let mut app = delta_app();
+        app.world_mut().send_event(OnGribDrag { x: 1., y: 2. });
+        app.world_mut().send_event(OnGribDrag { x: 3., y: -4. });
+        app.update();
+        assert_eq!(app.world().resource::<Delta>().0, Some(Vec2::new(4., -2.)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn no_delta_without_drag_reports() {
+        let mut app = delta_app();
+        app.world_mut().send_event(OnGribDrag { x: 1., y: 2. });
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Delta>().0, None);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn sum_mouse_motions() {
+        use bevy::input::mouse::MouseMotion;
+
+        let mut app = delta_app();
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(1., 2.),
+        });
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(3., -4.),
+        });
+        app.update();
+        assert_eq!(app.world().resource::<Delta>().0, Some(Vec2::new(4., -2.)));
+    }
+
     fn new_bounds() -> Bounds {
         Bounds {
             position: Vec2::new(5., 10.),
@@ -1,7 +1,5 @@
 use crate::embedding::CurrentMoving;
-use crate::prelude::{WebviewInitialized, WryWebViews};
-use crate::util::as_wry_rect;
-use bevy::input::common_conditions::input_pressed;
+use bevy::input::common_conditions::{input_just_pressed, input_just_released, input_pressed};
 use bevy::math::Vec2;
 use bevy::prelude::*;
 use bevy::window::Window;
@@ -16,19 +14,27 @@ impl Plugin for ResizePlugin {
             Update,
             (
                 change_mouse_cursor_icon.run_if(not(input_pressed(MouseButton::Left))),
+                start_resize.run_if(input_just_pressed(MouseButton::Left)),
                 resize_bounds.run_if(input_pressed(MouseButton::Left)),
-                render_bounds,
+                end_resize.run_if(input_just_released(MouseButton::Left)),
             ),
         );
     }
 }
 
+/// Marker component indicating that the webview is being resized.
+#[derive(Component)]
+struct Resizing;
+
 fn change_mouse_cursor_icon(
     mut commands: Commands,
     mut windows: Query<(Entity, &Window)>,
-    views: Query<(Entity, &EmbedWithin, &Bounds, &Resizable), Without<CurrentMoving>>,
+    views: Query<
+        (Entity, &EmbedWithin, &Bounds, &Resizable, &ResizeSettings),
+        Without<CurrentMoving>,
+    >,
 ) {
-    for (entity, parent, bounds, resizable) in views.iter() {
+    for (entity, parent, bounds, resizable, settings) in views.iter() {
         if !resizable.0 {
             continue;
         }
@@ -43,7 +49,7 @@ fn change_mouse_cursor_icon(
         } else {
             cursor_pos
         };
-        if let Some(resize_mode) = bounds.maybe_resizable(cursor_pos, None) {
+        if let Some(resize_mode) = settings.resize_mode(bounds, cursor_pos, None) {
             commands.entity(entity).insert(resize_mode);
             commands
                 .entity(window_entity)
@@ -55,14 +61,41 @@ fn change_mouse_cursor_icon(
     }
 }
 
+fn start_resize(
+    mut commands: Commands,
+    mut ew: EventWriter<WebviewResizeStarted>,
+    webviews: Query<(Entity, &ResizeMode, &Resizable), Without<CurrentMoving>>,
+) {
+    for (webview_entity, resize_mode, resizable) in webviews.iter() {
+        if !resizable.0 {
+            continue;
+        }
+        commands.entity(webview_entity).insert(Resizing);
+        ew.write(WebviewResizeStarted {
+            webview_entity,
+            mode: *resize_mode,
+        });
+    }
+}
+
 fn resize_bounds(
+    mut ew: EventWriter<WebviewResized>,
     mut webviews: Query<
-        (&mut Bounds, &ResizeMode, &EmbedWithin, &Resizable),
-        Without<CurrentMoving>,
+        (
+            Entity,
+            &mut Bounds,
+            &ResizeMode,
+            &EmbedWithin,
+            &Resizable,
+            &ResizeSettings,
+        ),
+        (With<Resizing>, Without<CurrentMoving>),
     >,
     windows: Query<&Window>,
 ) {
-    for (mut bounds, resize_mode, parent, resizable) in webviews.iter_mut() {
+    for (webview_entity, mut bounds, resize_mode, parent, resizable, settings) in
+        webviews.iter_mut()
+    {
         if !resizable.0 {
             continue;
         }
@@ -76,20 +109,28 @@ fn resize_bounds(
             } else {
                 cursor_pos
             };
-            bounds.transform(resize_mode, cursor_pos, 0.);
+            let mut resized = *bounds;
+            settings.resize(&mut resized, resize_mode, cursor_pos);
+            if bounds.set_if_neq(resized) {
+                ew.write(WebviewResized {
+                    webview_entity,
+                    bounds: resized,
+                });
+            }
         }
     }
 }
 
-fn render_bounds(
-    webview_map: NonSend<WryWebViews>,
-    views: Query<(Entity, &Bounds), Or<(Changed<Bounds>, Added<WebviewInitialized>)>>,
+fn end_resize(
+    mut commands: Commands,
+    mut ew: EventWriter<WebviewResizeEnded>,
+    webviews: Query<(Entity, &Bounds), With<Resizing>>,
 ) {
-    for (entity, bounds) in views.iter() {
-        if let Some(webview) = webview_map.0.get(&entity) {
-            if let Err(e) = webview.set_bounds(as_wry_rect(bounds)) {
-                bevy::log::error!("Failed to set resize bounds: {}", e);
-            }
-        }
+    for (webview_entity, bounds) in webviews.iter() {
+        commands.entity(webview_entity).remove::<Resizing>();
+        ew.write(WebviewResizeEnded {
+            webview_entity,
+            bounds: *bounds,
+        });
     }
 }
@@ -0,0 +1,109 @@
+use crate::prelude::WryWebViews;
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+use crate::util::WryResultLog;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy::winit::WinitWindows;
+use bevy_webview_core::bundle::WebviewInitialized;
+use bevy_webview_core::bundle::embedding::{EmbedWithin, WebviewZIndex};
+#[cfg(target_os = "windows")]
+use wry::WebViewExtWindows;
+#[cfg(not(target_os = "linux"))]
+use wry::raw_window_handle::HasWindowHandle;
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+use wry::raw_window_handle::RawWindowHandle;
+
+pub struct ZIndexPlugin;
+
+impl Plugin for ZIndexPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, restack_webviews);
+    }
+}
+
+/// Restacks all webviews within the window in which the z-index has been changed or a webview has been initialized.
+fn restack_webviews(
+    changed: Query<
+        &EmbedWithin,
+        (
+            With<WebviewInitialized>,
+            Or<(Changed<WebviewZIndex>, Added<WebviewInitialized>)>,
+        ),
+    >,
+    webviews: Query<(Entity, &EmbedWithin, &WebviewZIndex), With<WebviewInitialized>>,
+    wry_webviews: NonSend<WryWebViews>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    let windows = changed
+        .iter()
+        .map(|parent| parent.0)
+        .collect::<HashSet<_>>();
+    for window_entity in windows {
+        let mut stack = webviews
+            .iter()
+            .filter(|(_, parent, _)| parent.0 == window_entity)
+            .map(|(entity, _, z_index)| (*z_index, entity))
+            .collect::<Vec<_>>();
+        stack.sort();
+        for (_, webview_entity) in stack {
+            raise(window_entity, webview_entity, &wry_webviews, &winit_windows);
+        }
+    }
+}
+
+/// Moves the webview in front of the others within the window.
+#[cfg(target_os = "linux")]
+fn raise(
+    _window_entity: Entity,
+    webview_entity: Entity,
+    wry_webviews: &WryWebViews,
+    _winit_windows: &WinitWindows,
+) {
+    use gtk::prelude::WidgetExt;
+    use wry::WebViewExtUnix;
+
+    let Some(webview) = wry_webviews.0.get(&webview_entity) else {
+        return;
+    };
+    // The embedded webview is placed in its own child window, so raises it among the siblings.
+    if let Some(window) = webview.webview().toplevel().and_then(|w| w.window()) {
+        window.raise();
+    }
+}
+
+/// Moves the webview in front of the others within the window.
+///
+/// Reparenting puts the webview on top of the other children.
+#[cfg(not(target_os = "linux"))]
+fn raise(
+    window_entity: Entity,
+    webview_entity: Entity,
+    wry_webviews: &WryWebViews,
+    winit_windows: &WinitWindows,
+) {
+    let Some(_webview) = wry_webviews.0.get(&webview_entity) else {
+        return;
+    };
+    let Some(window_handle) = winit_windows
+        .get_window(window_entity)
+        .and_then(|w| w.window_handle().ok())
+        .map(|h| h.as_raw())
+    else {
+        return;
+    };
+    match window_handle {
+        #[cfg(target_os = "windows")]
+        RawWindowHandle::Win32(handle) => {
+            _webview.reparent(handle.hwnd.get()).output_log_if_failed();
+        }
+        #[cfg(target_os = "macos")]
+        RawWindowHandle::AppKit(_) => {
+            use objc2::rc::Retained;
+            use wry::WebViewExtMacOS;
+            _webview
+                .reparent(Retained::into_raw(_webview.ns_window()))
+                .output_log_if_failed();
+        }
+        _ => {}
+    }
+}
@@ -1,23 +1,23 @@
 //! Provides a mechanism to control the basic behavior of Webview.
 
-use crate::webview::emit_webview_event::EventEmitterPlugin;
+use crate::util::as_wry_rect;
 use crate::webview::handlers::WryHandlersPlugin;
-use crate::webview::ipc_resolve::IpcResolvePlugin;
 use crate::webview::load_webview::LoadWebviewPlugin;
 use crate::webview::protocol::CustomProtocolPlugin;
-use crate::webview::visible::VisiblePlugin;
-use bevy::platform::collections::HashMap;
+use bevy::platform::collections::{HashMap, HashSet};
 use bevy::prelude::{App, Deref, DerefMut, Entity, Plugin};
 use bevy_flurx_ipc::FlurxIpcPlugin;
+use bevy_webview_core::backend::{BackendResult, WebviewBackend, WebviewBackendPlugin};
+use bevy_webview_core::prelude::{
+    Bounds, ExportPdf, PrintCompletion, PrintWebview, ScreenshotCompletion, Webview,
+};
 
-mod emit_webview_event;
 pub mod handlers;
-mod ipc_resolve;
+mod hit_test;
 mod load_webview;
-mod visible;
+#[cfg(feature = "offscreen")]
+mod offscreen;
 
-#[cfg(debug_assertions)]
-mod devtools;
 #[cfg(any(
     target_os = "linux",
     target_os = "dragonfly",
@@ -26,15 +26,14 @@ mod devtools;
     target_os = "openbsd",
 ))]
 mod linux;
+mod print;
 mod protocol;
+mod screenshot;
 
 #[allow(missing_docs)]
 pub mod prelude {
-    pub use crate::webview::{
-        WryWebViews,
-        emit_webview_event::{EmitIpcEvent, EventPayload},
-        handlers::prelude::*,
-    };
+    pub use crate::webview::{WryWebViews, handlers::prelude::*};
+    pub use bevy_webview_core::backend::{EmitIpcEvent, EventPayload};
 }
 
 pub(crate) struct WebviewPlugin;
@@ -47,9 +46,7 @@ impl Plugin for WebviewPlugin {
 
         app.add_plugins((
             LoadWebviewPlugin,
-            VisiblePlugin,
-            EventEmitterPlugin,
-            IpcResolvePlugin,
+            WebviewBackendPlugin::<WryWebViews>::default(),
             WryHandlersPlugin,
             CustomProtocolPlugin,
             #[cfg(any(
@@ -62,15 +59,182 @@ impl Plugin for WebviewPlugin {
             linux::WebviewSupportLinuxPlugin,
         ))
         .init_non_send_resource::<WryWebViews>();
-
-        #[cfg(debug_assertions)]
-        app.add_plugins(devtools::DevtoolsPlugin);
     }
 }
 
 /// A hashmap that manages the initialized webview.
 ///
 /// [`World`](bevy::prelude::World) holds this as [`NonSend`](bevy::prelude::NonSend).
-#[repr(transparent)]
 #[derive(Deref, DerefMut, Default)]
-pub struct WryWebViews(pub(crate) HashMap<Entity, wry::WebView>);
+pub struct WryWebViews(
+    #[deref] pub(crate) HashMap<Entity, wry::WebView>,
+    /// The webviews hosted in their own child windows within the other window,
+    /// such as the ones embedded by [`EmbedWithin`](bevy_webview_core::prelude::EmbedWithin).
+    pub(crate) HashSet<Entity>,
+    /// The webviews rendered into images by [`Offscreen`](bevy_webview_core::prelude::Offscreen).
+    #[cfg(feature = "offscreen")]
+    pub(crate) offscreen::OffscreenViews,
+);
+
+impl WebviewBackend for WryWebViews {
+    fn load(&mut self, webview_entity: Entity, webview: &Webview) -> BackendResult {
+        if let Some(wry_webview) = self.0.get(&webview_entity) {
+            match webview {
+                Webview::Uri(uri) => wry_webview.load_url(&uri.0)?,
+                Webview::Html(html) => wry_webview.load_html(html)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn reload(&mut self, webview_entity: Entity) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.reload()?;
+        }
+        Ok(())
+    }
+
+    fn set_bounds(&mut self, webview_entity: Entity, bounds: &Bounds) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.set_bounds(as_wry_rect(bounds))?;
+        }
+        Ok(())
+    }
+
+    fn set_visible(&mut self, webview_entity: Entity, visible: bool) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.set_visible(visible)?;
+        }
+        Ok(())
+    }
+
+    fn set_zoom(&mut self, webview_entity: Entity, scale_factor: f64) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.zoom(scale_factor)?;
+        }
+        Ok(())
+    }
+
+    fn set_cursor_hit_test(&mut self, webview_entity: Entity, hit_test: bool) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            let in_child_window = self.1.contains(&webview_entity);
+            hit_test::set_cursor_hit_test(webview, in_child_window, hit_test)?;
+        }
+        Ok(())
+    }
+
+    fn print(
+        &mut self,
+        webview_entity: Entity,
+        options: &PrintWebview,
+        completion: PrintCompletion,
+    ) -> BackendResult {
+        match self.0.get(&webview_entity) {
+            Some(webview) => print::print(webview, options, completion)?,
+            None => completion.complete(false),
+        }
+        Ok(())
+    }
+
+    fn export_pdf(
+        &mut self,
+        webview_entity: Entity,
+        options: &ExportPdf,
+        completion: PrintCompletion,
+    ) -> BackendResult {
+        match self.0.get(&webview_entity) {
+            Some(webview) => print::export_pdf(webview, options, completion)?,
+            None => completion.complete(false),
+        }
+        Ok(())
+    }
+
+    fn take_screenshot(
+        &mut self,
+        webview_entity: Entity,
+        completion: ScreenshotCompletion,
+    ) -> BackendResult {
+        match self.0.get(&webview_entity) {
+            Some(webview) => screenshot::take_screenshot(webview, completion)?,
+            None => completion.complete(Err("The webview was not found".to_string())),
+        }
+        Ok(())
+    }
+
+    #[cfg(debug_assertions)]
+    fn open_devtools(&mut self, webview_entity: Entity) {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.open_devtools();
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn open_devtools(&mut self, _: Entity) {}
+
+    #[cfg(debug_assertions)]
+    fn close_devtools(&mut self, webview_entity: Entity) {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.close_devtools();
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn close_devtools(&mut self, _: Entity) {}
+
+    #[cfg(debug_assertions)]
+    fn is_devtools_open(&self, webview_entity: Entity) -> Option<bool> {
+        self.0
+            .get(&webview_entity)
+            .map(|webview| webview.is_devtools_open())
+    }
+
+    /// The devtools are only available in debug builds.
+    #[cfg(not(debug_assertions))]
+    fn is_devtools_open(&self, _: Entity) -> Option<bool> {
+        None
+    }
+
+    /// The native snapshot is available on Linux, macOS and Windows.
+    #[cfg(feature = "offscreen")]
+    fn supports_offscreen(&self) -> bool {
+        cfg!(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "macos",
+            target_os = "windows",
+        ))
+    }
+
+    #[cfg(feature = "offscreen")]
+    fn capture_frame(
+        &mut self,
+        webview_entity: Entity,
+    ) -> BackendResult<Option<bevy_webview_core::prelude::OffscreenFrame>> {
+        match self.0.get(&webview_entity) {
+            Some(webview) => self.2.capture_frame(webview_entity, webview),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(feature = "offscreen")]
+    fn send_offscreen_input(
+        &mut self,
+        webview_entity: Entity,
+        input: &bevy_webview_core::prelude::OffscreenInput,
+    ) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            offscreen::send_input(webview, input)?;
+        }
+        Ok(())
+    }
+
+    fn evaluate_script(&mut self, webview_entity: Entity, script: &str) -> BackendResult {
+        if let Some(webview) = self.0.get(&webview_entity) {
+            webview.evaluate_script(script)?;
+        }
+        Ok(())
+    }
+}
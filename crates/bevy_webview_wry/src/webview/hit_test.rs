@@ -0,0 +1,91 @@
+use bevy_webview_core::backend::BackendResult;
+use wry::WebView;
+
+/// Passes the mouse events through the webview by emptying the input shape of the window wrapping it,
+/// so that they are delivered to the parent window.
+///
+/// Only the webviews hosted in their own child windows are supported,
+/// because the window wrapping the other webviews is the game window itself.
+/// See [`hosts_in_child_window`](crate::webview::load_webview::hosts_in_child_window).
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) fn set_cursor_hit_test(
+    webview: &WebView,
+    in_child_window: bool,
+    hit_test: bool,
+) -> BackendResult {
+    use gtk::cairo::Region;
+    use gtk::prelude::WidgetExt;
+    use wry::WebViewExtUnix;
+
+    if !in_child_window {
+        return Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into());
+    }
+    let Some(toplevel) = webview.webview().toplevel() else {
+        return Ok(());
+    };
+    // Unsetting the shape makes the whole window receive the mouse events even after it is resized.
+    let shape = (!hit_test).then(Region::create);
+    toplevel.input_shape_combine_region(shape.as_ref());
+    Ok(())
+}
+
+/// Passes the mouse events through the webview by disabling the container window that wry creates for the controller.
+///
+/// The system delivers the mouse events over the disabled child window to its parent, the game window.
+#[cfg(target_os = "windows")]
+pub(crate) fn set_cursor_hit_test(webview: &WebView, _: bool, hit_test: bool) -> BackendResult {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::Input::KeyboardAndMouse::EnableWindow;
+    use wry::WebViewExtWindows;
+
+    let mut container = HWND::default();
+    // SAFETY: `container` is a valid pointer to write the handle into.
+    unsafe { webview.controller().ParentWindow(&mut container)? };
+    // SAFETY: `container` is the window created by wry, which lives as long as the webview.
+    // The returned value is the previous state, not an error.
+    let _ = unsafe { EnableWindow(container, hit_test) };
+    Ok(())
+}
+
+/// Passes the mouse events through the webview by making the panel hosting it ignore them,
+/// so that they are delivered to the game window underneath.
+///
+/// The webviews embedded within the other window are unsupported,
+/// because they are the subviews of the game window and `NSView` can't ignore the mouse events.
+#[cfg(target_os = "macos")]
+pub(crate) fn set_cursor_hit_test(
+    webview: &WebView,
+    in_child_window: bool,
+    hit_test: bool,
+) -> BackendResult {
+    use wry::WebViewExtMacOS;
+
+    if in_child_window {
+        return Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into());
+    }
+    // The webview without `EmbedWithin` is the content view of the panel attached above the game window.
+    if let Some(panel) = webview.webview().window() {
+        panel.setIgnoresMouseEvents(!hit_test);
+    }
+    Ok(())
+}
+
+/// The native webview can't pass the mouse events through on this platform.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "windows",
+    target_os = "macos",
+)))]
+pub(crate) fn set_cursor_hit_test(_: &WebView, _: bool, _: bool) -> BackendResult {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+}
@@ -9,6 +9,7 @@ use crate::webview::load_webview::protocol::feed_uri;
 use crate::webview::protocol::{WryRequestSender, WryResponseHandles};
 use bevy::prelude::*;
 use bevy::winit::WinitWindows;
+use bevy_webview_core::backend::{CLICK_THROUGH_SCRIPT, FORWARD_INPUT_SCRIPT, SCREENSHOT_SCRIPT};
 use bevy_webview_core::bundle::embedding::{Bounds, EmbedWithin};
 use bevy_webview_core::prelude::*;
 use rand::distr::{Alphanumeric, SampleString};
@@ -26,6 +27,18 @@ impl Plugin for LoadWebviewPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(PreUpdate, load_web_views);
 
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        ))]
+        app.add_systems(
+            Update,
+            resize_child_window_webviews.run_if(on_event::<bevy::window::WindowResized>),
+        );
+
         #[cfg(target_os = "macos")]
         {
             use bevy::prelude::IntoScheduleConfigs;
@@ -59,6 +72,19 @@ type Configs2<'a> = (
 
 type ConfigsPlatformSpecific<'a> = (&'a Theme, &'a BrowserAcceleratorKeys, &'a UseHttpsScheme);
 
+#[cfg(feature = "offscreen")]
+type OffscreenConfig<'a> = Option<&'a Offscreen>;
+
+#[cfg(not(feature = "offscreen"))]
+type OffscreenConfig<'a> = ();
+
+/// The webviews are hosted in their windows, the parent windows, or offscreen.
+#[cfg(feature = "offscreen")]
+type WebviewHost = Or<(With<Window>, With<EmbedWithin>, With<Offscreen>)>;
+
+#[cfg(not(feature = "offscreen"))]
+type WebviewHost = Or<(With<Window>, With<EmbedWithin>)>;
+
 #[allow(clippy::too_many_arguments)]
 fn load_web_views(
     mut commands: Commands,
@@ -72,11 +98,10 @@ fn load_web_views(
             ConfigsPlatformSpecific,
             Option<&EmbedWithin>,
             Option<&Bounds>,
+            Option<&ClickThrough>,
+            OffscreenConfig,
         ),
-        (
-            Without<WebviewInitialized>,
-            Or<(With<Window>, With<EmbedWithin>)>,
-        ),
+        (Without<WebviewInitialized>, WebviewHost),
     >,
     ipc_params: IpcHandlerParams,
     event_params: WryEventParams,
@@ -84,12 +109,32 @@ fn load_web_views(
     windows: NonSend<WinitWindows>,
     request_sender: NonSend<WryRequestSender>,
 ) {
-    for (webview_entity, handlers, configs1, configs2, configs_platform, embed_within, bounds) in
-        views.iter_mut()
+    for (
+        webview_entity,
+        handlers,
+        configs1,
+        configs2,
+        configs_platform,
+        embed_within,
+        bounds,
+        click_through,
+        offscreen,
+    ) in views.iter_mut()
     {
         let Some(builder) = new_builder(embed_within.is_some(), &bounds) else {
             continue;
         };
+        let in_child_window =
+            !is_offscreen(offscreen) && hosts_in_child_window(embed_within, click_through);
+        let builder = if in_child_window {
+            let Some(window) = windows.get_window(webview_entity) else {
+                continue;
+            };
+            let size = window.inner_size();
+            builder.with_bounds(window_bounds(size.width, size.height))
+        } else {
+            builder
+        };
         let builder = ipc_params.feed_ipc(webview_entity, builder);
         let builder = event_params.feed_handlers(webview_entity, handlers, builder);
         let builder = feed_configs1(builder, configs1);
@@ -103,14 +148,41 @@ fn load_web_views(
             request_sender.clone(),
         );
         let builder = feed_platform_configs(builder, configs_platform);
-        let Some(Ok(webview)) = build_webview(builder, webview_entity, embed_within, &windows)
-        else {
+        #[cfg(feature = "offscreen")]
+        let webview = match offscreen {
+            Some(offscreen) => crate::webview::offscreen::build_webview(
+                builder,
+                webview_entity,
+                offscreen,
+                &windows,
+                &mut web_views.2,
+            ),
+            None => build_webview(
+                builder,
+                webview_entity,
+                embed_within,
+                in_child_window,
+                &windows,
+            ),
+        };
+        #[cfg(not(feature = "offscreen"))]
+        let webview = {
+            let () = offscreen;
+            build_webview(
+                builder,
+                webview_entity,
+                embed_within,
+                in_child_window,
+                &windows,
+            )
+        };
+        let Some(Ok(webview)) = webview else {
             continue;
         };
         #[cfg(target_os = "macos")]
         // Safety: Ensure that attach the winit window to webview.
         unsafe {
-            if embed_within.is_none() {
+            if embed_within.is_none() && !is_offscreen(offscreen) {
                 attach_inner_window(
                     configs1.4.is_transparent(),
                     &webview.ns_window(),
@@ -121,10 +193,23 @@ fn load_web_views(
         commands
             .entity(webview_entity)
             .insert((WebviewInitialized(()), WryResponseHandles::default()));
+        if embed_within.is_some() || in_child_window {
+            web_views.1.insert(webview_entity);
+        }
         web_views.0.insert(webview_entity, webview);
     }
 }
 
+#[cfg(feature = "offscreen")]
+const fn is_offscreen(offscreen: OffscreenConfig) -> bool {
+    offscreen.is_some()
+}
+
+#[cfg(not(feature = "offscreen"))]
+const fn is_offscreen(_: OffscreenConfig) -> bool {
+    false
+}
+
 fn new_builder<'a>(has_parent: bool, bounds: &Option<&Bounds>) -> Option<WebViewBuilder<'a>> {
     if has_parent {
         let mut builder = WebViewBuilder::new();
@@ -206,7 +291,13 @@ fn initialization_script(
 ) -> String {
     let s1 = include_str!("../../scripts/windowIdentifier.js")
         .replace("<WINDOW_IDENTIFIER>", identifier);
-    let mut scripts = vec![include_str!("../../scripts/bevy_flurx_api.js"), &s1];
+    let mut scripts = vec![
+        include_str!("../../scripts/bevy_flurx_api.js"),
+        &s1,
+        SCREENSHOT_SCRIPT,
+        CLICK_THROUGH_SCRIPT,
+        FORWARD_INPUT_SCRIPT,
+    ];
     if is_embedded {
         scripts.push(include_str!("../../scripts/gripZone.js"));
         #[cfg(target_os = "linux")]
@@ -246,10 +337,68 @@ fn feed_platform_configs<'a>(
     return builder;
 }
 
+/// Returns whether the webview without [`EmbedWithin`] is hosted in its own child window covering the game window.
+///
+/// On Linux, the webview built with [`WebViewBuilder::build`] wraps the game window itself,
+/// so the mouse events can't pass through it to the game window.
+/// The webviews passing them through are hosted in the child windows instead.
+fn hosts_in_child_window(
+    embed_within: Option<&EmbedWithin>,
+    click_through: Option<&ClickThrough>,
+) -> bool {
+    cfg!(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )) && embed_within.is_none()
+        && click_through.is_some_and(|click_through| *click_through != ClickThrough::Disabled)
+}
+
+fn window_bounds(width: u32, height: u32) -> wry::Rect {
+    wry::Rect {
+        position: wry::dpi::PhysicalPosition::new(0, 0).into(),
+        size: wry::dpi::PhysicalSize::new(width, height).into(),
+    }
+}
+
+/// Keeps the webviews hosted in the child windows covering the whole game windows.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+fn resize_child_window_webviews(
+    mut er_resized: EventReader<bevy::window::WindowResized>,
+    winit_windows: NonSend<WinitWindows>,
+    wry_web_views: NonSend<WryWebViews>,
+) {
+    for event in er_resized.read() {
+        // The embedded webviews follow their `Bounds` instead.
+        if !wry_web_views.1.contains(&event.window) {
+            continue;
+        }
+        let (Some(webview), Some(window)) = (
+            wry_web_views.0.get(&event.window),
+            winit_windows.get_window(event.window),
+        ) else {
+            continue;
+        };
+        let size = window.inner_size();
+        if let Err(e) = webview.set_bounds(window_bounds(size.width, size.height)) {
+            error!("Failed to resize webview {}: {e}", event.window);
+        }
+    }
+}
+
 fn build_webview(
     builder: WebViewBuilder,
     window_entity: Entity,
     parent_window: Option<&EmbedWithin>,
+    in_child_window: bool,
     windows: &WinitWindows,
 ) -> Option<wry::Result<WebView>> {
     if let Some(parent_window) = parent_window
@@ -257,7 +406,7 @@ fn build_webview(
         .and_then(|parent| windows.get_window(parent))
     {
         Some(builder.build_as_child(parent_window.deref()))
-    } else if cfg!(target_os = "macos") {
+    } else if cfg!(target_os = "macos") || in_child_window {
         windows
             .get_window(window_entity)
             .map(|window| builder.build_as_child(window.deref()))
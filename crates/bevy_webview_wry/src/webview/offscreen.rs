@@ -0,0 +1,285 @@
+//! Renders the [`Offscreen`] webviews into images with the native snapshot apis.
+//!
+//! The page is hosted in a window that is never shown, and the snapshot is decoded into the frame.
+//! The input is dispatched into the page as DOM events, since the native view never receives it.
+
+use crate::webview::screenshot;
+use bevy::asset::RenderAssetUsages;
+use bevy::image::{CompressedImageFormats, Image, ImageSampler, ImageType};
+use bevy::input::ButtonState;
+use bevy::input::keyboard::Key;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{Entity, MouseButton};
+use bevy::render::render_resource::TextureFormat;
+use bevy::winit::WinitWindows;
+use bevy_webview_core::backend::BackendResult;
+use bevy_webview_core::prelude::{Offscreen, OffscreenFrame, OffscreenInput};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wry::{WebView, WebViewBuilder};
+
+/// The script that dispatches [`OffscreenInput`] into the page.
+const OFFSCREEN_INPUT_SCRIPT: &str = include_str!("../../scripts/offscreenInput.js");
+
+/// The offscreen webviews and their latest frames.
+#[derive(Default)]
+pub(crate) struct OffscreenViews(HashMap<Entity, OffscreenView>);
+
+pub(crate) struct OffscreenView {
+    /// Keeps the window hosting the webview alive.
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    _host: gtk::OffscreenWindow,
+    state: Rc<RefCell<FrameState>>,
+}
+
+#[derive(Default)]
+struct FrameState {
+    /// Whether the snapshot has been requested and not captured yet.
+    capturing: bool,
+    frame: Option<OffscreenFrame>,
+}
+
+impl OffscreenViews {
+    /// Returns the latest frame captured since the last call, and requests the next snapshot.
+    ///
+    /// Since the snapshot is taken asynchronously, the frame lags a few frames behind the page.
+    pub(crate) fn capture_frame(
+        &mut self,
+        webview_entity: Entity,
+        webview: &WebView,
+    ) -> BackendResult<Option<OffscreenFrame>> {
+        let Some(view) = self.0.get(&webview_entity) else {
+            return Ok(None);
+        };
+        let mut state = view.state.borrow_mut();
+        let frame = state.frame.take();
+        if state.capturing {
+            return Ok(frame);
+        }
+        state.capturing = true;
+        drop(state);
+        let state = view.state.clone();
+        let requested = screenshot::snapshot(webview, move |png| {
+            let mut state = state.borrow_mut();
+            state.capturing = false;
+            match png.and_then(|png| decode_png(&png)) {
+                Ok(frame) => state.frame = Some(frame),
+                Err(e) => bevy::log::error!("Failed to capture webview {webview_entity}: {e}"),
+            }
+        });
+        if requested.is_err() {
+            view.state.borrow_mut().capturing = false;
+        }
+        requested.map(|()| frame)
+    }
+}
+
+/// Builds the webview hosted in the window that is never shown.
+///
+/// On Linux, the webview is hosted in [`gtk::OffscreenWindow`].
+/// On the other platforms, it is hosted in an existing window outside of its client area,
+/// because wry requires the parent window to create the webview.
+pub(crate) fn build_webview(
+    builder: WebViewBuilder,
+    webview_entity: Entity,
+    offscreen: &Offscreen,
+    windows: &WinitWindows,
+    views: &mut OffscreenViews,
+) -> Option<wry::Result<WebView>> {
+    let builder = builder.with_initialization_script(OFFSCREEN_INPUT_SCRIPT);
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    {
+        use gtk::prelude::{ContainerExt, GtkWindowExt, WidgetExt};
+        use wry::WebViewBuilderExtUnix;
+
+        let _ = windows;
+        let host = gtk::OffscreenWindow::new();
+        host.set_default_size(offscreen.size.x as i32, offscreen.size.y as i32);
+        let container = gtk::Box::new(gtk::Orientation::Vertical, 0);
+        host.add(&container);
+        host.show_all();
+        let webview = match builder.build_gtk(&container) {
+            Ok(webview) => webview,
+            Err(e) => return Some(Err(e)),
+        };
+        views.0.insert(
+            webview_entity,
+            OffscreenView {
+                _host: host,
+                state: Rc::default(),
+            },
+        );
+        Some(Ok(webview))
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    {
+        use std::ops::Deref;
+
+        let parent = windows.windows.values().next()?;
+        let size = offscreen.size.as_ivec2();
+        let builder = builder.with_bounds(wry::Rect {
+            // Places the webview to the left of the client area, so that it is never shown.
+            position: wry::dpi::PhysicalPosition::new(-size.x - 1, 0).into(),
+            size: wry::dpi::PhysicalSize::new(offscreen.size.x, offscreen.size.y).into(),
+        });
+        let webview = match builder.build_as_child(parent.deref()) {
+            Ok(webview) => webview,
+            Err(e) => return Some(Err(e)),
+        };
+        views.0.insert(
+            webview_entity,
+            OffscreenView {
+                state: Rc::default(),
+            },
+        );
+        Some(Ok(webview))
+    }
+}
+
+/// Dispatches the input into the page as DOM events.
+pub(crate) fn send_input(webview: &WebView, input: &OffscreenInput) -> BackendResult {
+    let Some(input) = dom_input(input) else {
+        return Ok(());
+    };
+    webview.evaluate_script(&format!("window.__FLURX__.__offscreenInput({input})"))?;
+    Ok(())
+}
+
+fn dom_input(input: &OffscreenInput) -> Option<serde_json::Value> {
+    let input = match input {
+        OffscreenInput::CursorMoved { position } => serde_json::json!({
+            "type": "move",
+            "x": position.x,
+            "y": position.y,
+        }),
+        OffscreenInput::MouseButton {
+            button,
+            state,
+            position,
+        } => serde_json::json!({
+            "type": if *state == ButtonState::Pressed { "down" } else { "up" },
+            "button": dom_button(*button)?,
+            "x": position.x,
+            "y": position.y,
+        }),
+        OffscreenInput::Wheel { delta, position } => serde_json::json!({
+            "type": "wheel",
+            // The positive delta of bevy scrolls up, whereas the positive delta of the page scrolls down.
+            "dx": -delta.x,
+            "dy": -delta.y,
+            "x": position.x,
+            "y": position.y,
+        }),
+        OffscreenInput::Keyboard { key, state } => serde_json::json!({
+            "type": "key",
+            "key": dom_key(key)?,
+            "pressed": *state == ButtonState::Pressed,
+        }),
+    };
+    Some(input)
+}
+
+/// Converts [`MouseButton`] into `MouseEvent.button`.
+const fn dom_button(button: MouseButton) -> Option<u8> {
+    match button {
+        MouseButton::Left => Some(0),
+        MouseButton::Middle => Some(1),
+        MouseButton::Right => Some(2),
+        _ => None,
+    }
+}
+
+/// Converts [`Key`] into `KeyboardEvent.key`.
+///
+/// The names of the named keys are the same as the DOM key values except for a few keys.
+fn dom_key(key: &Key) -> Option<String> {
+    match key {
+        Key::Character(c) => Some(c.to_string()),
+        Key::Space => Some(" ".to_string()),
+        Key::Super => Some("Meta".to_string()),
+        Key::Unidentified(_) | Key::Dead(_) => None,
+        key => Some(format!("{key:?}")),
+    }
+}
+
+fn decode_png(png: &[u8]) -> Result<OffscreenFrame, String> {
+    let image = Image::from_buffer(
+        png,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .map_err(|e| e.to_string())?;
+    let image = if image.texture_descriptor.format == TextureFormat::Rgba8UnormSrgb {
+        image
+    } else {
+        image
+            .convert(TextureFormat::Rgba8UnormSrgb)
+            .ok_or("The snapshot can't be converted into RGBA")?
+    };
+    Ok(OffscreenFrame {
+        size: image.size(),
+        data: image.data.unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::webview::offscreen::{dom_input, dom_key};
+    use bevy::input::ButtonState;
+    use bevy::input::keyboard::{Key, NativeKey};
+    use bevy::math::Vec2;
+    use bevy::prelude::MouseButton;
+    use bevy_webview_core::prelude::OffscreenInput;
+
+    #[test]
+    fn convert_key_into_dom_key() {
+        assert_eq!(dom_key(&Key::Character("a".into())), Some("a".to_string()));
+        assert_eq!(dom_key(&Key::Space), Some(" ".to_string()));
+        assert_eq!(dom_key(&Key::Enter), Some("Enter".to_string()));
+        assert_eq!(dom_key(&Key::ArrowLeft), Some("ArrowLeft".to_string()));
+        assert_eq!(dom_key(&Key::Super), Some("Meta".to_string()));
+        assert_eq!(dom_key(&Key::Unidentified(NativeKey::Unidentified)), None);
+    }
+
+    #[test]
+    fn convert_wheel_into_dom_delta() {
+        let input = dom_input(&OffscreenInput::Wheel {
+            delta: Vec2::new(0., 16.),
+            position: Vec2::new(1., 2.),
+        })
+        .unwrap();
+        assert_eq!(input["type"], "wheel");
+        assert_eq!(input["dy"], -16.);
+    }
+
+    #[test]
+    fn ignore_unsupported_mouse_button() {
+        let input = dom_input(&OffscreenInput::MouseButton {
+            button: MouseButton::Back,
+            state: ButtonState::Pressed,
+            position: Vec2::ZERO,
+        });
+        assert!(input.is_none());
+    }
+}
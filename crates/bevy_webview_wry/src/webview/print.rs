@@ -0,0 +1,313 @@
+use bevy_webview_core::backend::BackendResult;
+use bevy_webview_core::prelude::{ExportPdf, PrintCompletion, PrintWebview};
+use wry::WebView;
+
+/// The number of points per millimeter.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const POINTS_PER_MM: f32 = 72. / 25.4;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) fn print(
+    webview: &WebView,
+    options: &PrintWebview,
+    completion: PrintCompletion,
+) -> BackendResult {
+    use gtk::prelude::PrintOperationExt as _;
+    use gtk::{PageSetup, PrintOperationAction};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use webkit2gtk::{PrintOperation, PrintOperationExt};
+    use wry::WebViewExtUnix;
+
+    let page_setup = PageSetup::new();
+    linux::set_margins(&page_setup, &options.margins);
+
+    // `webkit_print_operation_run_dialog` is modal and blocks the frames until the dialog is closed,
+    // so the settings are chosen with the asynchronous dialog of GTK, and then WebKit prints the page with them.
+    let dialog = gtk::PrintOperation::new();
+    dialog.set_allow_async(true);
+    dialog.set_default_page_setup(Some(&page_setup));
+    let confirmed = Rc::new(RefCell::new(None));
+    dialog.connect_begin_print({
+        let confirmed = confirmed.clone();
+        move |dialog, context| {
+            confirmed.replace(Some((dialog.print_settings(), context.page_setup())));
+            // Nothing is drawn by GTK.
+            dialog.cancel();
+        }
+    });
+    let webview = webview.webview();
+    dialog.connect_done(move |_, _| {
+        let Some((settings, page_setup)) = confirmed.take() else {
+            // The dialog has been cancelled.
+            completion.clone().complete(false);
+            return;
+        };
+        let operation = PrintOperation::new(&webview);
+        if let Some(settings) = settings {
+            operation.set_print_settings(&settings);
+        }
+        operation.set_page_setup(&page_setup);
+        linux::connect_completion(&operation, completion.clone());
+        operation.print();
+    });
+    dialog.run(PrintOperationAction::PrintDialog, None::<&gtk::Window>)?;
+    Ok(())
+}
+
+/// wry does not tell when printing finishes, so the completion is reported once the print dialog is shown.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub(crate) fn print(
+    webview: &WebView,
+    options: &PrintWebview,
+    completion: PrintCompletion,
+) -> BackendResult {
+    use wry::{PrintMargin, PrintOptions, WebViewExtDarwin};
+
+    let margins = &options.margins;
+    webview.print_with_options(&PrintOptions {
+        margins: PrintMargin {
+            top: margins.top * POINTS_PER_MM,
+            right: margins.right * POINTS_PER_MM,
+            bottom: margins.bottom * POINTS_PER_MM,
+            left: margins.left * POINTS_PER_MM,
+        },
+    })?;
+    completion.complete(true);
+    Ok(())
+}
+
+/// The margins can't be specified, and wry does not tell when printing finishes,
+/// so the completion is reported once the print dialog is shown.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "ios",
+)))]
+pub(crate) fn print(
+    webview: &WebView,
+    options: &PrintWebview,
+    completion: PrintCompletion,
+) -> BackendResult {
+    use bevy_webview_core::prelude::PrintMargins;
+
+    if options.margins != PrintMargins::default() {
+        bevy::log::warn!(
+            "The print margins are ignored on this platform; choose them in the print dialog instead"
+        );
+    }
+    webview.print()?;
+    completion.complete(true);
+    Ok(())
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) fn export_pdf(
+    webview: &WebView,
+    options: &ExportPdf,
+    completion: PrintCompletion,
+) -> BackendResult {
+    use gtk::{PageSetup, PaperSize, PrintSettings, Unit};
+    use webkit2gtk::{PrintOperation, PrintOperationExt};
+    use wry::WebViewExtUnix;
+
+    let path = std::path::absolute(&options.path)?;
+    let settings = PrintSettings::new();
+    settings.set("printer", Some("Print to File"));
+    settings.set("output-file-format", Some("pdf"));
+    settings.set(
+        "output-uri",
+        Some(&gtk::glib::filename_to_uri(&path, None)?),
+    );
+
+    let size = options.page_size.size_mm();
+    let page_setup = PageSetup::new();
+    page_setup.set_paper_size(&PaperSize::new_custom(
+        "flurx",
+        "flurx",
+        size.x as f64,
+        size.y as f64,
+        Unit::Mm,
+    ));
+    linux::set_margins(&page_setup, &options.margins);
+
+    let operation = PrintOperation::new(&webview.webview());
+    operation.set_print_settings(&settings);
+    operation.set_page_setup(&page_setup);
+    linux::connect_completion(&operation, completion);
+    operation.print();
+    Ok(())
+}
+
+/// Exports the page with `WKWebView.createPDF`.
+///
+/// WebKit renders the whole page into a single PDF page sized to the content,
+/// so the page size and the margins are not applied.
+#[cfg(target_os = "macos")]
+pub(crate) fn export_pdf(
+    webview: &WebView,
+    options: &ExportPdf,
+    completion: PrintCompletion,
+) -> BackendResult {
+    use block2::RcBlock;
+    use objc2_foundation::{NSData, NSError};
+    use std::cell::Cell;
+    use wry::WebViewExtMacOS;
+
+    bevy::log::warn!(
+        "The page size and the margins are not applied to the PDF on macOS; the page is exported as a single page"
+    );
+    let path = std::path::absolute(&options.path)?;
+    // The block must be `Fn`, but WebKit calls the completion handler only once.
+    let completion = Cell::new(Some(completion));
+    let handler = RcBlock::new(move |data: *mut NSData, error: *mut NSError| {
+        let Some(completion) = completion.take() else {
+            return;
+        };
+        // SAFETY: WebKit calls the handler on the main thread with either data or an error,
+        // each of which is either null or a valid object that stays alive until the handler returns.
+        // The references don't escape the handler.
+        let result = match unsafe { data.as_ref() } {
+            Some(data) => std::fs::write(&path, data.to_vec()).map_err(|e| e.to_string()),
+            // SAFETY: Same as above.
+            None => Err(unsafe { error.as_ref() }
+                .map(|e| e.localizedDescription().to_string())
+                .unwrap_or_else(|| "The PDF is empty".to_string())),
+        };
+        if let Err(e) = &result {
+            bevy::log::error!("Failed to export the PDF: {e}");
+        }
+        completion.complete(result.is_ok());
+    });
+    // SAFETY: This runs on the main thread because `WryWebViews` is a non-send resource,
+    // and the webview is alive during the call since it's borrowed.
+    // WebKit copies (retains) the handler block, so it outlives this function until it's called,
+    // and a null configuration means the whole content of the page.
+    unsafe {
+        webview
+            .webview()
+            .createPDFWithConfiguration_completionHandler(None, &handler);
+    }
+    Ok(())
+}
+
+/// Exports the page with WebView2 `PrintToPdf`.
+#[cfg(target_os = "windows")]
+pub(crate) fn export_pdf(
+    webview: &WebView,
+    options: &ExportPdf,
+    completion: PrintCompletion,
+) -> BackendResult {
+    use webview2_com::Microsoft::Web::WebView2::Win32::{
+        ICoreWebView2_7, ICoreWebView2Environment6,
+    };
+    use webview2_com::PrintToPdfCompletedHandler;
+    use windows::core::{HSTRING, Interface};
+    use wry::WebViewExtWindows;
+
+    const MM_PER_INCH: f64 = 25.4;
+
+    let path = HSTRING::from(std::path::absolute(&options.path)?.as_os_str());
+    let size = options.page_size.size_mm();
+    let margins = &options.margins;
+    // SAFETY: The controller and the environment are alive while the webview exists,
+    // and this runs on the UI thread that created them because `WryWebViews` is a non-send resource.
+    unsafe {
+        let core = webview
+            .controller()
+            .CoreWebView2()?
+            .cast::<ICoreWebView2_7>()?;
+        let settings = webview
+            .environment()
+            .cast::<ICoreWebView2Environment6>()?
+            .CreatePrintSettings()?;
+        settings.SetPageWidth(size.x as f64 / MM_PER_INCH)?;
+        settings.SetPageHeight(size.y as f64 / MM_PER_INCH)?;
+        settings.SetMarginTop(margins.top as f64 / MM_PER_INCH)?;
+        settings.SetMarginRight(margins.right as f64 / MM_PER_INCH)?;
+        settings.SetMarginBottom(margins.bottom as f64 / MM_PER_INCH)?;
+        settings.SetMarginLeft(margins.left as f64 / MM_PER_INCH)?;
+        settings.SetShouldPrintBackgrounds(true)?;
+        let handler = PrintToPdfCompletedHandler::create(Box::new(move |result, succeeded| {
+            if let Err(e) = &result {
+                bevy::log::error!("Failed to export the PDF: {e}");
+            }
+            completion.complete(result.is_ok() && succeeded);
+            Ok(())
+        }));
+        core.PrintToPdf(&path, &settings, &handler)?;
+    }
+    Ok(())
+}
+
+/// wry does not expose the PDF export on the other platforms.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "windows",
+)))]
+pub(crate) fn export_pdf(_: &WebView, _: &ExportPdf, _: PrintCompletion) -> BackendResult {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod linux {
+    use bevy_webview_core::prelude::{PrintCompletion, PrintMargins};
+    use gtk::{PageSetup, Unit};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use webkit2gtk::{PrintOperation, PrintOperationExt};
+
+    pub(super) fn set_margins(page_setup: &PageSetup, margins: &PrintMargins) {
+        page_setup.set_top_margin(margins.top as f64, Unit::Mm);
+        page_setup.set_right_margin(margins.right as f64, Unit::Mm);
+        page_setup.set_bottom_margin(margins.bottom as f64, Unit::Mm);
+        page_setup.set_left_margin(margins.left as f64, Unit::Mm);
+    }
+
+    /// Reports the completion when the print operation finishes or fails.
+    pub(super) fn connect_completion(operation: &PrintOperation, completion: PrintCompletion) {
+        // `finished` is also emitted after `failed`.
+        let failed = Rc::new(Cell::new(false));
+        operation.connect_failed({
+            let failed = failed.clone();
+            let completion = completion.clone();
+            move |_, e| {
+                bevy::log::error!("Failed to print: {e}");
+                failed.set(true);
+                completion.clone().complete(false);
+            }
+        });
+        operation.connect_finished(move |_| {
+            if !failed.get() {
+                completion.clone().complete(true);
+            }
+        });
+    }
+}
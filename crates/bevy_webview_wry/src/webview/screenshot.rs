@@ -0,0 +1,210 @@
+use bevy_webview_core::backend::BackendResult;
+use bevy_webview_core::prelude::ScreenshotCompletion;
+use wry::WebView;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "windows",
+))]
+pub(crate) fn take_screenshot(
+    webview: &WebView,
+    completion: ScreenshotCompletion,
+) -> BackendResult {
+    snapshot(webview, move |png| completion.complete(png))
+}
+
+/// Takes the PNG snapshot of the page with the native api, and passes it to `on_captured`.
+///
+/// `on_captured` is called on the main thread once the engine has captured the page.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) fn snapshot(
+    webview: &WebView,
+    on_captured: impl FnOnce(Result<Vec<u8>, String>) + 'static,
+) -> BackendResult {
+    use gtk::gio::Cancellable;
+    use webkit2gtk::{SnapshotOptions, SnapshotRegion, WebViewExt};
+    use wry::WebViewExtUnix;
+
+    webview.webview().snapshot(
+        SnapshotRegion::Visible,
+        SnapshotOptions::NONE,
+        None::<&Cancellable>,
+        move |result| {
+            let png = result.map_err(|e| e.to_string()).and_then(|surface| {
+                let surface = cairo::ImageSurface::try_from(surface)
+                    .map_err(|_| "The snapshot is not an image surface".to_string())?;
+                let mut png = Vec::new();
+                surface.write_to_png(&mut png).map_err(|e| e.to_string())?;
+                Ok(png)
+            });
+            on_captured(png);
+        },
+    );
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn snapshot(
+    webview: &WebView,
+    on_captured: impl FnOnce(Result<Vec<u8>, String>) + 'static,
+) -> BackendResult {
+    use block2::RcBlock;
+    use objc2_app_kit::NSImage;
+    use objc2_foundation::NSError;
+    use std::cell::Cell;
+    use wry::WebViewExtMacOS;
+
+    // The block must be `Fn`, but WebKit calls the completion handler only once.
+    let on_captured = Cell::new(Some(on_captured));
+    let handler = RcBlock::new(move |image: *mut NSImage, error: *mut NSError| {
+        // SAFETY: WebKit calls the handler on the main thread with either an image or an error,
+        // each of which is either null or a valid object that stays alive until the handler returns.
+        // The references don't escape the handler.
+        let result = match unsafe { image.as_ref() } {
+            Some(image) => png_from_image(image),
+            // SAFETY: Same as above.
+            None => Err(unsafe { error.as_ref() }
+                .map(|e| e.localizedDescription().to_string())
+                .unwrap_or_else(|| "The snapshot is empty".to_string())),
+        };
+        if let Some(on_captured) = on_captured.take() {
+            on_captured(result);
+        }
+    });
+    // SAFETY: This runs on the main thread because `WryWebViews` is a non-send resource,
+    // and the webview is alive during the call since it's borrowed.
+    // WebKit copies (retains) the handler block, so it outlives this function until it's called,
+    // and a null configuration means the visible bounds of the webview.
+    unsafe {
+        webview
+            .webview()
+            .takeSnapshotWithConfiguration_completionHandler(None, &handler);
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn png_from_image(image: &objc2_app_kit::NSImage) -> Result<Vec<u8>, String> {
+    use objc2_app_kit::{NSBitmapImageFileType, NSBitmapImageRep};
+    use objc2_foundation::NSDictionary;
+
+    let tiff = image
+        .TIFFRepresentation()
+        .ok_or("Failed to encode the snapshot")?;
+    let bitmap =
+        NSBitmapImageRep::imageRepWithData(&tiff).ok_or("Failed to decode the snapshot")?;
+    // SAFETY: The properties dictionary is empty, so it contains no values of unexpected types,
+    // and `bitmap` is a valid image rep created above on the current thread.
+    let png = unsafe {
+        bitmap.representationUsingType_properties(NSBitmapImageFileType::PNG, &NSDictionary::new())
+    }
+    .ok_or("Failed to encode the snapshot as PNG")?;
+    Ok(png.to_vec())
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn snapshot(
+    webview: &WebView,
+    on_captured: impl FnOnce(Result<Vec<u8>, String>) + 'static,
+) -> BackendResult {
+    use webview2_com::CapturePreviewCompletedHandler;
+    use webview2_com::Microsoft::Web::WebView2::Win32::COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG;
+    use windows::Win32::Foundation::HGLOBAL;
+    use windows::Win32::System::Com::StructuredStorage::CreateStreamOnHGlobal;
+    use wry::WebViewExtWindows;
+
+    // SAFETY: The controller is alive while the webview exists.
+    let core = unsafe { webview.controller().CoreWebView2()? };
+    let stream = unsafe { CreateStreamOnHGlobal(HGLOBAL::default(), true)? };
+    let handler = CapturePreviewCompletedHandler::create(Box::new({
+        let stream = stream.clone();
+        move |result| {
+            let png = result
+                .and_then(|_| read_stream(&stream))
+                .map_err(|e| e.to_string());
+            on_captured(png);
+            Ok(())
+        }
+    }));
+    unsafe {
+        core.CapturePreview(
+            COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG,
+            &stream,
+            &handler,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn read_stream(stream: &windows::Win32::System::Com::IStream) -> windows::core::Result<Vec<u8>> {
+    use windows::Win32::System::Com::STREAM_SEEK_SET;
+
+    let mut png = Vec::new();
+    let mut buf = vec![0_u8; 64 * 1024];
+    // SAFETY: The buffer outlives the reads.
+    unsafe {
+        stream.Seek(0, STREAM_SEEK_SET, None)?;
+        loop {
+            let mut read = 0;
+            stream
+                .Read(buf.as_mut_ptr().cast(), buf.len() as u32, Some(&mut read))
+                .ok()?;
+            if read == 0 {
+                break;
+            }
+            png.extend_from_slice(&buf[..read as usize]);
+        }
+    }
+    Ok(png)
+}
+
+/// Falls back to rendering the page into a canvas with [`SCREENSHOT_SCRIPT`](bevy_webview_core::backend::SCREENSHOT_SCRIPT)
+/// because wry does not expose the native snapshot on the other platforms.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos",
+    target_os = "windows",
+)))]
+pub(crate) fn take_screenshot(
+    webview: &WebView,
+    completion: ScreenshotCompletion,
+) -> BackendResult {
+    webview.evaluate_script(&completion.dom_script())?;
+    Ok(())
+}
+
+/// The native snapshot is not available on the other platforms.
+#[cfg(all(
+    feature = "offscreen",
+    not(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "windows",
+    ))
+))]
+pub(crate) fn snapshot(
+    _: &WebView,
+    _: impl FnOnce(Result<Vec<u8>, String>) + 'static,
+) -> BackendResult {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+}
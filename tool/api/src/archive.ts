@@ -0,0 +1,135 @@
+import {invoke} from "./core";
+import {BaseDirectory} from "./fs";
+
+/** `zip`, `tar` or `tar_gz`; detected from the extension if not specified. */
+export type ArchiveFormat = "zip" | "tar" | "tar_gz";
+
+export interface ArchiveEntry {
+    /** The path in the archive separated by `/`. */
+    path: string,
+    size: number,
+    isDir: boolean,
+}
+
+export interface ArchiveProgress {
+    processedEntries: number,
+    totalEntries: number,
+    processedBytes: number,
+    totalBytes: number,
+}
+
+export interface ArchiveListOptions {
+    dir?: BaseDirectory,
+    format?: ArchiveFormat,
+}
+
+export interface ArchiveExtractOptions extends ArchiveListOptions {
+    toDir?: BaseDirectory,
+    /** The entries to extract; a directory extracts everything under it. All entries by default. */
+    entries?: string[],
+    /** Whether to overwrite the files that already exist in the destination. */
+    overwrite?: boolean,
+    /** Called at most once per frame while extracting. */
+    onProgress?: (progress: ArchiveProgress) => void,
+}
+
+export interface ArchiveCreateOptions extends ArchiveListOptions {
+    sourceDir?: BaseDirectory,
+    /** Called at most once per frame while creating. */
+    onProgress?: (progress: ArchiveProgress) => void,
+}
+
+export namespace archive {
+    /**
+     * Lists the entries in the archive.
+     *
+     * @example
+     * import {archive} from "bevy_flurx_api";
+     * const entries: ArchiveEntry[] = await archive.list("mods.zip", {
+     *     dir: "Download",
+     * });
+     */
+    export const list = async (
+        path: string,
+        options?: ArchiveListOptions,
+    ): Promise<ArchiveEntry[]> => {
+        return await invoke("FLURX|archive::list", {
+            path,
+            ...options,
+        });
+    }
+
+    /**
+     * Extracts the archive into the directory.
+     *
+     * The entries pointing outside of the destination are rejected.
+     *
+     * @example
+     * import {archive} from "bevy_flurx_api";
+     * await archive.extract("mods.zip", "mods", {
+     *     dir: "Download",
+     *     toDir: "AppData",
+     *     onProgress: (progress) => console.log(progress.processedBytes / progress.totalBytes),
+     * });
+     */
+    export const extract = async (
+        path: string,
+        to: string,
+        options?: ArchiveExtractOptions,
+    ): Promise<void> => {
+        const {onProgress, ...args} = options ?? {};
+        await withProgress("FLURX|archive::extract", onProgress, (progressEventId) => invoke("FLURX|archive::extract", {
+            path,
+            to,
+            progressEventId,
+            ...args
+        }));
+    }
+
+    /**
+     * Creates the archive from the files or directories.
+     *
+     * Each source is stored under its file name, and directories are stored recursively.
+     *
+     * @example
+     * import {archive} from "bevy_flurx_api";
+     * await archive.create("save.zip", ["saves/slot1", "config.ron"], {
+     *     dir: "Download",
+     *     sourceDir: "AppData",
+     * });
+     */
+    export const create = async (
+        path: string,
+        sources: string[],
+        options?: ArchiveCreateOptions,
+    ): Promise<void> => {
+        const {onProgress, ...args} = options ?? {};
+        await withProgress("FLURX|archive::create", onProgress, (progressEventId) => invoke("FLURX|archive::create", {
+            path,
+            sources,
+            progressEventId,
+            ...args
+        }));
+    }
+
+    const withProgress = async (
+        command: string,
+        onProgress: ((progress: ArchiveProgress) => void) | undefined,
+        f: (progressEventId: string | undefined) => Promise<void>,
+    ): Promise<void> => {
+        const progressEventId = onProgress ? `${command}::${window.crypto.getRandomValues(new Uint32Array(1))[0]}` : undefined;
+        const prop = `_event_${window.__FLURX__.windowIdentifier}_${progressEventId}`;
+        if (onProgress) {
+            Object.defineProperty(window.__FLURX__, prop, {
+                value: onProgress,
+                writable: false,
+                configurable: true
+            });
+        }
+        try {
+            await f(progressEventId);
+        } finally {
+            Reflect.deleteProperty(window.__FLURX__, prop);
+        }
+    }
+}
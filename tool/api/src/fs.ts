@@ -3,25 +3,77 @@ import {invoke} from "./core";
 export interface FileEntry {
     name: string,
     path: string,
-    children: FileEntry[] | null
+    isDir: boolean,
+    /** Null if not a directory or deeper than `depth`. */
+    children: FileEntry[] | null,
+    metadata?: FileMetadata,
+}
+
+export type FileType = "file" | "dir" | "symlink" | "other";
+
+export interface FileMetadata {
+    fileType: FileType,
+    size: number,
+    readonly: boolean,
+    /** Milliseconds since the unix epoch, or null if the platform doesn't support it. */
+    modified: number | null,
+    /** Milliseconds since the unix epoch, or null if the platform doesn't support it. */
+    accessed: number | null,
+    /** Milliseconds since the unix epoch, or null if the platform doesn't support it. */
+    created: number | null,
+    symlinkTarget: string | null,
 }
 
 export interface FsWriteFileOptions {
     dir?: BaseDirectory,
     append?: boolean,
     recursive?: boolean,
+    /** Writes to a temporary file in the same directory, and then renames it to the target. */
+    atomic?: boolean,
+    /** Takes an advisory lock on `<file>.lock` while writing, and fails if another writer holds it. */
+    lock?: boolean,
+    /** Copies the previous version to `<file>.bak` before writing. */
+    backup?: boolean,
 }
 
 export interface FsBaseDirectoryOption {
     dir?: BaseDirectory,
 }
 
+export type SortBy = "name" | "modified" | "size";
+
+export interface FsReadDirOptions {
+    dir?: BaseDirectory,
+    /** Whether to include the metadata in each entry. */
+    metadata?: boolean,
+    /** The depth of the subdirectories to read; `0` reads only the entries of the directory. Unlimited by default. */
+    depth?: number,
+    /** The glob pattern that the file names must match. */
+    glob?: string,
+    /** The extensions that the files must have. */
+    extensions?: string[],
+    /** Whether to include the hidden files and directories. Default is `true`. */
+    includeHidden?: boolean,
+    sort?: SortBy,
+    descending?: boolean,
+    /** The number of the top-level entries to skip. */
+    offset?: number,
+    /** The maximum number of the top-level entries. */
+    limit?: number,
+    /** The name of the last top-level entry of the previous page; the entries up to it are skipped. */
+    after?: string,
+}
+
 export interface FsDirOptions {
     dir?: BaseDirectory,
     recursive?: boolean,
 }
 
 export type BaseDirectory =
+    "AppData" |
+    "AppConfig" |
+    "AppCache" |
+    "AppLog" |
     "ConfigLocal" |
     "Data" |
     "LocalData" |
@@ -47,6 +99,74 @@ export interface CopyFileOptions {
     toBaseDir?: BaseDirectory,
 }
 
+export type FsWatchEventKind = "create" | "modify" | "remove" | "rename";
+
+export interface FsWatchEvent {
+    kind: FsWatchEventKind,
+    paths: string[],
+}
+
+export interface FsWatchOptions extends FsBaseDirectoryOption {
+    /** Whether to watch the subdirectories. */
+    recursive?: boolean,
+    /** The events are delivered after no events have occurred for this milliseconds. */
+    debounceMs?: number,
+}
+
+export interface FsOpenOptions extends FsBaseDirectoryOption {
+    /** Default is `true`. */
+    read?: boolean,
+    write?: boolean,
+    append?: boolean,
+    create?: boolean,
+    truncate?: boolean,
+}
+
+export interface FsChunkOptions {
+    /** If specified, reads or writes from this position instead of the current position. */
+    offset?: number,
+}
+
+export type SeekWhence = "start" | "current" | "end";
+
+export interface FsGlobOptions extends FsBaseDirectoryOption {
+    /** Whether to include the directories matching the pattern. */
+    includeDirs?: boolean,
+}
+
+export interface CopyDirProgress {
+    copiedFiles: number,
+    totalFiles: number,
+    copiedBytes: number,
+    totalBytes: number,
+}
+
+export interface CopyDirOptions extends CopyFileOptions {
+    /** Whether to overwrite the files that already exist in the destination. */
+    overwrite?: boolean,
+    /** Called at most once per frame while copying. */
+    onProgress?: (progress: CopyDirProgress) => void,
+}
+
+export interface FsRemoveOptions extends FsBaseDirectoryOption {
+    /** Moves to the trash instead of removing permanently. */
+    toTrash?: boolean,
+}
+
+export interface FsRemoveDirOptions extends FsDirOptions {
+    /** Moves to the trash instead of removing permanently. A non-empty directory requires `recursive`. */
+    toTrash?: boolean,
+}
+
+export interface TrashItem {
+    /** The platform specific id to restore the item. */
+    id: string,
+    name: string,
+    originalPath: string,
+    /** Seconds since the unix epoch. */
+    deletedAt: number,
+}
+
 export interface RenameFileOptions {
     oldDir?: BaseDirectory,
     newDir?: BaseDirectory,
@@ -72,6 +192,42 @@ export namespace fs {
         })
     }
 
+    /**
+     * Copies a directory recursively.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * await fs.copyDir("from", "to", {
+     *     onProgress: (progress) => console.log(progress.copiedBytes / progress.totalBytes),
+     * });
+     */
+    export const copyDir = async (
+        from: string,
+        to: string,
+        options?: CopyDirOptions,
+    ): Promise<void> => {
+        const {onProgress, ...args} = options ?? {};
+        const progressEventId = onProgress ? `FLURX|fs::copy_dir::${window.crypto.getRandomValues(new Uint32Array(1))[0]}` : undefined;
+        const prop = `_event_${window.__FLURX__.windowIdentifier}_${progressEventId}`;
+        if (onProgress) {
+            Object.defineProperty(window.__FLURX__, prop, {
+                value: onProgress,
+                writable: false,
+                configurable: true
+            });
+        }
+        try {
+            await invoke("FLURX|fs::copy_dir", {
+                from,
+                to,
+                progressEventId,
+                ...args
+            });
+        } finally {
+            Reflect.deleteProperty(window.__FLURX__, prop);
+        }
+    }
+
     /**
      * Creates a directory.
      *
@@ -105,6 +261,210 @@ export namespace fs {
         });
     }
 
+    /**
+     * Returns the metadata of a file or directory.
+     *
+     * Symbolic links are followed.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const metadata: FileMetadata = await fs.metadata("path");
+     */
+    export const metadata = async (
+        path: string,
+        options?: FsBaseDirectoryOption,
+    ): Promise<FileMetadata> => {
+        return await invoke("FLURX|fs::metadata", {
+            path,
+            ...options
+        });
+    }
+
+    /**
+     * Returns the metadata of a file or directory without following symbolic links.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const metadata: FileMetadata = await fs.symlinkMetadata("path");
+     */
+    export const symlinkMetadata = async (
+        path: string,
+        options?: FsBaseDirectoryOption,
+    ): Promise<FileMetadata> => {
+        return await invoke("FLURX|fs::symlink_metadata", {
+            path,
+            ...options
+        });
+    }
+
+    /**
+     * Watches the changes of a file or directory.
+     *
+     * Returns the subscription id which is used to stop watching with {@link unwatch}.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const id: number = await fs.watch("path", (event) => {
+     *     console.log(event.kind, event.paths);
+     * }, {recursive: true});
+     */
+    export const watch = async (
+        path: string,
+        f: (event: FsWatchEvent) => void,
+        options?: FsWatchOptions,
+    ): Promise<number> => {
+        const id: number = await invoke("FLURX|fs::watch", {
+            path,
+            ...options
+        });
+        Object.defineProperty(window.__FLURX__, watchEventProp(id), {
+            value: f,
+            writable: false,
+            configurable: true
+        });
+        return id;
+    }
+
+    /**
+     * Stops watching started by {@link watch}.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * await fs.unwatch(id);
+     */
+    export const unwatch = async (id: number): Promise<void> => {
+        Reflect.deleteProperty(window.__FLURX__, watchEventProp(id));
+        await invoke("FLURX|fs::unwatch", id);
+    }
+
+    const watchEventProp = (id: number) => `_event_${window.__FLURX__.windowIdentifier}_FLURX|fs::watch::${id}`;
+
+    /**
+     * Opens a file to read or write it in chunks.
+     *
+     * Returns the handle of the file which is closed by {@link close} or when the webview is closed.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const handle: number = await fs.open("path", {write: true, create: true});
+     */
+    export const open = async (
+        path: string,
+        options?: FsOpenOptions,
+    ): Promise<number> => {
+        return await invoke("FLURX|fs::open", {
+            path,
+            ...options
+        });
+    }
+
+    /**
+     * Reads up to `length` bytes from the file opened by {@link open}.
+     *
+     * Returns an empty array if the end of the file has been reached.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const chunk: Uint8Array = await fs.readChunk(handle, 1024 * 1024);
+     */
+    export const readChunk = async (
+        handle: number,
+        length: number,
+        options?: FsChunkOptions,
+    ): Promise<Uint8Array> => {
+        const chunk: number[] = await invoke("FLURX|fs::read_chunk", {
+            handle,
+            length,
+            ...options
+        });
+        return new Uint8Array(chunk);
+    }
+
+    /**
+     * Writes the contents to the file opened by {@link open}.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * await fs.writeChunk(handle, new Uint8Array([0, 1, 2]));
+     */
+    export const writeChunk = async (
+        handle: number,
+        contents: Uint8Array | Iterable<number> | ArrayLike<number>,
+        options?: FsChunkOptions,
+    ): Promise<void> => {
+        await invoke("FLURX|fs::write_chunk", {
+            handle,
+            contents: Array.from(contents),
+            ...options
+        });
+    }
+
+    /**
+     * Moves the position of the file opened by {@link open}, and returns the new position from the start.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const size: number = await fs.seek(handle, 0, "end");
+     */
+    export const seek = async (
+        handle: number,
+        offset: number,
+        whence: SeekWhence = "start",
+    ): Promise<number> => {
+        return await invoke("FLURX|fs::seek", {
+            handle,
+            offset,
+            whence
+        });
+    }
+
+    /**
+     * Closes the file opened by {@link open}.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * await fs.close(handle);
+     */
+    export const close = async (handle: number): Promise<void> => {
+        await invoke("FLURX|fs::close", handle);
+    }
+
+    /**
+     * Returns the paths matching the glob pattern.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const paths: string[] = await fs.glob("mods/**/*.ron");
+     */
+    export const glob = async (
+        pattern: string,
+        options?: FsGlobOptions,
+    ): Promise<string[]> => {
+        return await invoke("FLURX|fs::glob", {
+            pattern,
+            ...options
+        });
+    }
+
+    /**
+     * Moves a file or directory, even across devices.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * await fs.move("from", "to");
+     */
+    export const move = async (
+        from: string,
+        to: string,
+        options?: CopyFileOptions,
+    ): Promise<void> => {
+        await invoke("FLURX|fs::move", {
+            from,
+            to,
+            ...options
+        });
+    }
+
     /**
      * Reads a file as byte array.
      *
@@ -148,7 +508,7 @@ export namespace fs {
      */
     export const removeFile = async (
         path: string,
-        options?: FsBaseDirectoryOption,
+        options?: FsRemoveOptions,
     ): Promise<void> => {
         await invoke("FLURX|fs::remove_file", {
             path,
@@ -156,6 +516,52 @@ export namespace fs {
         });
     }
 
+    /**
+     * Moves a file or directory to the trash.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * await fs.trash("path");
+     */
+    export const trash = async (
+        path: string,
+        options?: FsBaseDirectoryOption,
+    ): Promise<void> => {
+        await invoke("FLURX|fs::trash", {
+            path,
+            ...options
+        });
+    }
+
+    /**
+     * Returns the items in the trash whose original paths are permitted by `AllowPaths`.
+     * Without `AllowPaths`, only the items originally in the app directories are returned.
+     *
+     * Not supported on macOS.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const items: TrashItem[] = await fs.trashList();
+     */
+    export const trashList = async (): Promise<TrashItem[]> => {
+        return await invoke("FLURX|fs::trash_list");
+    }
+
+    /**
+     * Restores the items in the trash to their original paths.
+     * Only the items returned by `fs.trashList` can be restored.
+     *
+     * Not supported on macOS.
+     *
+     * @example
+     * import {fs} from "bevy_flurx_api";
+     * const items = await fs.trashList();
+     * await fs.trashRestore(items.map((item) => item.id));
+     */
+    export const trashRestore = async (ids: string[]): Promise<void> => {
+        await invoke("FLURX|fs::trash_restore", ids);
+    }
+
     /**
      * Renames a file.
      *
@@ -216,13 +622,17 @@ export namespace fs {
     /**
      * List directory files.
      *
+     * If `metadata` is `true`, each entry contains its metadata.
+     * Use `offset` and `limit`, or `after` with the name of the last entry of the previous page, to paginate the top-level entries.
+     * The subdirectories are read only for the entries in the page.
+     *
      * @example
      * import {fs} from "bevy_flurx_api";
-     * const files: FileEntry[] = await fs.readDir("path");
+     * const files: FileEntry[] = await fs.readDir("path", {depth: 0, sort: "name", limit: 100});
      */
     export const readDir = async (
         path: string,
-        options?: FsBaseDirectoryOption,
+        options?: FsReadDirOptions,
     ): Promise<FileEntry[]> => {
         return await invoke("FLURX|fs::read_dir", {
             path,
@@ -239,7 +649,7 @@ export namespace fs {
      */
     export const removeDir = async (
         path: string,
-        options?: FsDirOptions,
+        options?: FsRemoveDirOptions,
     ): Promise<void> => {
         await invoke("FLURX|fs::remove_dir", {path, ...options});
     }
@@ -0,0 +1,61 @@
+import {invoke} from "./core";
+import {BaseDirectory} from "./fs";
+
+export type HashAlgorithm = "sha256" | "sha1" | "blake3" | "crc32";
+
+export type HashEncoding = "hex" | "base64";
+
+export interface HashOptions {
+    /** `hex` by default. */
+    encoding?: HashEncoding,
+}
+
+export interface HashFileOptions extends HashOptions {
+    dir?: BaseDirectory,
+}
+
+export namespace hash {
+    /**
+     * Computes the hash of the file.
+     *
+     * The file is read in chunks in another thread.
+     *
+     * @example
+     * import {hash} from "bevy_flurx_api";
+     * const sha256: string = await hash.file("mods.zip", "sha256", {
+     *     dir: "Download",
+     * });
+     */
+    export const file = async (
+        path: string,
+        algorithm: HashAlgorithm,
+        options?: HashFileOptions,
+    ): Promise<string> => {
+        return await invoke("FLURX|hash::file", {
+            path,
+            algorithm,
+            ...options,
+        });
+    }
+
+    /**
+     * Computes the hash of the text or bytes.
+     *
+     * A text is hashed as UTF-8 bytes.
+     *
+     * @example
+     * import {hash} from "bevy_flurx_api";
+     * const crc32: string = await hash.bytes(new Uint8Array([0, 1, 2]), "crc32");
+     */
+    export const bytes = async (
+        contents: string | Uint8Array | Iterable<number> | ArrayLike<number>,
+        algorithm: HashAlgorithm,
+        options?: HashOptions,
+    ): Promise<string> => {
+        return await invoke("FLURX|hash::bytes", {
+            contents: typeof contents === "string" ? contents : Array.from(contents),
+            algorithm,
+            ...options,
+        });
+    }
+}
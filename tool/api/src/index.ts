@@ -8,6 +8,8 @@ export * from "./notification";
 export * from "./os";
 export * from "./monitor";
 export * from "./http";
+export * from "./archive";
+export * from "./hash";
 export * from "./core";
 export {PhysicalPosition, PhysicalSize} from "./core";
 export {Monitor} from "./monitor";
@@ -221,4 +221,56 @@ export namespace path {
     export const picture = async (): Promise<string | null> => {
         return await invoke("FLURX|path::picture");
     }
-}
\ No newline at end of file
+
+    /**
+     *  Returns the app-specific data path resolved from `AppIdentifier`.
+     *
+     *  If `AppIdentifier` is not inserted or the path is not permitted by `AllowPaths`, will be null.
+     *
+     *  @example
+     *  import {path} from "bevy_flurx_api";
+     *  const appDataPath: string | null = await path.appData();
+     */
+    export const appData = async (): Promise<string | null> => {
+        return await invoke("FLURX|path::app_data");
+    }
+
+    /**
+     *  Returns the app-specific config path resolved from `AppIdentifier`.
+     *
+     *  If `AppIdentifier` is not inserted or the path is not permitted by `AllowPaths`, will be null.
+     *
+     *  @example
+     *  import {path} from "bevy_flurx_api";
+     *  const appConfigPath: string | null = await path.appConfig();
+     */
+    export const appConfig = async (): Promise<string | null> => {
+        return await invoke("FLURX|path::app_config");
+    }
+
+    /**
+     *  Returns the app-specific cache path resolved from `AppIdentifier`.
+     *
+     *  If `AppIdentifier` is not inserted or the path is not permitted by `AllowPaths`, will be null.
+     *
+     *  @example
+     *  import {path} from "bevy_flurx_api";
+     *  const appCachePath: string | null = await path.appCache();
+     */
+    export const appCache = async (): Promise<string | null> => {
+        return await invoke("FLURX|path::app_cache");
+    }
+
+    /**
+     *  Returns the app-specific log path resolved from `AppIdentifier`.
+     *
+     *  If `AppIdentifier` is not inserted or the path is not permitted by `AllowPaths`, will be null.
+     *
+     *  @example
+     *  import {path} from "bevy_flurx_api";
+     *  const appLogPath: string | null = await path.appLog();
+     */
+    export const appLog = async (): Promise<string | null> => {
+        return await invoke("FLURX|path::app_log");
+    }
+}